@@ -0,0 +1,487 @@
+use std::sync::Arc;
+
+use tracing::instrument;
+use async_trait::async_trait;
+pub use policy::{BasicRbacPolicy, Permission};
+
+use fluvio_controlplane_metadata::extended::ObjectType;
+
+use crate::{AuthContext, Authorization, TypeAction, InstanceAction, AuthError};
+use crate::x509::X509Identity;
+
+#[derive(Debug, Clone)]
+pub struct BasicAuthorization {
+    policy: Arc<BasicRbacPolicy>,
+}
+
+impl BasicAuthorization {
+    pub fn new(policy: BasicRbacPolicy) -> Self {
+        Self {
+            policy: Arc::new(policy),
+        }
+    }
+}
+
+#[async_trait]
+impl Authorization for BasicAuthorization {
+    type Context = BasicAuthContext;
+
+    #[instrument(level = "trace", skip(self, socket))]
+    async fn create_auth_context(
+        &self,
+        socket: &mut fluvio_socket::FluvioSocket,
+    ) -> Result<Self::Context, AuthError> {
+        let identity = X509Identity::create_from_connection(socket).await?;
+        Ok(BasicAuthContext {
+            identity,
+            policy: self.policy.clone(),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct BasicAuthContext {
+    identity: X509Identity,
+    policy: Arc<BasicRbacPolicy>,
+}
+
+impl BasicAuthContext {
+    pub(crate) fn new(identity: X509Identity, policy: Arc<BasicRbacPolicy>) -> Self {
+        Self { identity, policy }
+    }
+}
+
+#[async_trait]
+impl AuthContext for BasicAuthContext {
+    async fn allow_type_action(
+        &self,
+        ty: ObjectType,
+        action: TypeAction,
+    ) -> Result<bool, AuthError> {
+        self.policy
+            .evaluate(action.into(), ty, None, &self.identity)
+            .await
+    }
+
+    /// instance action is granted either for object type or for instance name
+    async fn allow_instance_action(
+        &self,
+        ty: ObjectType,
+        action: InstanceAction,
+        key: &str,
+    ) -> Result<bool, AuthError> {
+        self.policy
+            .evaluate(action.into(), ty, Some(key), &self.identity)
+            .await
+    }
+
+    fn principal(&self) -> Option<&str> {
+        Some(&self.identity.principal)
+    }
+}
+
+/// basic policy module
+/// does imple subtitution
+mod policy {
+
+    use std::fs::read;
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+    use std::convert::TryFrom;
+
+    use tracing::debug;
+    use serde::{Serialize, Deserialize};
+
+    use crate::{AuthError, TypeAction, InstanceAction};
+    use crate::x509::X509Identity;
+
+    use super::ObjectType;
+
+    type Role = String;
+
+    #[derive(Debug, Clone, PartialEq, Hash, Eq, Deserialize, Serialize)]
+    pub enum Action {
+        Create,
+        Read,
+        Update,
+        Delete,
+        Produce,
+        Consume,
+        All,
+    }
+
+    impl From<TypeAction> for Action {
+        fn from(action: TypeAction) -> Self {
+            match action {
+                TypeAction::Create => Action::Create,
+                TypeAction::Read => Action::Read,
+            }
+        }
+    }
+
+    impl From<InstanceAction> for Action {
+        fn from(action: InstanceAction) -> Self {
+            match action {
+                InstanceAction::Read => Action::Read,
+                InstanceAction::Update => Action::Update,
+                InstanceAction::Delete => Action::Delete,
+                InstanceAction::Produce => Action::Produce,
+                InstanceAction::Consume => Action::Consume,
+            }
+        }
+    }
+
+    /// action granted to a role.
+    /// instance names ending with `*` match all instances with that prefix, ex: `payments-*`
+    #[derive(Debug, Clone, PartialEq, Hash, Eq, Deserialize, Serialize)]
+    #[serde(untagged)]
+    pub enum Permission {
+        /// action on all instances of object type
+        Type(Action),
+        /// action only on named instances
+        Instance {
+            action: Action,
+            instances: Vec<String>,
+        },
+    }
+
+    impl From<Action> for Permission {
+        fn from(action: Action) -> Self {
+            Self::Type(action)
+        }
+    }
+
+    impl Permission {
+        /// check if permission grants action.
+        /// type level check (no instance) is only granted by type permission
+        fn allows(&self, action: &Action, instance: Option<&str>) -> bool {
+            match self {
+                Self::Type(permission) => permission.includes(action),
+                Self::Instance {
+                    action: permission,
+                    instances,
+                } => {
+                    permission.includes(action)
+                        && instance
+                            .map(|name| instances.iter().any(|pattern| matches(pattern, name)))
+                            .unwrap_or(false)
+                }
+            }
+        }
+    }
+
+    impl Action {
+        /// Read also grants Consume, policies written before Consume was added
+        /// granted consumers Read on topics
+        fn includes(&self, action: &Action) -> bool {
+            self == action
+                || self == &Action::All
+                || (self == &Action::Read && action == &Action::Consume)
+        }
+    }
+
+    fn matches(pattern: &str, name: &str) -> bool {
+        match pattern.strip_suffix('*') {
+            Some(prefix) => name.starts_with(prefix),
+            None => pattern == name,
+        }
+    }
+
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+    pub struct BasicRbacPolicy(pub HashMap<Role, HashMap<ObjectType, Vec<Permission>>>);
+
+    impl From<HashMap<Role, HashMap<ObjectType, Vec<Permission>>>> for BasicRbacPolicy {
+        fn from(map: HashMap<Role, HashMap<ObjectType, Vec<Permission>>>) -> Self {
+            Self(map)
+        }
+    }
+
+    impl TryFrom<PathBuf> for BasicRbacPolicy {
+        type Error = std::io::Error;
+        fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
+            debug!("reading basic policy: {:#?}", path);
+            let file = read(path)?;
+            let policy: BasicRbacPolicy = serde_json::from_slice(&file)?;
+            Ok(policy)
+        }
+    }
+
+    impl BasicRbacPolicy {
+        pub async fn evaluate(
+            &self,
+            action: Action,
+            object_type: ObjectType,
+            instance: Option<&str>,
+            identity: &X509Identity,
+        ) -> Result<bool, AuthError> {
+            //   let (action,object,_instance) = request;
+            // For each scope provided in the identity,
+            // check if there is a match;
+            let is_allowed = identity.scopes().iter().any(|scope| {
+                self.0
+                    .get(scope)
+                    .map(|objects| {
+                        objects
+                            .get(&object_type)
+                            .map(|permissions| {
+                                permissions
+                                    .iter()
+                                    .any(|permission| permission.allows(&action, instance))
+                            })
+                            .unwrap_or(false)
+                    })
+                    .unwrap_or(false)
+            });
+
+            Ok(is_allowed)
+        }
+    }
+
+    impl Default for BasicRbacPolicy {
+        // default only allows the `Root` role to have full permissions;
+        fn default() -> Self {
+            let mut root_policy = HashMap::new();
+
+            root_policy.insert(ObjectType::Spu, vec![Action::All.into()]);
+            root_policy.insert(ObjectType::CustomSpu, vec![Action::All.into()]);
+            root_policy.insert(ObjectType::SpuGroup, vec![Action::All.into()]);
+            root_policy.insert(ObjectType::Topic, vec![Action::All.into()]);
+            root_policy.insert(ObjectType::Partition, vec![Action::All.into()]);
+            root_policy.insert(ObjectType::SmartModule, vec![Action::All.into()]);
+
+            let mut policy = HashMap::new();
+
+            policy.insert(String::from("Root"), root_policy);
+
+            Self(policy)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::fs::File;
+    use std::path::PathBuf;
+    use std::convert::TryFrom;
+    use std::collections::HashMap;
+
+    use fluvio_future::test_async;
+
+    use crate::x509::X509Identity;
+
+    use super::policy::*;
+    use super::ObjectType;
+
+    #[test]
+    fn test_policy_serialization() {
+        let mut policy = BasicRbacPolicy::default();
+
+        let mut default_role = HashMap::new();
+
+        default_role.insert(ObjectType::Topic, vec![Action::All.into()]);
+        default_role.insert(ObjectType::Partition, vec![Action::All.into()]);
+        default_role.insert(ObjectType::SpuGroup, vec![Action::Read.into()]);
+        default_role.insert(ObjectType::CustomSpu, vec![Action::Read.into()]);
+        default_role.insert(ObjectType::Spu, vec![Action::Read.into()]);
+
+        policy.0.insert(String::from("Default"), default_role);
+
+        let tmp_file_path = PathBuf::from("/tmp/policy.json");
+        let tmp = File::create(tmp_file_path.clone()).expect("failed to create policy file");
+        serde_json::to_writer(&tmp, &policy).expect("failed to serialize policy to json file");
+
+        let recovered_policy =
+            BasicRbacPolicy::try_from(tmp_file_path).expect("failed to parse policy from file");
+
+        assert_eq!(
+            policy, recovered_policy,
+            "serialized and deserialized policies from file should match"
+        )
+    }
+
+    #[test_async]
+    async fn test_policy_enforcement_simple() -> Result<(), ()> {
+        let mut policy = BasicRbacPolicy::default();
+        let identity = X509Identity::new("User".to_owned(), vec!["Default".to_owned()]);
+
+        let mut role1 = HashMap::new();
+        role1.insert(
+            ObjectType::Topic,
+            vec![Action::Delete.into(), Action::Read.into()],
+        );
+
+        policy.0.insert(String::from("Default"), role1);
+
+        assert!(!policy
+            .evaluate(Action::Create, ObjectType::CustomSpu, None, &identity)
+            .await
+            .expect("eval"));
+        assert!(!policy
+            .evaluate(Action::Create, ObjectType::Topic, None, &identity)
+            .await
+            .expect("eval"));
+        assert!(policy
+            .evaluate(Action::Read, ObjectType::Topic, None, &identity)
+            .await
+            .expect("eval"));
+        assert!(policy
+            .evaluate(Action::Delete, ObjectType::Topic, Some("test"), &identity)
+            .await
+            .expect("eval"));
+
+        Ok(())
+    }
+
+    #[test_async]
+    async fn test_policy_enforcement_produce_consume() -> Result<(), ()> {
+        let mut policy = BasicRbacPolicy::default();
+        let identity = X509Identity::new("User".to_owned(), vec!["Consumer".to_owned()]);
+
+        let mut consumer = HashMap::new();
+        consumer.insert(ObjectType::Topic, vec![Action::Consume.into()]);
+        policy.0.insert(String::from("Consumer"), consumer);
+
+        assert!(policy
+            .evaluate(Action::Consume, ObjectType::Topic, Some("test"), &identity)
+            .await
+            .expect("eval"));
+        assert!(!policy
+            .evaluate(Action::Produce, ObjectType::Topic, Some("test"), &identity)
+            .await
+            .expect("eval"));
+
+        // root can do anything with topics
+        let root = X509Identity::new("Admin".to_owned(), vec!["Root".to_owned()]);
+        assert!(policy
+            .evaluate(Action::Produce, ObjectType::Topic, Some("test"), &root)
+            .await
+            .expect("eval"));
+
+        Ok(())
+    }
+
+    #[test_async]
+    async fn test_policy_read_allows_consume() -> Result<(), ()> {
+        let mut policy = BasicRbacPolicy::default();
+        let identity = X509Identity::new("User".to_owned(), vec!["Reader".to_owned()]);
+
+        // policy from before Consume was added
+        let mut reader = HashMap::new();
+        reader.insert(ObjectType::Topic, vec![Action::Read.into()]);
+        policy.0.insert(String::from("Reader"), reader);
+
+        assert!(policy
+            .evaluate(Action::Consume, ObjectType::Topic, Some("test"), &identity)
+            .await
+            .expect("eval"));
+        assert!(!policy
+            .evaluate(Action::Produce, ObjectType::Topic, Some("test"), &identity)
+            .await
+            .expect("eval"));
+
+        Ok(())
+    }
+
+    #[test_async]
+    async fn test_policy_enforcement_instance() -> Result<(), ()> {
+        let mut policy = BasicRbacPolicy::default();
+        let identity = X509Identity::new("User".to_owned(), vec!["Payments".to_owned()]);
+
+        let mut payments = HashMap::new();
+        payments.insert(
+            ObjectType::Topic,
+            vec![
+                Permission::Instance {
+                    action: Action::Read,
+                    instances: vec!["payments-*".to_owned(), "audit".to_owned()],
+                },
+                Permission::Instance {
+                    action: Action::All,
+                    instances: vec!["payments-test".to_owned()],
+                },
+            ],
+        );
+        policy.0.insert(String::from("Payments"), payments);
+
+        // instance permission doesn't grant access to whole type
+        assert!(!policy
+            .evaluate(Action::Read, ObjectType::Topic, None, &identity)
+            .await
+            .expect("eval"));
+        assert!(policy
+            .evaluate(
+                Action::Read,
+                ObjectType::Topic,
+                Some("payments-eu"),
+                &identity
+            )
+            .await
+            .expect("eval"));
+        assert!(policy
+            .evaluate(Action::Read, ObjectType::Topic, Some("audit"), &identity)
+            .await
+            .expect("eval"));
+        assert!(!policy
+            .evaluate(
+                Action::Read,
+                ObjectType::Topic,
+                Some("audit-log"),
+                &identity
+            )
+            .await
+            .expect("eval"));
+        assert!(!policy
+            .evaluate(
+                Action::Delete,
+                ObjectType::Topic,
+                Some("payments-eu"),
+                &identity
+            )
+            .await
+            .expect("eval"));
+        assert!(policy
+            .evaluate(
+                Action::Delete,
+                ObjectType::Topic,
+                Some("payments-test"),
+                &identity
+            )
+            .await
+            .expect("eval"));
+        assert!(!policy
+            .evaluate(
+                Action::Read,
+                ObjectType::Partition,
+                Some("payments-eu"),
+                &identity
+            )
+            .await
+            .expect("eval"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_policy_permission_json() {
+        let policy: BasicRbacPolicy = serde_json::from_str(
+            r#"{
+                "Default": {
+                    "Topic": ["Read", { "action": "Update", "instances": ["payments-*"] }]
+                }
+            }"#,
+        )
+        .expect("parse policy");
+
+        assert_eq!(
+            policy.0["Default"][&ObjectType::Topic],
+            vec![
+                Permission::Type(Action::Read),
+                Permission::Instance {
+                    action: Action::Update,
+                    instances: vec!["payments-*".to_owned()]
+                }
+            ]
+        );
+    }
+}
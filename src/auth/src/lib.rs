@@ -1,7 +1,11 @@
 mod policy;
 mod error;
+mod root;
 
 pub mod x509;
+pub mod basic;
+pub mod token;
 
 pub use policy::*;
 pub use error::AuthError;
+pub use root::{RootAuthorization, RootAuthContext};
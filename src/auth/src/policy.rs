@@ -15,7 +15,14 @@ pub enum TypeAction {
 }
 
 pub enum InstanceAction {
+    /// read metadata of instance
+    Read,
+    Update,
     Delete,
+    /// write records to topic
+    Produce,
+    /// read records and consumer offsets of topic
+    Consume,
 }
 
 #[async_trait]
@@ -34,6 +41,11 @@ pub trait AuthContext {
         action: InstanceAction,
         key: &str,
     ) -> Result<bool, AuthError>;
+
+    /// authenticated principal, none if caller is not identified
+    fn principal(&self) -> Option<&str> {
+        None
+    }
 }
 
 #[async_trait]
@@ -0,0 +1,351 @@
+//!
+//! # Token Authorization
+//!
+//! Authenticate clients with username/password or bearer token instead of client certificates.
+//! Secrets are stored in credentials file as salted PBKDF2-HMAC-SHA256 hash.
+//! When x509 identity is accepted, clients connecting through TLS proxy with certificate
+//! don't need credentials.
+//!
+
+use std::sync::Arc;
+use std::fs::read;
+use std::path::PathBuf;
+use std::convert::TryFrom;
+use std::collections::HashMap;
+use std::io::{Error as IoError, ErrorKind};
+
+use tracing::{debug, instrument};
+use async_trait::async_trait;
+use serde::{Serialize, Deserialize};
+use hmac::Hmac;
+use sha2::Sha256;
+
+use futures_util::stream::StreamExt;
+
+use dataplane::ErrorCode;
+use dataplane::bytes::Buf;
+use dataplane::api::{api_decode, ApiMessage, RequestHeader, RequestMessage};
+use dataplane::authenticate::{
+    AuthenticateRequest, AuthenticateResponse, AuthMechanism, AUTHENTICATE_API_KEY,
+};
+use fluvio_socket::FluvioSocket;
+
+use crate::{Authorization, AuthError};
+use crate::basic::{BasicAuthContext, BasicRbacPolicy};
+use crate::x509::X509Identity;
+use crate::x509::request::{AuthRequest, AUTH_REQUEST_API_KEY};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum CredentialKind {
+    Password,
+    Token,
+}
+
+impl Default for CredentialKind {
+    fn default() -> Self {
+        Self::Password
+    }
+}
+
+/// PBKDF2 iterations used when credential doesn't specify them
+pub const DEFAULT_HASH_ITERATIONS: u32 = 100_000;
+
+fn default_hash_iterations() -> u32 {
+    DEFAULT_HASH_ITERATIONS
+}
+
+/// hashed secret of principal
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Credential {
+    #[serde(default)]
+    pub kind: CredentialKind,
+    pub salt: String,
+    /// PBKDF2 iterations, higher is slower to verify and to brute force
+    #[serde(default = "default_hash_iterations")]
+    pub iterations: u32,
+    /// hex encoded PBKDF2-HMAC-SHA256 of secret with salt, see `hash_secret`
+    pub hash: String,
+    /// scopes are evaluated by policy, same as x509 scopes
+    #[serde(default)]
+    pub scopes: Vec<String>,
+}
+
+impl Credential {
+    fn verify(&self, secret: &str) -> bool {
+        constant_time_eq(
+            hash_secret(&self.salt, secret, self.iterations).as_bytes(),
+            self.hash.as_bytes(),
+        )
+    }
+}
+
+/// credentials keyed by principal
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CredentialStore(pub HashMap<String, Credential>);
+
+impl TryFrom<PathBuf> for CredentialStore {
+    type Error = IoError;
+
+    fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
+        debug!("reading credentials: {:#?}", path);
+        let file = read(path)?;
+        let store: CredentialStore = serde_json::from_slice(&file)?;
+        Ok(store)
+    }
+}
+
+impl CredentialStore {
+    /// find identity matching request, tokens are matched against all token credentials.
+    /// Each token credential is hashed, so number of tokens should be kept small
+    pub fn authenticate(&self, request: &AuthenticateRequest) -> Option<X509Identity> {
+        let matched = match request.mechanism {
+            AuthMechanism::Plain => self.0.get_key_value(&request.username).filter(|(_, cred)| {
+                cred.kind == CredentialKind::Password && cred.verify(&request.secret)
+            }),
+            AuthMechanism::Token => self.0.iter().find(|(_, cred)| {
+                cred.kind == CredentialKind::Token && cred.verify(&request.secret)
+            }),
+        };
+
+        matched.map(|(principal, cred)| X509Identity::new(principal.clone(), cred.scopes.clone()))
+    }
+}
+
+/// hash secret with salt using PBKDF2-HMAC-SHA256, used to generate credentials file
+pub fn hash_secret(salt: &str, secret: &str, iterations: u32) -> String {
+    let mut hash = [0u8; 32];
+    pbkdf2::pbkdf2::<Hmac<Sha256>>(secret.as_bytes(), salt.as_bytes(), iterations, &mut hash);
+    hash.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// first request of connection, identity from TLS proxy or credentials from client
+#[derive(Debug)]
+enum InitialRequest {
+    AuthRequest(RequestMessage<AuthRequest>),
+    AuthenticateRequest(RequestMessage<AuthenticateRequest>),
+}
+
+impl Default for InitialRequest {
+    fn default() -> Self {
+        Self::AuthenticateRequest(RequestMessage::default())
+    }
+}
+
+impl ApiMessage for InitialRequest {
+    type ApiKey = u16;
+
+    fn decode_with_header<T>(src: &mut T, header: RequestHeader) -> Result<Self, IoError>
+    where
+        Self: Default + Sized,
+        Self::ApiKey: Sized,
+        T: Buf,
+    {
+        match header.api_key() {
+            AUTH_REQUEST_API_KEY => api_decode!(InitialRequest, AuthRequest, src, header),
+            AUTHENTICATE_API_KEY => api_decode!(InitialRequest, AuthenticateRequest, src, header),
+            _ => Err(IoError::new(
+                ErrorKind::PermissionDenied,
+                "authenticate must be first request",
+            )),
+        }
+    }
+}
+
+/// Authorization where client must authenticate with credentials before any other request.
+/// If x509 identity is accepted, identity sent by TLS proxy is used instead of credentials.
+/// Authenticated principal is evaluated against policy
+#[derive(Debug, Clone)]
+pub struct TokenAuthorization {
+    credentials: Arc<CredentialStore>,
+    policy: Arc<BasicRbacPolicy>,
+    accept_x509: bool,
+}
+
+impl TokenAuthorization {
+    pub fn new(credentials: CredentialStore, policy: BasicRbacPolicy) -> Self {
+        Self {
+            credentials: Arc::new(credentials),
+            policy: Arc::new(policy),
+            accept_x509: false,
+        }
+    }
+
+    /// accept x509 identity of clients connecting with certificate.
+    /// Only enable when TLS proxy authenticates client certificates, since identity is trusted as sent
+    pub fn with_x509_identity(mut self) -> Self {
+        self.accept_x509 = true;
+        self
+    }
+}
+
+#[async_trait]
+impl Authorization for TokenAuthorization {
+    type Context = BasicAuthContext;
+
+    #[instrument(level = "trace", skip(self, socket))]
+    async fn create_auth_context(
+        &self,
+        socket: &mut FluvioSocket,
+    ) -> Result<Self::Context, AuthError> {
+        let initial = socket
+            .get_mut_stream()
+            .api_stream::<InitialRequest, _>()
+            .next()
+            .await;
+        let request = match initial {
+            Some(Ok(InitialRequest::AuthenticateRequest(request))) => request,
+            Some(Ok(InitialRequest::AuthRequest(request))) => {
+                if !self.accept_x509 {
+                    return Err(IoError::new(
+                        ErrorKind::PermissionDenied,
+                        "x509 identity is not accepted, authenticate must be first request",
+                    )
+                    .into());
+                }
+                let identity = X509Identity::accept_request(socket, request).await?;
+                debug!(principal = %identity.principal, "authenticated with x509 identity");
+                return Ok(BasicAuthContext::new(identity, self.policy.clone()));
+            }
+            Some(Err(err)) => {
+                debug!("invalid initial request: {}", err);
+                return Err(IoError::new(
+                    ErrorKind::PermissionDenied,
+                    "authenticate must be first request",
+                )
+                .into());
+            }
+            None => return Err(IoError::new(ErrorKind::Interrupted, "connection closed").into()),
+        };
+
+        let identity = self.credentials.authenticate(&request.request);
+        let response = match &identity {
+            Some(identity) => AuthenticateResponse {
+                error_code: ErrorCode::None,
+                principal: identity.principal.clone(),
+            },
+            None => AuthenticateResponse {
+                error_code: ErrorCode::AuthenticationFailed,
+                ..Default::default()
+            },
+        };
+
+        socket
+            .get_mut_sink()
+            .send_response(
+                &request.new_response(response),
+                request.header.api_version(),
+            )
+            .await
+            .map_err(|_| {
+                IoError::new(
+                    ErrorKind::Interrupted,
+                    "connection interrupted during response",
+                )
+            })?;
+
+        match identity {
+            Some(identity) => {
+                debug!(principal = %identity.principal, "authenticated");
+                Ok(BasicAuthContext::new(identity, self.policy.clone()))
+            }
+            None => {
+                debug!(username = %request.request.username, "authentication failed");
+                Err(IoError::new(ErrorKind::PermissionDenied, "authentication failed").into())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::collections::HashMap;
+
+    use dataplane::authenticate::AuthenticateRequest;
+
+    use super::{Credential, CredentialKind, CredentialStore, hash_secret};
+
+    const ITERATIONS: u32 = 1000;
+
+    fn credential(kind: CredentialKind, secret: &str, scopes: Vec<String>) -> Credential {
+        Credential {
+            kind,
+            salt: "salt".to_owned(),
+            iterations: ITERATIONS,
+            hash: hash_secret("salt", secret, ITERATIONS),
+            scopes,
+        }
+    }
+
+    #[test]
+    fn test_hash_secret() {
+        assert_eq!(
+            hash_secret("K3mZ9q", "ci-secret", ITERATIONS),
+            "9c4e7d2267969803cacdcf42c04459edac7263b75d1d3cd29ea238b74e071b78"
+        );
+        assert_ne!(
+            hash_secret("K3mZ9q", "ci-secret", ITERATIONS),
+            hash_secret("K3mZ9q", "ci-secret", ITERATIONS + 1)
+        );
+    }
+
+    #[test]
+    fn test_authenticate() {
+        let mut credentials = HashMap::new();
+        credentials.insert(
+            "ci".to_owned(),
+            credential(
+                CredentialKind::Password,
+                "secret",
+                vec!["Default".to_owned()],
+            ),
+        );
+        credentials.insert(
+            "worker".to_owned(),
+            credential(CredentialKind::Token, "abcdef", vec!["Root".to_owned()]),
+        );
+        let store = CredentialStore(credentials);
+
+        let identity = store
+            .authenticate(&AuthenticateRequest::password(
+                "ci".to_owned(),
+                "secret".to_owned(),
+            ))
+            .expect("authenticated");
+        assert_eq!(identity.principal, "ci");
+        assert_eq!(identity.scopes, vec!["Default".to_owned()]);
+
+        assert!(store
+            .authenticate(&AuthenticateRequest::password(
+                "ci".to_owned(),
+                "wrong".to_owned()
+            ))
+            .is_none());
+        assert!(store
+            .authenticate(&AuthenticateRequest::password(
+                "unknown".to_owned(),
+                "secret".to_owned()
+            ))
+            .is_none());
+
+        // token can't be used as password
+        assert!(store
+            .authenticate(&AuthenticateRequest::password(
+                "worker".to_owned(),
+                "abcdef".to_owned()
+            ))
+            .is_none());
+
+        let identity = store
+            .authenticate(&AuthenticateRequest::token("abcdef".to_owned()))
+            .expect("authenticated");
+        assert_eq!(identity.principal, "worker");
+        assert!(store
+            .authenticate(&AuthenticateRequest::token("secret".to_owned()))
+            .is_none());
+    }
+}
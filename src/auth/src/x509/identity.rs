@@ -2,10 +2,10 @@ use serde::{Serialize, Deserialize};
 
 use futures_util::stream::StreamExt;
 
-use fluvio_protocol::api::{ResponseMessage};
+use fluvio_protocol::api::{ResponseMessage, RequestMessage};
 use fluvio_socket::FluvioSocket;
 
-use super::request::{AuthorizationScopes, AuthorizationApiRequest, AuthResponse};
+use super::request::{AuthorizationScopes, AuthorizationApiRequest, AuthRequest, AuthResponse};
 
 #[derive(Debug, Default, Clone, Deserialize, Serialize)]
 pub struct X509Identity {
@@ -24,7 +24,7 @@ impl X509Identity {
 
     /// extract x509 identity from TCP Socket
     pub async fn create_from_connection(socket: &mut FluvioSocket) -> Result<Self, std::io::Error> {
-        let identity = {
+        let req_msg = {
             let stream = &mut socket.get_mut_stream();
 
             let mut api_stream = stream.api_stream::<AuthorizationApiRequest, _>();
@@ -32,10 +32,7 @@ impl X509Identity {
             if let Some(msg) = api_stream.next().await {
                 match msg {
                     Ok(req_msg) => match req_msg {
-                        AuthorizationApiRequest::AuthRequest(req_msg) => Self {
-                            scopes: req_msg.request.scopes,
-                            principal: req_msg.request.principal,
-                        },
+                        AuthorizationApiRequest::AuthRequest(req_msg) => req_msg,
                     },
                     Err(_e) => {
                         return Err(std::io::Error::new(
@@ -53,6 +50,19 @@ impl X509Identity {
             }
         };
 
+        Self::accept_request(socket, req_msg).await
+    }
+
+    /// acknowledge identity sent by TLS proxy
+    pub(crate) async fn accept_request(
+        socket: &mut FluvioSocket,
+        req_msg: RequestMessage<AuthRequest>,
+    ) -> Result<Self, std::io::Error> {
+        let identity = Self {
+            scopes: req_msg.request.scopes,
+            principal: req_msg.request.principal,
+        };
+
         let sink = &mut socket.get_mut_sink();
 
         let response = AuthResponse { success: true };
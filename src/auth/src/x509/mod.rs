@@ -1,7 +1,7 @@
 #[cfg(unix)]
 mod authenticator;
 mod identity;
-mod request;
+pub(crate) mod request;
 
 #[cfg(unix)]
 pub use authenticator::*;
@@ -5,19 +5,22 @@
 //!
 
 use std::{io::Error as IoError, path::PathBuf};
+use std::collections::BTreeMap;
 use std::io::ErrorKind;
 use tracing::{debug, trace, instrument};
 use structopt::StructOpt;
 use structopt::clap::arg_enum;
-use fluvio_future::io::StreamExt;
+use fluvio_future::io::{Stream, StreamExt};
 
 mod record_format;
 
-use fluvio::{Fluvio, PartitionConsumer, Offset, ConsumerConfig, FluvioError};
+use fluvio::{Fluvio, PartitionConsumer, TopicConsumer, Offset, ConsumerConfig, FluvioError, Header};
+use fluvio::dataplane::Timestamp;
 use fluvio_sc_schema::ApiError;
 use fluvio::consumer::Record;
+use fluvio::metadata::smartmodule::{SmartModuleSpec, SmartModuleKind};
 
-use crate::Result;
+use crate::{Result, CliError};
 use crate::common::FluvioExtensionMetadata;
 use self::record_format::{
     format_text_record, format_binary_record, format_dynamic_record, format_raw_record, format_json,
@@ -25,6 +28,15 @@ use self::record_format::{
 
 const DEFAULT_TAIL: u32 = 10;
 
+/// parse SmartStream parameter of form "key=value"
+fn parse_key_val(param: &str) -> std::result::Result<(String, String), String> {
+    let mut parts = param.splitn(2, '=');
+    match (parts.next(), parts.next()) {
+        (Some(key), Some(value)) if !key.is_empty() => Ok((key.to_owned(), value.to_owned())),
+        _ => Err(format!("invalid parameter {}, expected key=value", param)),
+    }
+}
+
 /// Read messages from a topic/partition
 ///
 /// By default, consume operates in "streaming" mode, where the command will remain
@@ -40,6 +52,10 @@ pub struct ConsumeOpt {
     #[structopt(short = "p", long, default_value = "0", value_name = "integer")]
     pub partition: i32,
 
+    /// Consume records from all partitions of the topic
+    #[structopt(short = "A", long, conflicts_with = "disable_continuous")]
+    pub all_partitions: bool,
+
     /// disable continuous processing of messages
     #[structopt(short = "d", long)]
     pub disable_continuous: bool,
@@ -48,18 +64,30 @@ pub struct ConsumeOpt {
     #[structopt(short, long)]
     pub key_value: bool,
 
+    /// Print record headers in "{key=value, ...}" format before each record
+    #[structopt(long)]
+    pub headers: bool,
+
+    /// Print timestamp of each record, in milliseconds since unix epoch
+    #[structopt(long)]
+    pub timestamps: bool,
+
     /// Consume records starting X from the beginning of the log (default: 0)
-    #[structopt(short = "B", value_name = "integer", conflicts_with_all = &["offset", "tail"])]
+    #[structopt(short = "B", value_name = "integer", conflicts_with_all = &["offset", "tail", "from_timestamp"])]
     pub from_beginning: Option<Option<u32>>,
 
     /// The offset of the first record to begin consuming from
-    #[structopt(short, long, value_name = "integer", conflicts_with_all = &["from_beginning", "tail"])]
+    #[structopt(short, long, value_name = "integer", conflicts_with_all = &["from_beginning", "tail", "from_timestamp"])]
     pub offset: Option<u32>,
 
     /// Consume records starting X from the end of the log (default: 10)
-    #[structopt(long, value_name = "integer", conflicts_with_all = &["from_beginning", "offset"])]
+    #[structopt(long, value_name = "integer", conflicts_with_all = &["from_beginning", "offset", "from_timestamp"])]
     pub tail: Option<Option<u32>>,
 
+    /// Consume records starting from the first record at or after timestamp, in milliseconds since unix epoch
+    #[structopt(long, value_name = "integer", conflicts_with_all = &["from_beginning", "offset", "tail"])]
+    pub from_timestamp: Option<u64>,
+
     /// Maximum number of bytes to be retrieved
     #[structopt(short = "b", long = "maxbytes", value_name = "integer")]
     pub max_bytes: Option<i32>,
@@ -79,21 +107,53 @@ pub struct ConsumeOpt {
     )]
     pub output: ConsumeOutputType,
 
-    /// Path to a SmartStream filter wasm file
-    #[structopt(long, group("smartstream"))]
-    pub filter: Option<PathBuf>,
-
-    /// Path to a SmartStream map wasm file
-    #[structopt(long, group("smartstream"))]
-    pub map: Option<PathBuf>,
-
-    /// Path to a WASM file for aggregation
-    #[structopt(long, group("smartstream"))]
+    /// Path to a SmartStream filter wasm file, may be repeated
+    ///
+    /// SmartStreams are chained in order of filters, maps, array-maps,
+    /// filter-maps and aggregate, each kind in the order given.
+    #[structopt(long, number_of_values = 1)]
+    pub filter: Vec<PathBuf>,
+
+    /// Path to a SmartStream map wasm file, may be repeated
+    #[structopt(long, number_of_values = 1)]
+    pub map: Vec<PathBuf>,
+
+    /// Path to a SmartStream array-map wasm file, may be repeated
+    #[structopt(long, number_of_values = 1)]
+    pub array_map: Vec<PathBuf>,
+
+    /// Path to a SmartStream filter-map wasm file, may be repeated
+    #[structopt(long, number_of_values = 1)]
+    pub filter_map: Vec<PathBuf>,
+
+    /// Name of a SmartModule stored in the cluster, may be repeated
+    ///
+    /// SmartModules are applied after SmartStreams given as files,
+    /// except the --aggregate file which is always last.
+    #[structopt(long, value_name = "name", number_of_values = 1)]
+    pub smartmodule: Vec<String>,
+
+    /// Path to a WASM file for aggregation, applied after other SmartStreams
+    #[structopt(long)]
     pub aggregate: Option<PathBuf>,
 
     /// (Optional) Path to a file to use as an initial accumulator value with --aggregate
     #[structopt(long)]
     pub initial: Option<PathBuf>,
+
+    /// Parameter passed to the SmartStream, may be repeated
+    #[structopt(
+        short = "e",
+        long = "params",
+        value_name = "key=value",
+        parse(try_from_str = parse_key_val),
+        number_of_values = 1
+    )]
+    pub params: Vec<(String, String)>,
+
+    /// SmartModules given by name, along with the kind stored in the cluster
+    #[structopt(skip)]
+    smartmodule_kinds: Vec<(String, SmartModuleKind)>,
 }
 
 impl ConsumeOpt {
@@ -102,11 +162,41 @@ impl ConsumeOpt {
         name = "Consume",
         fields(topic = %self.topic, partition = self.partition),
     )]
-    pub async fn process(self, fluvio: &Fluvio) -> Result<()> {
-        let consumer = fluvio
-            .partition_consumer(&self.topic, self.partition)
+    pub async fn process(mut self, fluvio: &Fluvio) -> Result<()> {
+        self.resolve_smartmodules(fluvio).await?;
+
+        if self.all_partitions {
+            let consumer = fluvio.topic_consumer(&self.topic).await?;
+            self.consume_topic_records(consumer).await?;
+        } else {
+            let consumer = fluvio
+                .partition_consumer(&self.topic, self.partition)
+                .await?;
+            self.consume_records(consumer).await?;
+        }
+        Ok(())
+    }
+
+    /// look up kind of SmartModules given by name
+    async fn resolve_smartmodules(&mut self, fluvio: &Fluvio) -> Result<()> {
+        if self.smartmodule.is_empty() {
+            return Ok(());
+        }
+
+        let admin = fluvio.admin().await;
+        let smartmodules = admin
+            .list::<SmartModuleSpec, _>(self.smartmodule.clone())
             .await?;
-        self.consume_records(consumer).await?;
+
+        for name in &self.smartmodule {
+            let smartmodule = smartmodules
+                .iter()
+                .find(|smartmodule| &smartmodule.name == name)
+                .ok_or_else(|| CliError::invalid_arg(format!("smartmodule {} not found", name)))?;
+            self.smartmodule_kinds
+                .push((name.clone(), smartmodule.spec.kind.clone()));
+        }
+
         Ok(())
     }
 
@@ -124,23 +214,93 @@ impl ConsumeOpt {
         self.init_ctrlc()?;
         let offset = self.calculate_offset()?;
 
+        let consume_config = match self.consumer_config()? {
+            Some(config) => config,
+            None => return Ok(()),
+        };
+        if self.disable_continuous {
+            self.consume_records_batch(&consumer, offset, consume_config)
+                .await?;
+        } else {
+            self.print_status();
+            let stream = consumer.stream_with_config(offset, consume_config).await?;
+            self.consume_records_stream(stream).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Consume records from all partitions of the topic as a stream
+    pub async fn consume_topic_records(&self, consumer: TopicConsumer) -> Result<()> {
+        trace!(config = ?self, "Starting topic consumer:");
+        self.init_ctrlc()?;
+        let offset = self.calculate_offset()?;
+
+        let consume_config = match self.consumer_config()? {
+            Some(config) => config,
+            None => return Ok(()),
+        };
+        self.print_status();
+        let stream = consumer.stream_with_config(offset, consume_config).await?;
+        self.consume_records_stream(stream).await?;
+
+        Ok(())
+    }
+
+    /// Build consumer config from options, None if options are invalid
+    fn consumer_config(&self) -> Result<Option<ConsumerConfig>> {
         let mut builder = ConsumerConfig::builder();
         if let Some(max_bytes) = self.max_bytes {
             builder.max_bytes(max_bytes);
         }
 
-        if let Some(filter_path) = &self.filter {
+        for filter_path in &self.filter {
             let buffer = std::fs::read(filter_path)?;
             debug!(len = buffer.len(), "read filter bytes");
             builder.wasm_filter(buffer);
         }
 
-        if let Some(map_path) = &self.map {
+        for map_path in &self.map {
             let buffer = std::fs::read(map_path)?;
-            debug!(len = buffer.len(), "read filter bytes");
+            debug!(len = buffer.len(), "read map bytes");
             builder.wasm_map(buffer);
         }
 
+        for array_map_path in &self.array_map {
+            let buffer = std::fs::read(array_map_path)?;
+            debug!(len = buffer.len(), "read array-map bytes");
+            builder.wasm_array_map(buffer);
+        }
+
+        for filter_map_path in &self.filter_map {
+            let buffer = std::fs::read(filter_map_path)?;
+            debug!(len = buffer.len(), "read filter-map bytes");
+            builder.wasm_filter_map(buffer);
+        }
+
+        let mut has_aggregate_smartmodule = false;
+        for (name, kind) in &self.smartmodule_kinds {
+            match (kind, &self.initial) {
+                (SmartModuleKind::Aggregate, Some(acc_path)) => {
+                    has_aggregate_smartmodule = true;
+                    let acc = std::fs::read(acc_path)?;
+                    builder.wasm_smartmodule_aggregate(name.clone(), acc);
+                }
+                (SmartModuleKind::Aggregate, None) => {
+                    has_aggregate_smartmodule = true;
+                    builder.wasm_smartmodule(name.clone(), kind.clone());
+                }
+                _ => {
+                    builder.wasm_smartmodule(name.clone(), kind.clone());
+                }
+            }
+        }
+
+        if !self.params.is_empty() {
+            let params: BTreeMap<String, String> = self.params.iter().cloned().collect();
+            builder.wasm_params(params);
+        }
+
         match (&self.aggregate, &self.initial) {
             (Some(wasm_path), Some(acc_path)) => {
                 let wasm = std::fs::read(wasm_path)?;
@@ -151,23 +311,14 @@ impl ConsumeOpt {
                 let wasm = std::fs::read(wasm_path)?;
                 builder.wasm_aggregate(wasm, Vec::new());
             }
-            (None, Some(_)) => {
+            (None, Some(_)) if !has_aggregate_smartmodule => {
                 println!("In order to use --accumulator, you must also specify --aggregate");
-                return Ok(());
+                return Ok(None);
             }
-            (None, None) => (),
+            (None, _) => (),
         }
 
-        let consume_config = builder.build()?;
-        if self.disable_continuous {
-            self.consume_records_batch(&consumer, offset, consume_config)
-                .await?;
-        } else {
-            self.consume_records_stream(&consumer, offset, consume_config)
-                .await?;
-        }
-
-        Ok(())
+        Ok(Some(builder.build()?))
     }
 
     /// Consume records in a single batch, then exit
@@ -188,22 +339,20 @@ impl ConsumeOpt {
         for batch in response.records.batches.iter() {
             for record in batch.records().iter() {
                 let key = record.key.as_ref().map(|it| it.as_ref());
-                self.print_record(key, record.value.as_ref());
+                let timestamp = batch
+                    .get_header()
+                    .record_timestamp(record.preamble.timestamp_delta());
+                self.print_record(key, record.headers(), timestamp, record.value.as_ref());
             }
         }
         Ok(())
     }
 
     /// Consume records as a stream, waiting for new records to arrive
-    async fn consume_records_stream(
-        &self,
-        consumer: &PartitionConsumer,
-        offset: Offset,
-        config: ConsumerConfig,
-    ) -> Result<()> {
-        self.print_status();
-        let mut stream = consumer.stream_with_config(offset, config).await?;
-
+    async fn consume_records_stream<S>(&self, mut stream: S) -> Result<()>
+    where
+        S: Stream<Item = std::result::Result<Record, FluvioError>> + Unpin,
+    {
         while let Some(result) = stream.next().await {
             let result: std::result::Result<Record, _> = result;
             let record = match result {
@@ -215,7 +364,12 @@ impl ConsumeOpt {
                 Err(other) => return Err(other.into()),
             };
 
-            self.print_record(record.key(), record.value());
+            self.print_record(
+                record.key(),
+                record.headers(),
+                record.timestamp(),
+                record.value(),
+            );
         }
 
         debug!("fetch loop exited");
@@ -224,7 +378,13 @@ impl ConsumeOpt {
     }
 
     /// Process fetch topic response based on output type
-    pub fn print_record(&self, key: Option<&[u8]>, value: &[u8]) {
+    pub fn print_record(
+        &self,
+        key: Option<&[u8]>,
+        headers: &[Header],
+        timestamp: Timestamp,
+        value: &[u8],
+    ) {
         let formatted_key = key.map(|key| {
             String::from_utf8(key.to_owned())
                 .unwrap_or_else(|_| "<cannot print non-UTF8 key>".to_string())
@@ -238,15 +398,24 @@ impl ConsumeOpt {
             ConsumeOutputType::raw => Some(format_raw_record(value)),
         };
 
+        let mut prefix = String::new();
+        if self.timestamps {
+            prefix.push_str(&format!("{} ", timestamp));
+        }
+        if self.headers {
+            let headers: Vec<String> = headers.iter().map(|header| header.to_string()).collect();
+            prefix.push_str(&format!("{{{}}} ", headers.join(", ")));
+        }
+
         match (formatted_key, formatted_value) {
             (Some(key), Some(value)) if self.key_value => {
-                println!("[{}] {}", key, value);
+                println!("{}[{}] {}", prefix, key, value);
             }
             (None, Some(value)) if self.key_value => {
-                println!("[null] {}", value);
+                println!("{}[null] {}", prefix, value);
             }
             (_, Some(value)) => {
-                println!("{}", value);
+                println!("{}{}", prefix, value);
             }
             // (Some(_), None) only if JSON cannot be printed, so skip.
             _ => debug!("Skipping record that cannot be formatted"),
@@ -300,6 +469,16 @@ impl ConsumeOpt {
                 )
                 .bold()
             );
+        // If --from-timestamp=X
+        } else if let Some(timestamp) = self.from_timestamp {
+            eprintln!(
+                "{}",
+                format!(
+                    "Consuming records from timestamp {} in topic '{}'",
+                    timestamp, &self.topic
+                )
+                .bold()
+            );
         // If no offset config is given, read from the end
         } else {
             eprintln!(
@@ -340,6 +519,8 @@ impl ConsumeOpt {
         } else if let Some(maybe_tail) = self.tail {
             let tail = maybe_tail.unwrap_or(DEFAULT_TAIL);
             Offset::from_end(tail)
+        } else if let Some(timestamp) = self.from_timestamp {
+            Offset::from_timestamp(timestamp)
         } else {
             Offset::end()
         };
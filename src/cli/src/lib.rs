@@ -19,11 +19,13 @@ mod topic;
 mod consume;
 mod produce;
 mod partition;
+mod smartmodule;
 
 use topic::TopicCmd;
 use consume::ConsumeOpt;
 use produce::ProduceOpt;
 use partition::PartitionCmd;
+use smartmodule::SmartModuleCmd;
 use profile::ProfileOpt;
 use install::update::UpdateOpt;
 use install::plugins::InstallOpt;
@@ -210,6 +212,14 @@ pub enum FluvioCmd {
     /// total throughput of the Topic.
     #[structopt(name = "partition")]
     Partition(PartitionCmd),
+
+    /// Manage and view SmartModules
+    ///
+    /// SmartModules are compiled SmartStreams stored in the cluster by name.
+    /// Consumers can refer to them by name instead of uploading the WASM
+    /// module with every stream.
+    #[structopt(name = "smartmodule")]
+    SmartModule(SmartModuleCmd),
 }
 
 impl FluvioCmd {
@@ -231,6 +241,9 @@ impl FluvioCmd {
             Self::Partition(partition) => {
                 partition.process(out, &fluvio).await?;
             }
+            Self::SmartModule(smartmodule) => {
+                smartmodule.process(out, &fluvio).await?;
+            }
         }
 
         Ok(())
@@ -5,6 +5,7 @@ use structopt::StructOpt;
 use crate::Result;
 use crate::TopicCmd;
 use crate::PartitionCmd;
+use crate::SmartModuleCmd;
 use crate::ConsumeOpt;
 use crate::ProduceOpt;
 use fluvio_command::CommandExt;
@@ -26,6 +27,7 @@ impl MetadataOpt {
         let mut metadata = vec![
             TopicCmd::metadata(),
             PartitionCmd::metadata(),
+            SmartModuleCmd::metadata(),
             ProduceOpt::metadata(),
             ConsumeOpt::metadata(),
         ];
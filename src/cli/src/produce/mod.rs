@@ -4,7 +4,7 @@ use std::path::PathBuf;
 use structopt::StructOpt;
 use tracing::{error, debug};
 
-use fluvio::{Fluvio, TopicProducer, RecordKey};
+use fluvio::{Fluvio, TopicProducer, RecordKey, ProducerConfig, Compression, Acks};
 use fluvio_types::print_cli_ok;
 use crate::common::FluvioExtensionMetadata;
 use crate::{Result, CliError};
@@ -45,6 +45,24 @@ pub struct ProduceOpt {
     /// Path to a file to produce to the topic. If absent, producer will read stdin.
     #[structopt(short, long)]
     pub file: Option<PathBuf>,
+
+    /// Compression codec used for records sent to the topic
+    #[structopt(
+        long,
+        value_name = "codec",
+        default_value = "none",
+        possible_values = &["none", "gzip", "snappy", "lz4", "zstd"]
+    )]
+    pub compression: Compression,
+
+    /// Acknowledgments required from SPU: 0 (none), 1 (leader) or all (in-sync replicas)
+    #[structopt(
+        long,
+        value_name = "acks",
+        default_value = "1",
+        possible_values = &["0", "1", "all", "none", "leader"]
+    )]
+    pub acks: Acks,
 }
 
 fn validate_key_separator(separator: String) -> std::result::Result<(), String> {
@@ -58,7 +76,13 @@ fn validate_key_separator(separator: String) -> std::result::Result<(), String>
 
 impl ProduceOpt {
     pub async fn process(self, fluvio: &Fluvio) -> Result<()> {
-        let producer = fluvio.topic_producer(&self.topic).await?;
+        let config = ProducerConfig::builder()
+            .compression(self.compression)
+            .acks(self.acks)
+            .build()?;
+        let producer = fluvio
+            .topic_producer_with_config(&self.topic, config)
+            .await?;
 
         if self.raw {
             // Read all input and send as one record
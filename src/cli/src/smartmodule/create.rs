@@ -0,0 +1,57 @@
+//!
+//! # Create SmartModule
+//!
+//! CLI tree to upload a WASM SmartStream to the cluster
+//!
+
+use std::path::PathBuf;
+
+use tracing::debug;
+use structopt::StructOpt;
+
+use fluvio::Fluvio;
+use fluvio::metadata::smartmodule::{SmartModuleSpec, SmartModuleKind};
+use crate::Result;
+
+#[derive(Debug, StructOpt)]
+pub struct CreateSmartModuleOpt {
+    /// The name of the SmartModule to create
+    #[structopt(value_name = "name")]
+    name: String,
+
+    /// Path to the compiled WASM module
+    #[structopt(long = "wasm-file", value_name = "file.wasm", parse(from_os_str))]
+    wasm_file: PathBuf,
+
+    /// Type of SmartStream the module implements
+    #[structopt(
+        long = "kind",
+        value_name = "kind",
+        default_value = "filter",
+        possible_values = &["filter", "map", "array-map", "filter-map", "aggregate"]
+    )]
+    kind: SmartModuleKind,
+
+    /// Validates the SmartModule, does not store it
+    #[structopt(short = "d", long)]
+    dry_run: bool,
+}
+
+impl CreateSmartModuleOpt {
+    pub async fn process(self, fluvio: &Fluvio) -> Result<()> {
+        let wasm = std::fs::read(&self.wasm_file)?;
+        debug!(
+            "creating smartmodule: {}, kind: {}, size: {}",
+            self.name,
+            self.kind,
+            wasm.len()
+        );
+
+        let spec = SmartModuleSpec::new(self.kind, wasm);
+        let admin = fluvio.admin().await;
+        admin.create(self.name.clone(), self.dry_run, spec).await?;
+        println!("smartmodule \"{}\" created", self.name);
+
+        Ok(())
+    }
+}
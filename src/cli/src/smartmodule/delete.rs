@@ -0,0 +1,29 @@
+//!
+//! # Delete SmartModule
+//!
+//! CLI tree to delete a SmartModule
+//!
+
+use tracing::debug;
+use structopt::StructOpt;
+
+use fluvio::Fluvio;
+use fluvio::metadata::smartmodule::SmartModuleSpec;
+use crate::Result;
+
+#[derive(Debug, StructOpt)]
+pub struct DeleteSmartModuleOpt {
+    /// The name of the SmartModule to delete
+    #[structopt(value_name = "name")]
+    name: String,
+}
+
+impl DeleteSmartModuleOpt {
+    pub async fn process(self, fluvio: &Fluvio) -> Result<()> {
+        debug!("deleting smartmodule: {}", &self.name);
+        let admin = fluvio.admin().await;
+        admin.delete::<SmartModuleSpec, _>(&self.name).await?;
+        println!("smartmodule \"{}\" deleted", &self.name);
+        Ok(())
+    }
+}
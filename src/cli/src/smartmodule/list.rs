@@ -0,0 +1,112 @@
+//!
+//! # List SmartModules
+//!
+//! CLI tree and processing to list SmartModules
+//!
+
+use structopt::StructOpt;
+
+use fluvio::Fluvio;
+use fluvio::metadata::smartmodule::SmartModuleSpec;
+
+use crate::Result;
+use crate::common::output::Terminal;
+use crate::common::OutputFormat;
+
+/// Option for Listing SmartModules
+#[derive(Debug, StructOpt)]
+pub struct ListSmartModulesOpt {
+    #[structopt(flatten)]
+    output: OutputFormat,
+}
+
+impl ListSmartModulesOpt {
+    /// perform actions
+    pub async fn process<O>(self, out: std::sync::Arc<O>, fluvio: &Fluvio) -> Result<()>
+    where
+        O: Terminal,
+    {
+        let output = self.output.format;
+        let admin = fluvio.admin().await;
+
+        let smartmodules = admin.list::<SmartModuleSpec, _>(vec![]).await?;
+
+        // format and dump to screen
+        display::format_smartmodule_response_output(out, smartmodules, output)?;
+        Ok(())
+    }
+}
+
+mod display {
+
+    use prettytable::Row;
+    use prettytable::row;
+    use prettytable::cell;
+    use serde::Serialize;
+
+    use fluvio::metadata::objects::Metadata;
+    use fluvio::metadata::smartmodule::SmartModuleSpec;
+
+    use crate::common::t_println;
+    use crate::common::output::{OutputType, OutputError, Terminal, TableOutputHandler};
+
+    #[derive(Serialize)]
+    struct ListSmartModules(Vec<Metadata<SmartModuleSpec>>);
+
+    impl IntoIterator for ListSmartModules {
+        type Item = Metadata<SmartModuleSpec>;
+        type IntoIter = std::vec::IntoIter<Self::Item>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.0.into_iter()
+        }
+    }
+
+    /// Process server based on output type
+    pub fn format_smartmodule_response_output<O>(
+        out: std::sync::Arc<O>,
+        smartmodules: Vec<Metadata<SmartModuleSpec>>,
+        output_type: OutputType,
+    ) -> Result<(), OutputError>
+    where
+        O: Terminal,
+    {
+        if !smartmodules.is_empty() {
+            let list = ListSmartModules(smartmodules);
+            out.render_list(&list, output_type)?;
+        } else {
+            t_println!(out, "No smartmodules found");
+        }
+
+        Ok(())
+    }
+
+    impl TableOutputHandler for ListSmartModules {
+        /// table header implementation
+        fn header(&self) -> Row {
+            row!["NAME", "KIND", "SIZE", "CHECKSUM"]
+        }
+
+        /// return errors in string format
+        fn errors(&self) -> Vec<String> {
+            vec![]
+        }
+
+        fn content(&self) -> Vec<Row> {
+            let mut metadata: Vec<_> = self.0.iter().collect();
+            metadata.sort_by(|a, b| a.name.cmp(&b.name));
+            metadata
+                .into_iter()
+                .map(|metadata| {
+                    let spec = &metadata.spec;
+                    row![
+                        l -> metadata.name,
+                        l -> spec.kind.to_string(),
+                        r -> spec.wasm.len().to_string(),
+                        l -> spec.checksum
+                    ]
+                })
+                .collect()
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+use structopt::StructOpt;
+
+mod create;
+mod delete;
+mod list;
+
+use create::CreateSmartModuleOpt;
+use delete::DeleteSmartModuleOpt;
+use list::ListSmartModulesOpt;
+
+use fluvio::Fluvio;
+
+use crate::Result;
+use crate::common::COMMAND_TEMPLATE;
+use crate::common::output::Terminal;
+use crate::common::FluvioExtensionMetadata;
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "smartmodule", about = "SmartModule operations")]
+pub enum SmartModuleCmd {
+    /// Upload a compiled SmartStream WASM module with the given name
+    #[structopt(
+        name = "create",
+        template = COMMAND_TEMPLATE,
+    )]
+    Create(CreateSmartModuleOpt),
+
+    /// Delete a SmartModule with the given name
+    #[structopt(
+        name = "delete",
+        template = COMMAND_TEMPLATE,
+    )]
+    Delete(DeleteSmartModuleOpt),
+
+    /// List all of the SmartModules in the cluster
+    #[structopt(
+        name = "list",
+        template = COMMAND_TEMPLATE,
+    )]
+    List(ListSmartModulesOpt),
+}
+
+impl SmartModuleCmd {
+    pub async fn process<O: Terminal>(self, out: Arc<O>, fluvio: &Fluvio) -> Result<()> {
+        match self {
+            Self::Create(create) => {
+                create.process(fluvio).await?;
+            }
+            Self::Delete(delete) => {
+                delete.process(fluvio).await?;
+            }
+            Self::List(list) => {
+                list.process(out, fluvio).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn metadata() -> FluvioExtensionMetadata {
+        FluvioExtensionMetadata {
+            title: "smartmodule".into(),
+            package: Some("fluvio/fluvio".parse().unwrap()),
+            description: "SmartModule Operations".into(),
+            version: semver::Version::parse(env!("CARGO_PKG_VERSION")).unwrap(),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//!
+//! # Add Partitions
+//!
+//! CLI tree to add partitions to existing Topic
+//!
+
+use tracing::debug;
+use structopt::StructOpt;
+
+use fluvio::Fluvio;
+use fluvio::metadata::topic::TopicSpec;
+use fluvio::metadata::objects::TopicUpdate;
+use crate::{Result, CliError};
+
+#[derive(Debug, StructOpt)]
+pub struct AddPartitionsOpt {
+    /// The name of the Topic to add partitions to
+    #[structopt(value_name = "name")]
+    topic: String,
+
+    /// The number of Partitions to add to the Topic
+    ///
+    /// Existing partitions and their records are not changed. Records with
+    /// a key may be sent to a different partition after partitions are added.
+    #[structopt(short = "c", long = "count", value_name = "count")]
+    count: i32,
+}
+
+impl AddPartitionsOpt {
+    pub async fn process(self, fluvio: &Fluvio) -> Result<()> {
+        if self.count <= 0 {
+            return Err(CliError::invalid_arg("count must be greater than 0"));
+        }
+
+        let admin = fluvio.admin().await;
+        let topics = admin.list::<TopicSpec, _>(vec![self.topic.clone()]).await?;
+        let current = match topics.into_iter().find(|topic| topic.name == self.topic) {
+            Some(topic) => topic.spec.partitions(),
+            None => {
+                return Err(CliError::invalid_arg(format!(
+                    "topic \"{}\" not found",
+                    self.topic
+                )))
+            }
+        };
+
+        let partitions = current + self.count;
+        debug!(
+            "adding partitions to topic: {}, {} to {}",
+            &self.topic, current, partitions
+        );
+        admin
+            .update(self.topic.clone(), TopicUpdate::partitions(partitions))
+            .await?;
+        println!(
+            "topic \"{}\" partitions increased from {} to {}",
+            &self.topic, current, partitions
+        );
+        Ok(())
+    }
+}
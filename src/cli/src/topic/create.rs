@@ -12,7 +12,11 @@ use tracing::debug;
 use structopt::StructOpt;
 
 use fluvio::Fluvio;
-use fluvio::metadata::topic::TopicSpec;
+use fluvio::metadata::topic::{TopicSpec, TimestampType};
+use fluvio::metadata::topic::config::{
+    CLEANUP_POLICY, TOMBSTONE_RETENTION_SECONDS, TIMESTAMP_TYPE, RETENTION_SECONDS,
+    PARTITION_MAX_BYTES,
+};
 use crate::{Result, CliError};
 
 // -----------------------------------
@@ -77,6 +81,58 @@ pub struct CreateTopicOpt {
     )]
     replica_assignment: Option<PathBuf>,
 
+    /// How records which are no longer needed are removed
+    ///
+    /// With "delete", old segments are removed once they exceed retention time or size.
+    /// With "compact", only the latest record for each key is kept.
+    #[structopt(
+        long = "cleanup-policy",
+        value_name = "policy",
+        possible_values = &["delete", "compact"]
+    )]
+    cleanup_policy: Option<String>,
+
+    /// Seconds to keep tombstones (records with empty value) of compacted topic
+    #[structopt(
+        long = "tombstone-retention",
+        value_name = "seconds",
+        requires = "cleanup_policy"
+    )]
+    tombstone_retention: Option<u32>,
+
+    /// Seconds to keep records of topic, oldest segments are removed after that.
+    /// By default records are kept unless SPU sets retention
+    #[structopt(long = "retention-time", value_name = "seconds")]
+    retention_time: Option<u32>,
+
+    /// Max bytes of each partition, oldest segments are removed when exceeded
+    #[structopt(long = "max-partition-size", value_name = "bytes")]
+    max_partition_size: Option<u64>,
+
+    /// Source of record timestamps
+    ///
+    /// With "create-time", timestamp is assigned by producer.
+    /// With "log-append-time", timestamp is assigned by SPU when records are written.
+    #[structopt(
+        long = "timestamp-type",
+        value_name = "type",
+        possible_values = &["create-time", "log-append-time"]
+    )]
+    timestamp_type: Option<TimestampType>,
+
+    /// Topic config overriding storage settings of SPU, may be repeated
+    ///
+    /// Supported keys are "retention.seconds", "segment.max.bytes", "partition.max.bytes",
+    /// "max.message.bytes", "min.insync.replicas", "unclean.leader.election.enable",
+    /// "cleanup.policy", "tombstone.retention.seconds" and "timestamp.type".
+    #[structopt(
+        long = "config",
+        value_name = "key=value",
+        parse(try_from_str = parse_key_val),
+        number_of_values = 1
+    )]
+    config: Vec<(String, String)>,
+
     /// Validates configuration, does not provision
     #[structopt(short = "d", long)]
     dry_run: bool,
@@ -101,7 +157,7 @@ impl CreateTopicOpt {
         use fluvio::metadata::topic::TopicReplicaParam;
         use load::PartitionLoad;
 
-        let topic = if let Some(replica_assign_file) = &self.replica_assignment {
+        let mut topic = if let Some(replica_assign_file) = &self.replica_assignment {
             TopicSpec::Assigned(
                 PartitionMaps::file_decode(replica_assign_file).map_err(|err| {
                     IoError::new(
@@ -114,13 +170,43 @@ impl CreateTopicOpt {
                 })?,
             )
         } else {
-            TopicSpec::Computed(TopicReplicaParam {
-                partitions: self.partitions,
-                replication_factor: self.replication as i32,
-                ignore_rack_assignment: self.ignore_rack_assigment,
-            })
+            TopicSpec::Computed(TopicReplicaParam::new(
+                self.partitions,
+                self.replication as i32,
+                self.ignore_rack_assigment,
+            ))
         };
 
+        if let Some(policy) = &self.cleanup_policy {
+            topic
+                .set_config(CLEANUP_POLICY, policy)
+                .map_err(CliError::InvalidArg)?;
+        }
+        if let Some(seconds) = self.tombstone_retention {
+            topic
+                .set_config(TOMBSTONE_RETENTION_SECONDS, &seconds.to_string())
+                .map_err(CliError::InvalidArg)?;
+        }
+        if let Some(timestamp_type) = self.timestamp_type {
+            topic
+                .set_config(TIMESTAMP_TYPE, timestamp_type.label())
+                .map_err(CliError::InvalidArg)?;
+        }
+        if let Some(seconds) = self.retention_time {
+            topic
+                .set_config(RETENTION_SECONDS, &seconds.to_string())
+                .map_err(CliError::InvalidArg)?;
+        }
+        if let Some(bytes) = self.max_partition_size {
+            topic
+                .set_config(PARTITION_MAX_BYTES, &bytes.to_string())
+                .map_err(CliError::InvalidArg)?;
+        }
+        for (key, value) in &self.config {
+            topic.set_config(key, value).map_err(CliError::InvalidArg)?;
+        }
+        topic.config().validate().map_err(CliError::InvalidArg)?;
+
         let is_valid = hostname_validator::is_valid(&self.topic);
         if !is_valid {
             return Err(CliError::InvalidArg(
@@ -133,6 +219,15 @@ impl CreateTopicOpt {
     }
 }
 
+/// parse config of form "key=value"
+fn parse_key_val(config: &str) -> std::result::Result<(String, String), String> {
+    let mut parts = config.splitn(2, '=');
+    match (parts.next(), parts.next()) {
+        (Some(key), Some(value)) if !key.is_empty() => Ok((key.to_owned(), value.to_owned())),
+        _ => Err(format!("invalid config {}, expected key=value", config)),
+    }
+}
+
 /// module to load partitions maps from file
 mod load {
 
@@ -50,7 +50,8 @@ mod display {
     use serde::Serialize;
 
     use fluvio::metadata::objects::Metadata;
-    use fluvio::metadata::topic::TopicSpec;
+    use fluvio::metadata::topic::{TopicSpec, TopicConfig};
+    use fluvio::metadata::topic::config::TOPIC_CONFIG_KEYS;
 
     use crate::common::output::{
         OutputType, OutputError, DescribeObjectHandler, KeyValOutputHandler, TableOutputHandler,
@@ -143,6 +144,17 @@ mod display {
                     */
                 }
             }
+            // effective value of keys which are not set depends on SPU, only built-in default is known
+            for key in TOPIC_CONFIG_KEYS {
+                let value = match spec.config().get(key) {
+                    Some(value) => value.to_owned(),
+                    None => format!(
+                        "not set (default: {})",
+                        TopicConfig::default_value(key).unwrap_or_else(|| "none".to_owned())
+                    ),
+                };
+                key_values.push((key.to_string(), Some(value)));
+            }
 
             key_values.push((
                 "Status".to_owned(),
@@ -5,11 +5,13 @@ mod create;
 mod delete;
 mod describe;
 mod list;
+mod add_partitions;
 
 use create::CreateTopicOpt;
 use delete::DeleteTopicOpt;
 use describe::DescribeTopicsOpt;
 use list::ListTopicsOpt;
+use add_partitions::AddPartitionsOpt;
 
 use fluvio::Fluvio;
 
@@ -48,6 +50,13 @@ pub enum TopicCmd {
         template = COMMAND_TEMPLATE,
     )]
     List(ListTopicsOpt),
+
+    /// Add Partitions to a Topic with the given name
+    #[structopt(
+        name = "add-partitions",
+        template = COMMAND_TEMPLATE,
+    )]
+    AddPartitions(AddPartitionsOpt),
 }
 
 impl TopicCmd {
@@ -65,6 +74,9 @@ impl TopicCmd {
             Self::List(list) => {
                 list.process(out, fluvio).await?;
             }
+            Self::AddPartitions(add_partitions) => {
+                add_partitions.process(fluvio).await?;
+            }
         }
 
         Ok(())
@@ -5,7 +5,9 @@ use fluvio_future::net::DomainConnector;
 use tracing::{debug, instrument};
 use dataplane::core::Encoder;
 use dataplane::core::Decoder;
-use fluvio_sc_schema::objects::{Metadata, AllCreatableSpec};
+use fluvio_sc_schema::objects::{Metadata, AllCreatableSpec, AllUpdatableSpec, UpdateRequest};
+use fluvio_sc_schema::election::{ElectLeadersRequest, ElectionResult};
+use fluvio_sc_schema::audit::{AuditLogRequest, AuditRecord};
 use fluvio_sc_schema::AdminRequest;
 use fluvio_socket::SocketError;
 use fluvio_socket::MultiplexerSocket;
@@ -109,7 +111,8 @@ impl FluvioAdmin {
     #[instrument(skip(config))]
     pub async fn connect_with_config(config: &FluvioConfig) -> Result<Self, FluvioError> {
         let connector = DomainConnector::try_from(config.tls.clone())?;
-        let config = ClientConfig::new(&config.endpoint, connector);
+        let config = ClientConfig::new(&config.endpoint, connector)
+            .with_credentials(config.credentials.clone());
         let inner_client = config.connect().await?;
         debug!("connected to cluster at: {}", inner_client.config().addr());
 
@@ -162,6 +165,45 @@ impl FluvioAdmin {
         Ok(())
     }
 
+    /// update existing object
+    #[instrument(skip(self, name, spec))]
+    pub async fn update<S>(&self, name: String, spec: S) -> Result<(), FluvioError>
+    where
+        S: Into<AllUpdatableSpec>,
+    {
+        let update_request = UpdateRequest {
+            name,
+            spec: spec.into(),
+        };
+
+        self.send_receive(update_request).await?.as_result()?;
+
+        Ok(())
+    }
+
+    /// move leadership of partitions back to their preferred replica.
+    /// if topics is empty, all partitions are elected.
+    /// returns result for each partition whose leader was not preferred
+    #[instrument(skip(self))]
+    pub async fn elect_leaders(
+        &self,
+        topics: Vec<String>,
+    ) -> Result<Vec<ElectionResult>, FluvioError> {
+        let response = self.send_receive(ElectLeadersRequest { topics }).await?;
+        Ok(response.results)
+    }
+
+    /// query admin actions recorded by SC, ordered from oldest to newest.
+    /// only records of objects which caller can read are returned
+    #[instrument(skip(self))]
+    pub async fn audit_log(
+        &self,
+        request: AuditLogRequest,
+    ) -> Result<Vec<AuditRecord>, FluvioError> {
+        let response = self.send_receive(request).await?;
+        Ok(response.records)
+    }
+
     #[instrument(skip(self, filters))]
     pub async fn list<S, F>(&self, filters: F) -> Result<Vec<Metadata<S>>, FluvioError>
     where
@@ -21,6 +21,9 @@ pub struct FluvioConfig {
     // use the default of NoTls
     #[serde(default)]
     pub tls: TlsPolicy,
+    /// Credentials to authenticate with when the cluster doesn't use client certificates
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<Credentials>,
 }
 
 impl FluvioConfig {
@@ -29,6 +32,7 @@ impl FluvioConfig {
         Self {
             endpoint: addr.into(),
             tls: TlsPolicy::Disabled,
+            credentials: None,
         }
     }
 
@@ -37,4 +41,31 @@ impl FluvioConfig {
         self.tls = tls.into();
         self
     }
+
+    /// Add credentials for this cluster.
+    pub fn with_credentials(mut self, credentials: Credentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+}
+
+/// Credentials sent to the cluster before any other request
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Credentials {
+    Password { username: String, password: String },
+    Token { token: String },
+}
+
+// don't leak secrets to logs
+impl std::fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Password { username, .. } => f
+                .debug_struct("Password")
+                .field("username", username)
+                .finish(),
+            Self::Token { .. } => f.debug_struct("Token").finish(),
+        }
+    }
 }
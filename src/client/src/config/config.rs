@@ -397,7 +397,7 @@ pub mod test {
     use super::*;
     use std::path::PathBuf;
     use std::env::temp_dir;
-    use crate::config::{TlsPolicy, TlsConfig, TlsCerts};
+    use crate::config::{TlsPolicy, TlsConfig, TlsCerts, Credentials};
 
     #[test]
     fn test_default_path_arg() {
@@ -483,6 +483,34 @@ pub mod test {
             .expect("save should succeed");
     }
 
+    #[test]
+    fn test_credentials_save() {
+        let mut config = Config::new_with_local_cluster("localhost:9003".to_owned());
+        let credentials = Credentials::Password {
+            username: "ci".to_owned(),
+            password: "secret".to_owned(),
+        };
+        config.cluster_mut(LOCAL_PROFILE).unwrap().credentials = Some(credentials.clone());
+
+        let cfg_path = temp_dir().join("credentials.toml");
+        config
+            .save_to(cfg_path.clone())
+            .expect("save should succeed");
+        let update_conf_file =
+            ConfigFile::load(Some(cfg_path.to_string_lossy().to_string())).expect("parse failed");
+        let cluster = update_conf_file
+            .config()
+            .current_cluster()
+            .expect("cluster should exist");
+        assert_eq!(cluster.credentials, Some(credentials));
+
+        // clusters without credentials are unchanged
+        let conf_file = ConfigFile::load(Some("test-data/profiles/config.toml".to_owned()))
+            .expect("parse failed");
+        let cluster = conf_file.config().cluster("local").expect("cluster");
+        assert!(cluster.credentials.is_none());
+    }
+
     #[test]
     fn test_set_tls() {
         let mut conf_file = ConfigFile::load(Some("test-data/profiles/config.toml".to_owned()))
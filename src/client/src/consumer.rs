@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::collections::BTreeMap;
 
 use futures_util::stream::Stream;
 use tracing::{debug, error, trace, instrument};
@@ -12,7 +13,7 @@ use fluvio_spu_schema::server::stream_fetch::{
     SmartStreamKind, WASM_MODULE_V2_API,
 };
 use dataplane::{Isolation, SmartStreamError};
-use dataplane::ReplicaKey;
+use dataplane::{ReplicaKey, Timestamp};
 use dataplane::ErrorCode;
 use dataplane::fetch::DefaultFetchRequest;
 use dataplane::fetch::FetchPartition;
@@ -20,8 +21,10 @@ use dataplane::fetch::FetchableTopic;
 use dataplane::fetch::FetchablePartitionResponse;
 use dataplane::record::RecordSet;
 use dataplane::record::Record as DefaultRecord;
+use dataplane::record::Header;
 use dataplane::batch::Batch;
 use fluvio_types::event::offsets::OffsetPublisher;
+use fluvio_sc_schema::smartmodule::SmartModuleKind;
 
 use crate::FluvioError;
 use crate::offset::Offset;
@@ -302,23 +305,25 @@ impl PartitionConsumer {
         offset: Offset,
         config: ConsumerConfig,
     ) -> Result<impl Stream<Item = Result<Record, FluvioError>>, FluvioError> {
+        let partition = self.partition;
         let stream = self.stream_batches_with_config(offset, config).await?;
-        let flattened =
-            stream.flat_map(|result: Result<Batch, _>| match result {
-                Err(e) => Either::Right(once(err(e))),
-                Ok(batch) => {
-                    let base_offset = batch.base_offset;
-                    let records = batch.own_records().into_iter().enumerate().map(
-                        move |(relative, record)| {
-                            Ok(Record {
-                                offset: base_offset + relative as i64,
-                                record,
-                            })
-                        },
-                    );
-                    Either::Left(iter(records))
-                }
-            });
+        let flattened = stream.flat_map(move |result: Result<Batch, _>| match result {
+            Err(e) => Either::Right(once(err(e))),
+            Ok(batch) => {
+                let base_offset = batch.base_offset;
+                let header = batch.get_header().clone();
+                // offsets may have gaps if log has been compacted
+                let records = batch.own_records().into_iter().map(move |record| {
+                    Ok(Record {
+                        partition,
+                        offset: base_offset + record.get_offset_delta(),
+                        timestamp: header.record_timestamp(record.preamble.timestamp_delta()),
+                        record,
+                    })
+                });
+                Either::Left(iter(records))
+            }
+        });
 
         Ok(flattened)
     }
@@ -398,7 +403,10 @@ impl PartitionConsumer {
     {
         use fluvio_future::task::spawn;
         use futures_util::stream::empty;
-        use fluvio_spu_schema::server::stream_fetch::WASM_MODULE_API;
+        use fluvio_spu_schema::server::stream_fetch::{
+            WASM_MODULE_API, ARRAY_MAP_WASM_API, SMART_STREAM_PARAMS_API, CHAIN_SMART_STREAM_API,
+            NAMED_SMART_MODULE_API,
+        };
         use fluvio_protocol::api::Request;
 
         let replica = ReplicaKey::new(&self.topic, self.partition);
@@ -426,20 +434,63 @@ impl PartitionConsumer {
             .lookup_version(DefaultStreamFetchRequest::API_KEY)
             .unwrap_or((WASM_MODULE_API - 1) as i16);
 
-        if let Some(module) = config.wasm_module {
+        let mut modules = config.wasm_modules;
+        if !modules.is_empty() {
             if stream_fetch_version < WASM_MODULE_API as i16 {
                 return Err(FluvioError::Other("SPU does not support WASM".to_owned()));
             }
 
-            if stream_fetch_version < WASM_MODULE_V2_API as i16 {
+            if modules.iter().any(|module| {
+                matches!(
+                    module.kind,
+                    SmartStreamKind::ArrayMap | SmartStreamKind::FilterMap
+                )
+            }) && stream_fetch_version < ARRAY_MAP_WASM_API as i16
+            {
+                return Err(FluvioError::Other(
+                    "SPU does not support ArrayMap or FilterMap SmartStreams".to_owned(),
+                ));
+            }
+
+            if modules
+                .iter()
+                .any(|module| matches!(module.wasm, SmartStreamWasm::Named(_)))
+                && stream_fetch_version < NAMED_SMART_MODULE_API as i16
+            {
+                return Err(FluvioError::Other(
+                    "SPU does not support named SmartModules".to_owned(),
+                ));
+            }
+
+            if !config.wasm_params.is_empty() {
+                if stream_fetch_version < SMART_STREAM_PARAMS_API as i16 {
+                    return Err(FluvioError::Other(
+                        "SPU does not support SmartStream parameters".to_owned(),
+                    ));
+                }
+                for module in modules.iter_mut() {
+                    module.params = config.wasm_params.clone().into();
+                }
+            }
+
+            if modules.len() > 1 {
+                if stream_fetch_version < CHAIN_SMART_STREAM_API as i16 {
+                    return Err(FluvioError::Other(
+                        "SPU does not support chained SmartStreams".to_owned(),
+                    ));
+                }
+                debug!("Using chained WASM API");
+                stream_request.wasm_payloads = modules;
+            } else if stream_fetch_version < WASM_MODULE_V2_API as i16 {
                 // SmartStream V1
                 debug!("Using WASM V1 API");
-                let SmartStreamWasm::Raw(wasm) = module.wasm;
-                stream_request.wasm_module = wasm;
+                if let SmartStreamWasm::Raw(wasm) = modules.remove(0).wasm {
+                    stream_request.wasm_module = wasm;
+                }
             } else {
                 // SmartStream V2
                 debug!("Using WASM V2 API");
-                stream_request.wasm_payload = Some(module);
+                stream_request.wasm_payload = modules.pop();
             }
         }
         let mut stream = self
@@ -593,15 +644,19 @@ static MAX_FETCH_BYTES: Lazy<i32> = Lazy::new(|| {
 });
 
 /// Configures the behavior of consumer fetching and streaming
-#[derive(Debug, Builder)]
+#[derive(Debug, Clone, Builder)]
 #[builder(build_fn(private, name = "build_impl"))]
 pub struct ConsumerConfig {
     #[builder(default = "*MAX_FETCH_BYTES")]
     pub(crate) max_bytes: i32,
     #[builder(default)]
     pub(crate) isolation: Isolation,
-    #[builder(private, default, setter(into, strip_option))]
-    pub(crate) wasm_module: Option<SmartStreamPayload>,
+    /// SmartStreams executed in the order they were added
+    #[builder(private, default, setter(into))]
+    pub(crate) wasm_modules: Vec<SmartStreamPayload>,
+    /// Key/value parameters passed to the SmartStream
+    #[builder(default, setter(into))]
+    pub(crate) wasm_params: BTreeMap<String, String>,
 }
 
 impl ConsumerConfig {
@@ -618,20 +673,49 @@ impl ConsumerConfigBuilder {
         Ok(config)
     }
 
+    /// Adds a SmartStream after the ones added before, so they are chained
+    fn add_wasm_module(&mut self, module: SmartStreamPayload) -> &mut Self {
+        let mut modules = self.wasm_modules.take().unwrap_or_default();
+        modules.push(module);
+        self.wasm_modules(modules)
+    }
+
     /// Adds a SmartStream filter to this ConsumerConfig
     pub fn wasm_filter<T: Into<Vec<u8>>>(&mut self, filter: T) -> &mut Self {
-        self.wasm_module(SmartStreamPayload {
+        self.add_wasm_module(SmartStreamPayload {
             wasm: SmartStreamWasm::Raw(filter.into()),
             kind: SmartStreamKind::Filter,
+            ..Default::default()
         });
         self
     }
 
     /// Adds a SmartStream map to this ConsumerConfig
     pub fn wasm_map<T: Into<Vec<u8>>>(&mut self, map: T) -> &mut Self {
-        self.wasm_module(SmartStreamPayload {
+        self.add_wasm_module(SmartStreamPayload {
             wasm: SmartStreamWasm::Raw(map.into()),
             kind: SmartStreamKind::Map,
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Adds a SmartStream array-map to this ConsumerConfig
+    pub fn wasm_array_map<T: Into<Vec<u8>>>(&mut self, array_map: T) -> &mut Self {
+        self.add_wasm_module(SmartStreamPayload {
+            wasm: SmartStreamWasm::Raw(array_map.into()),
+            kind: SmartStreamKind::ArrayMap,
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Adds a SmartStream filter-map to this ConsumerConfig
+    pub fn wasm_filter_map<T: Into<Vec<u8>>>(&mut self, filter_map: T) -> &mut Self {
+        self.add_wasm_module(SmartStreamPayload {
+            wasm: SmartStreamWasm::Raw(filter_map.into()),
+            kind: SmartStreamKind::FilterMap,
+            ..Default::default()
         });
         self
     }
@@ -642,11 +726,51 @@ impl ConsumerConfigBuilder {
         aggregate: T,
         accumulator: U,
     ) -> &mut Self {
-        self.wasm_module(SmartStreamPayload {
+        self.add_wasm_module(SmartStreamPayload {
             wasm: SmartStreamWasm::Raw(aggregate.into()),
             kind: SmartStreamKind::Aggregate {
                 accumulator: accumulator.into(),
             },
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Adds a SmartModule stored in the cluster, referenced by name
+    pub fn wasm_smartmodule<N: Into<String>>(
+        &mut self,
+        name: N,
+        kind: SmartModuleKind,
+    ) -> &mut Self {
+        let kind = match kind {
+            SmartModuleKind::Filter => SmartStreamKind::Filter,
+            SmartModuleKind::Map => SmartStreamKind::Map,
+            SmartModuleKind::ArrayMap => SmartStreamKind::ArrayMap,
+            SmartModuleKind::FilterMap => SmartStreamKind::FilterMap,
+            SmartModuleKind::Aggregate => SmartStreamKind::Aggregate {
+                accumulator: Vec::new(),
+            },
+        };
+        self.add_wasm_module(SmartStreamPayload {
+            wasm: SmartStreamWasm::Named(name.into()),
+            kind,
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Adds an aggregate SmartModule stored in the cluster with initial accumulator value
+    pub fn wasm_smartmodule_aggregate<N: Into<String>, U: Into<Vec<u8>>>(
+        &mut self,
+        name: N,
+        accumulator: U,
+    ) -> &mut Self {
+        self.add_wasm_module(SmartStreamPayload {
+            wasm: SmartStreamWasm::Named(name.into()),
+            kind: SmartStreamKind::Aggregate {
+                accumulator: accumulator.into(),
+            },
+            ..Default::default()
         });
         self
     }
@@ -654,18 +778,35 @@ impl ConsumerConfigBuilder {
 
 /// The individual record for a given stream.
 pub struct Record {
+    /// The partition this Record was read from
+    partition: i32,
     /// The offset of this Record into its partition
     offset: i64,
+    /// Milliseconds since unix epoch, -1 if unknown
+    timestamp: Timestamp,
     /// The Record contents
     record: DefaultRecord,
 }
 
 impl Record {
+    /// The partition this Record was read from
+    pub fn partition(&self) -> i32 {
+        self.partition
+    }
+
     /// The offset from the initial offset for a given stream.
     pub fn offset(&self) -> i64 {
         self.offset
     }
 
+    /// Time when record was created by producer or appended to the log,
+    /// depending on timestamp type of the topic.
+    ///
+    /// In milliseconds since unix epoch, -1 if record doesn't have timestamp.
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+
     /// Returns the contents of this Record's key, if it exists
     pub fn key(&self) -> Option<&[u8]> {
         self.record.key().map(|it| it.as_ref())
@@ -676,6 +817,11 @@ impl Record {
         self.record.value().as_ref()
     }
 
+    /// Returns the headers attached to this Record
+    pub fn headers(&self) -> &[Header] {
+        self.record.headers()
+    }
+
     /// Returns the inner representation of the Record
     pub fn into_inner(self) -> DefaultRecord {
         self.record
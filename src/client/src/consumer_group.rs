@@ -0,0 +1,396 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures_util::future::Either;
+use futures_util::stream::{Stream, StreamExt, once, select_all, unfold};
+use tokio::select;
+use tokio::sync::watch;
+use tracing::{debug, error, instrument};
+
+use dataplane::{ErrorCode, ReplicaKey};
+use fluvio_future::task::spawn;
+use fluvio_future::timer::sleep;
+use fluvio_sc_schema::consumer_group::{
+    GroupAssignment, GroupHeartbeatRequest, JoinGroupRequest, LeaveGroupRequest,
+};
+use fluvio_spu_schema::server::consumer_offset::{
+    CommitConsumerOffsetRequest, FetchConsumerOffsetRequest, CONSUMER_OFFSETS_TOPIC,
+    consumer_offsets_partition,
+};
+use fluvio_types::event::SimpleEvent;
+
+use crate::FluvioError;
+use crate::offset::Offset;
+use crate::consumer::{ConsumerConfig, PartitionConsumer, Record};
+use crate::spu::SpuPool;
+use crate::sockets::VersionedSerialSocket;
+
+/// member is removed from group if SC doesn't receive heartbeat within this time
+const SESSION_TIMEOUT: Duration = Duration::from_secs(10);
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(3);
+
+/// assignment of active stream, none if group has no active stream
+type SharedAssignment = Arc<Mutex<Option<GroupAssignment>>>;
+
+/// An interface for consuming a topic as a member of a named consumer group
+///
+/// Partitions of the topic are divided across all live members of the group,
+/// so each event is delivered to only one member. When members join or leave,
+/// the SC reassigns partitions and each member's stream switches to its new
+/// partitions.
+///
+/// A partition is only reassigned after its previous member has stopped
+/// streaming it, so two members never stream the same partition at once.
+/// A member stops streaming revoked partitions once its stream is polled
+/// past the records of its previous assignment.
+///
+/// Members record their progress by committing offsets, which are stored in
+/// a replicated internal topic. When a partition is assigned to a member,
+/// streaming resumes from the group's committed offset. Commits are tagged
+/// with the generation of the assignment, so a member which has lost a
+/// partition can't overwrite offsets committed by its new member.
+///
+/// # Example
+///
+/// ```no_run
+/// # use fluvio::{Fluvio, Offset, FluvioError};
+/// # mod futures {
+/// #     pub use futures_util::stream::StreamExt;
+/// # }
+/// # async fn example(fluvio: &Fluvio) -> Result<(), FluvioError> {
+/// use futures::StreamExt;
+/// let group = fluvio.consumer_group("my-group", "my-topic").await?;
+/// let mut stream = group.stream(Offset::beginning()).await?;
+/// while let Some(Ok(record)) = stream.next().await {
+///     let value = String::from_utf8_lossy(record.value());
+///     println!("Got record from partition {}: {}", record.partition(), value);
+///     group.commit(&record).await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct ConsumerGroup {
+    group: String,
+    topic: String,
+    pool: Arc<SpuPool>,
+    sc: VersionedSerialSocket,
+    assignment: SharedAssignment,
+}
+
+impl ConsumerGroup {
+    pub(crate) fn new(
+        group: String,
+        topic: String,
+        pool: Arc<SpuPool>,
+        sc: VersionedSerialSocket,
+    ) -> Self {
+        Self {
+            group,
+            topic,
+            pool,
+            sc,
+            assignment: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Returns the name of the consumer group
+    pub fn group(&self) -> &str {
+        &self.group
+    }
+
+    /// Returns the name of the Topic that this group consumes
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    /// Joins the group and continuously streams events from assigned partitions
+    ///
+    /// Each assigned partition starts from the offset committed by the group.
+    /// Partitions without a committed offset start from `offset`.
+    ///
+    /// The stream is a member of the group until it is dropped.
+    #[instrument(skip(self, offset))]
+    pub async fn stream(
+        &self,
+        offset: Offset,
+    ) -> Result<impl Stream<Item = Result<Record, FluvioError>>, FluvioError> {
+        let config = ConsumerConfig::builder().build()?;
+        self.stream_with_config(offset, config).await
+    }
+
+    /// Joins the group and continuously streams events from assigned partitions
+    /// using a specific fetching configuration
+    #[instrument(skip(self, offset, config))]
+    pub async fn stream_with_config(
+        &self,
+        offset: Offset,
+        config: ConsumerConfig,
+    ) -> Result<impl Stream<Item = Result<Record, FluvioError>>, FluvioError> {
+        let (member_id, assignment) =
+            join_group(&self.sc, &self.pool, &self.group, &self.topic, 0).await?;
+        debug!(%member_id, ?assignment, "joined consumer group");
+
+        *self.assignment.lock().unwrap() = Some(assignment.clone());
+        let (sender, receiver) = watch::channel(assignment.clone());
+        // generation whose partitions stream has switched to, acknowledged to SC by heartbeat
+        let (switched_sender, switched) = watch::channel(assignment.generation);
+        let switched_sender = Arc::new(switched_sender);
+        let end_event = SimpleEvent::shared();
+        spawn(heartbeat_loop(
+            self.sc.clone(),
+            self.pool.clone(),
+            self.group.clone(),
+            self.topic.clone(),
+            member_id,
+            assignment,
+            self.assignment.clone(),
+            sender,
+            switched,
+            end_event.clone(),
+        ));
+
+        // yield each assignment with receiver which is notified when it is replaced.
+        // membership is dropped with stream, which makes heartbeat loop leave group
+        let first = (receiver.borrow().clone(), receiver.clone());
+        let membership = Membership(end_event);
+        let assignments = once(async move { first }).chain(unfold(
+            (receiver, membership),
+            |(mut receiver, membership)| async move {
+                receiver.changed().await.ok()?;
+                let assignment = receiver.borrow().clone();
+                Some(((assignment, receiver.clone()), (receiver, membership)))
+            },
+        ));
+
+        let group = self.group.clone();
+        let topic = self.topic.clone();
+        let pool = self.pool.clone();
+        let shared = self.assignment.clone();
+        let stream = assignments
+            .then(move |(assignment, mut receiver)| {
+                let group = group.clone();
+                let topic = topic.clone();
+                let pool = pool.clone();
+                let offset = offset.clone();
+                let config = config.clone();
+                let shared = shared.clone();
+                let switched = switched_sender.clone();
+                async move {
+                    // streams of previous assignment have been dropped by now,
+                    // so its revoked partitions are no longer consumed
+                    debug!(?assignment, "consuming assigned partitions");
+                    *shared.lock().unwrap() = Some(assignment.clone());
+                    let _ = switched.send(assignment.generation);
+                    let mut partition_streams = vec![];
+                    for partition in assignment.partitions {
+                        let start = match committed_offset(&pool, &group, &topic, partition).await?
+                        {
+                            Some(committed) => Offset::absolute(committed)?,
+                            None => offset.clone(),
+                        };
+                        let consumer =
+                            PartitionConsumer::new(topic.clone(), partition, pool.clone());
+                        let stream = consumer.stream_with_config(start, config.clone()).await?;
+                        partition_streams.push(stream.boxed());
+                    }
+                    let rebalanced = async move {
+                        let _ = receiver.changed().await;
+                    };
+                    Ok(select_all(partition_streams).take_until(rebalanced))
+                }
+            })
+            .flat_map(|result| match result {
+                Ok(stream) => Either::Left(stream),
+                Err(err) => Either::Right(once(async move { Err(err) })),
+            });
+
+        Ok(stream.boxed())
+    }
+
+    /// Commits the offset after `record`, so the group resumes from the next record
+    pub async fn commit(&self, record: &Record) -> Result<(), FluvioError> {
+        self.commit_offset(record.partition(), record.offset() + 1)
+            .await
+    }
+
+    /// Commits next offset to be consumed by group from partition
+    ///
+    /// Only partitions assigned to the active stream of this group can be committed.
+    /// Commits of other partitions fail with `IllegalGeneration`.
+    #[instrument(skip(self))]
+    pub async fn commit_offset(&self, partition: i32, offset: i64) -> Result<(), FluvioError> {
+        let generation = match &*self.assignment.lock().unwrap() {
+            Some(assignment) if assignment.partitions.contains(&partition) => assignment.generation,
+            _ => {
+                debug!(partition, "partition is not assigned to this member");
+                return Err(FluvioError::ConsumerGroup(
+                    self.group.clone(),
+                    ErrorCode::IllegalGeneration,
+                ));
+            }
+        };
+
+        let leader = self
+            .pool
+            .create_serial_socket(&offsets_replica(&self.group))
+            .await?;
+        let response = leader
+            .send_receive(CommitConsumerOffsetRequest {
+                group: self.group.clone(),
+                topic: self.topic.clone(),
+                partition,
+                offset,
+                generation,
+            })
+            .await?;
+        match response.error_code {
+            ErrorCode::None => Ok(()),
+            code => Err(FluvioError::ConsumerGroup(self.group.clone(), code)),
+        }
+    }
+}
+
+/// notify heartbeat loop to leave group when dropped
+struct Membership(Arc<SimpleEvent>);
+
+impl Drop for Membership {
+    fn drop(&mut self) {
+        self.0.notify();
+    }
+}
+
+/// partition of internal topic storing offsets of group
+fn offsets_replica(group: &str) -> ReplicaKey {
+    ReplicaKey::new(CONSUMER_OFFSETS_TOPIC, consumer_offsets_partition(group))
+}
+
+/// join group above generation offsets were last committed in and `known_generation`,
+/// so commits of group which is created again are not fenced by its earlier commits
+async fn join_group(
+    sc: &VersionedSerialSocket,
+    pool: &SpuPool,
+    group: &str,
+    topic: &str,
+    known_generation: i32,
+) -> Result<(String, GroupAssignment), FluvioError> {
+    let (_, committed_generation) = fetch_committed(pool, group, topic, 0).await?;
+    let response = sc
+        .send_receive(JoinGroupRequest {
+            group: group.to_owned(),
+            topic: topic.to_owned(),
+            session_timeout_ms: SESSION_TIMEOUT.as_millis() as u32,
+            min_generation: committed_generation.max(known_generation),
+        })
+        .await?;
+    match response.error_code {
+        ErrorCode::None => Ok((response.member_id, response.assignment)),
+        code => Err(FluvioError::ConsumerGroup(group.to_owned(), code)),
+    }
+}
+
+async fn committed_offset(
+    pool: &SpuPool,
+    group: &str,
+    topic: &str,
+    partition: i32,
+) -> Result<Option<i64>, FluvioError> {
+    let (offset, _) = fetch_committed(pool, group, topic, partition).await?;
+    Ok(offset)
+}
+
+/// offset committed by group for partition and highest generation group committed in
+async fn fetch_committed(
+    pool: &SpuPool,
+    group: &str,
+    topic: &str,
+    partition: i32,
+) -> Result<(Option<i64>, i32), FluvioError> {
+    let leader = pool.create_serial_socket(&offsets_replica(group)).await?;
+    let response = leader
+        .send_receive(FetchConsumerOffsetRequest {
+            group: group.to_owned(),
+            topic: topic.to_owned(),
+            partition,
+        })
+        .await?;
+    match response.error_code {
+        ErrorCode::None => Ok((response.offset, response.generation)),
+        code => Err(FluvioError::ConsumerGroup(group.to_owned(), code)),
+    }
+}
+
+/// keep membership alive and publish new assignments until stream is dropped.
+/// Heartbeat acknowledges generation stream has switched to, after streams of revoked
+/// partitions are dropped, so SC can reassign revoked partitions.
+/// if SC no longer knows member, join again as new member
+#[allow(clippy::too_many_arguments)]
+async fn heartbeat_loop(
+    sc: VersionedSerialSocket,
+    pool: Arc<SpuPool>,
+    group: String,
+    topic: String,
+    mut member_id: String,
+    mut current: GroupAssignment,
+    shared: SharedAssignment,
+    sender: watch::Sender<GroupAssignment>,
+    switched: watch::Receiver<i32>,
+    end_event: Arc<SimpleEvent>,
+) {
+    loop {
+        select! {
+            _ = end_event.listen() => {
+                debug!(%member_id, "consumer group stream dropped, leaving group");
+                let request = LeaveGroupRequest {
+                    group: group.clone(),
+                    topic: topic.clone(),
+                    member_id: member_id.clone(),
+                };
+                if let Err(err) = sc.send_receive(request).await {
+                    error!("error leaving consumer group: {}", err);
+                }
+                break;
+            },
+            _ = sleep(HEARTBEAT_INTERVAL) => {
+                let request = GroupHeartbeatRequest {
+                    group: group.clone(),
+                    topic: topic.clone(),
+                    member_id: member_id.clone(),
+                    generation: *switched.borrow(),
+                };
+                let assignment = match sc.send_receive(request).await {
+                    Ok(response) if response.error_code == ErrorCode::UnknownMemberId => {
+                        debug!(%member_id, "member expired, joining group again");
+                        match join_group(&sc, &pool, &group, &topic, current.generation).await {
+                            Ok((new_member_id, assignment)) => {
+                                member_id = new_member_id;
+                                assignment
+                            }
+                            Err(err) => {
+                                error!("error joining consumer group: {}", err);
+                                continue;
+                            }
+                        }
+                    }
+                    Ok(response) if response.error_code.is_error() => {
+                        error!(code = ?response.error_code, "consumer group heartbeat failed");
+                        continue;
+                    }
+                    Ok(response) => response.assignment,
+                    Err(err) => {
+                        error!("error sending consumer group heartbeat: {}", err);
+                        continue;
+                    }
+                };
+
+                if current != assignment {
+                    debug!(?assignment, "consumer group rebalanced");
+                    current = assignment.clone();
+                    if sender.send(assignment).is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    *shared.lock().unwrap() = None;
+}
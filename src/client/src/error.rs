@@ -5,6 +5,8 @@ use fluvio_sc_schema::ApiError;
 use crate::config::ConfigError;
 use semver::Version;
 use dataplane::smartstream::SmartStreamRuntimeError;
+use dataplane::compression::CompressionError;
+use dataplane::ErrorCode;
 
 /// Possible errors that may arise when using Fluvio
 #[derive(thiserror::Error, Debug)]
@@ -15,6 +17,10 @@ pub enum FluvioError {
     TopicNotFound(String),
     #[error("Partition not found: {0}-{1}")]
     PartitionNotFound(String, i32),
+    #[error("Producing to partition {0}-{1} failed: {}", .2.to_sentence())]
+    ProducePartition(String, i32, ErrorCode),
+    #[error("Consumer group {0} failed: {}", .1.to_sentence())]
+    ConsumerGroup(String, ErrorCode),
     #[error("Spu not found: {0}")]
     SPUNotFound(i32),
     #[error("Fluvio socket error")]
@@ -23,6 +29,8 @@ pub enum FluvioError {
     AdminApi(#[from] ApiError),
     #[error("Fluvio config error")]
     ClientConfig(#[from] ConfigError),
+    #[error("Authentication failed: {0}")]
+    Authentication(String),
     #[error("Attempted to create negative offset: {0}")]
     NegativeOffset(i64),
     #[error("Cluster (with platform version {cluster_version}) is older than the minimum required version {client_minimum_version}
@@ -43,6 +51,10 @@ To interact with this cluster, please install the matching CLI version using the
     },
     #[error("Consumer config error: {0}")]
     ConsumerConfig(String),
+    #[error("Producer config error: {0}")]
+    ProducerConfig(String),
+    #[error("Compression error")]
+    Compression(#[from] CompressionError),
     #[error("Encountered a runtime error in the user's SmartStream")]
     SmartStreamRuntime(#[from] SmartStreamRuntimeError),
     #[error("Unknown error: {0}")]
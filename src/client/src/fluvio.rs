@@ -11,7 +11,10 @@ use semver::Version;
 use crate::config::ConfigFile;
 use crate::admin::FluvioAdmin;
 use crate::TopicProducer;
+use crate::ProducerConfig;
 use crate::PartitionConsumer;
+use crate::ConsumerGroup;
+use crate::TopicConsumer;
 use crate::FluvioError;
 use crate::FluvioConfig;
 use crate::spu::SpuPool;
@@ -73,7 +76,8 @@ impl Fluvio {
         connector: DomainConnector,
         config: &FluvioConfig,
     ) -> Result<Self, FluvioError> {
-        let config = ClientConfig::new(&config.endpoint, connector);
+        let config = ClientConfig::new(&config.endpoint, connector)
+            .with_credentials(config.credentials.clone());
         let inner_client = config.connect().await?;
         debug!("connected to cluster at: {}", inner_client.config().addr());
 
@@ -126,6 +130,30 @@ impl Fluvio {
     pub async fn topic_producer<S: Into<String>>(
         &self,
         topic: S,
+    ) -> Result<TopicProducer, FluvioError> {
+        self.topic_producer_with_config(topic, ProducerConfig::default())
+            .await
+    }
+
+    /// Creates a new `TopicProducer` for the given topic name with custom configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use fluvio::{Fluvio, FluvioError, RecordKey, ProducerConfig, Compression};
+    /// # async fn do_produce_to_topic(fluvio: &Fluvio) -> Result<(), FluvioError> {
+    /// let config = ProducerConfig::builder()
+    ///     .compression(Compression::Gzip)
+    ///     .build()?;
+    /// let producer = fluvio.topic_producer_with_config("my-topic", config).await?;
+    /// producer.send(RecordKey::NULL, "Hello, Fluvio!").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn topic_producer_with_config<S: Into<String>>(
+        &self,
+        topic: S,
+        config: ProducerConfig,
     ) -> Result<TopicProducer, FluvioError> {
         let topic = topic.into();
         debug!(topic = &*topic, "Creating producer");
@@ -135,7 +163,7 @@ impl Fluvio {
             return Err(FluvioError::TopicNotFound(topic));
         }
 
-        Ok(TopicProducer::new(topic, spu_pool))
+        Ok(TopicProducer::new(topic, spu_pool, config))
     }
 
     /// Creates a new `PartitionConsumer` for the given topic and partition
@@ -173,6 +201,70 @@ impl Fluvio {
         ))
     }
 
+    /// Creates a new `TopicConsumer` for all partitions of the given topic
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use fluvio::{Fluvio, Offset, FluvioError};
+    /// # async fn do_consume_topic(fluvio: &Fluvio) -> Result<(), FluvioError> {
+    /// let consumer = fluvio.topic_consumer("my-topic").await?;
+    /// let stream = consumer.stream(Offset::beginning()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn topic_consumer<S: Into<String>>(
+        &self,
+        topic: S,
+    ) -> Result<TopicConsumer, FluvioError> {
+        let topic = topic.into();
+        debug!(topic = &*topic, "Creating topic consumer");
+
+        let spu_pool = self.spu_pool().await?;
+        if !spu_pool.topic_exists(&topic).await? {
+            return Err(FluvioError::TopicNotFound(topic));
+        }
+
+        Ok(TopicConsumer::new(topic, spu_pool))
+    }
+
+    /// Creates a new `ConsumerGroup` for the given group name and topic
+    ///
+    /// Streams of the same group divide partitions of the topic between them
+    /// and resume from offsets committed by the group.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use fluvio::{Fluvio, Offset, FluvioError};
+    /// # async fn do_consume_with_group(fluvio: &Fluvio) -> Result<(), FluvioError> {
+    /// let group = fluvio.consumer_group("my-group", "my-topic").await?;
+    /// let stream = group.stream(Offset::beginning()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn consumer_group<G: Into<String>, S: Into<String>>(
+        &self,
+        group: G,
+        topic: S,
+    ) -> Result<ConsumerGroup, FluvioError> {
+        let group = group.into();
+        let topic = topic.into();
+        debug!(group = &*group, topic = &*topic, "Creating consumer group");
+
+        let spu_pool = self.spu_pool().await?;
+        if !spu_pool.topic_exists(&topic).await? {
+            return Err(FluvioError::TopicNotFound(topic));
+        }
+
+        Ok(ConsumerGroup::new(
+            group,
+            topic,
+            spu_pool,
+            self.create_serial_client().await,
+        ))
+    }
+
     /// Provides an interface for managing a Fluvio cluster
     ///
     /// # Example
@@ -86,6 +86,8 @@ mod sockets;
 mod admin;
 mod fluvio;
 pub mod consumer;
+mod consumer_group;
+mod topic_consumer;
 mod producer;
 mod offset;
 mod sync;
@@ -96,8 +98,12 @@ pub mod config;
 use tracing::instrument;
 pub use error::FluvioError;
 pub use config::FluvioConfig;
-pub use producer::{TopicProducer, RecordKey};
+pub use producer::{
+    TopicProducer, RecordKey, ProducerConfig, Compression, Acks, Header, DeliveryFuture,
+};
 pub use consumer::{PartitionConsumer, ConsumerConfig};
+pub use consumer_group::ConsumerGroup;
+pub use topic_consumer::TopicConsumer;
 pub use offset::Offset;
 
 pub use crate::admin::FluvioAdmin;
@@ -210,6 +216,10 @@ pub mod metadata {
         pub use fluvio_sc_schema::spg::*;
     }
 
+    pub mod smartmodule {
+        pub use fluvio_sc_schema::smartmodule::*;
+    }
+
     pub mod partition {
         pub use fluvio_sc_schema::partition::*;
     }
@@ -218,6 +228,14 @@ pub mod metadata {
         pub use fluvio_sc_schema::objects::*;
     }
 
+    pub mod election {
+        pub use fluvio_sc_schema::election::*;
+    }
+
+    pub mod audit {
+        pub use fluvio_sc_schema::audit::*;
+    }
+
     pub mod core {
         pub use fluvio_sc_schema::core::*;
     }
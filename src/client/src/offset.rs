@@ -14,6 +14,7 @@ pub(crate) enum OffsetInner {
     Absolute(i64),
     FromBeginning(i64),
     FromEnd(i64),
+    FromTimestamp(i64),
 }
 
 impl OffsetInner {
@@ -28,6 +29,13 @@ impl OffsetInner {
                 let resolved = offsets.last_stable_offset - offset;
                 resolved.clamp(offsets.start_offset, offsets.last_stable_offset)
             }
+            Self::FromTimestamp(_) => {
+                // all records are older, so start with next record
+                let resolved = offsets
+                    .timestamp_offset
+                    .unwrap_or(offsets.last_stable_offset);
+                resolved.clamp(offsets.start_offset, offsets.last_stable_offset)
+            }
         }
     }
 }
@@ -271,6 +279,26 @@ impl Offset {
         }
     }
 
+    /// Creates an offset pointing to the first log entry with the same or later timestamp
+    ///
+    /// The timestamp is given in milliseconds since the Unix epoch and is compared
+    /// against the create time or log append time of each event, depending on the
+    /// timestamp type of the topic. If every event in the log is older, the offset
+    /// points past the newest log entry, so only new events are read.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use fluvio::Offset;
+    /// // Creates an offset pointing to the first event from 2021-06-01 00:00:00 UTC
+    /// let offset: Offset = Offset::from_timestamp(1_622_505_600_000);
+    /// ```
+    pub fn from_timestamp(timestamp: u64) -> Offset {
+        Self {
+            inner: OffsetInner::FromTimestamp(timestamp as i64),
+        }
+    }
+
     /// Converts this offset into an absolute offset
     ///
     /// If this offset is relative from the beginning (i.e. it was created
@@ -283,6 +311,9 @@ impl Offset {
     /// the absolute offset by finding the last stably-committed event and subtracting
     /// the relative offset from it.
     ///
+    /// If this offset was created using the [`from_timestamp`] function, the
+    /// SPU looks up the first event with the same or later timestamp.
+    ///
     /// Calling `to_absolute` on an offset that is already absolute just returns
     /// that same offset.
     ///
@@ -298,7 +329,11 @@ impl Offset {
             OffsetInner::Absolute(offset) => *offset,
             inner => {
                 let replica = ReplicaKey::new(topic, partition);
-                let offsets = fetch_offsets(client, &replica).await?;
+                let timestamp = match inner {
+                    OffsetInner::FromTimestamp(timestamp) => Some(*timestamp),
+                    _ => None,
+                };
+                let offsets = fetch_offsets(client, &replica, timestamp).await?;
                 inner.resolve(&offsets)
             }
         };
@@ -312,15 +347,19 @@ impl Offset {
 async fn fetch_offsets(
     client: &mut VersionedSerialSocket,
     replica: &ReplicaKey,
+    timestamp: Option<i64>,
 ) -> Result<FetchOffsetPartitionResponse, FluvioError> {
     debug!("fetching offset for replica: {}", replica);
 
-    let response = client
-        .send_receive(FetchOffsetsRequest::new(
+    let request = match timestamp {
+        Some(timestamp) => FetchOffsetsRequest::new_with_timestamp(
             replica.topic.to_owned(),
             replica.partition,
-        ))
-        .await?;
+            timestamp,
+        ),
+        None => FetchOffsetsRequest::new(replica.topic.to_owned(), replica.partition),
+    };
+    let response = client.send_receive(request).await?;
 
     trace!(
         "receive fetch response replica: {}, {:#?}",
@@ -352,6 +391,7 @@ mod tests {
             partition_index: 0,
             start_offset: 0,
             last_stable_offset: 10,
+            timestamp_offset: None,
         };
 
         let offset_inner = OffsetInner::FromBeginning(3);
@@ -366,6 +406,7 @@ mod tests {
             partition_index: 0,
             start_offset: 5,
             last_stable_offset: 10,
+            timestamp_offset: None,
         };
 
         let offset_inner = OffsetInner::FromBeginning(3);
@@ -380,6 +421,7 @@ mod tests {
             partition_index: 0,
             start_offset: 0,
             last_stable_offset: 10,
+            timestamp_offset: None,
         };
 
         let offset_inner = OffsetInner::FromBeginning(15);
@@ -394,6 +436,7 @@ mod tests {
             partition_index: 0,
             start_offset: 5,
             last_stable_offset: 10,
+            timestamp_offset: None,
         };
 
         let offset_inner = OffsetInner::FromBeginning(15);
@@ -408,6 +451,7 @@ mod tests {
             partition_index: 0,
             start_offset: 0,
             last_stable_offset: 10,
+            timestamp_offset: None,
         };
 
         let offset_inner = OffsetInner::FromEnd(3);
@@ -422,6 +466,7 @@ mod tests {
             partition_index: 0,
             start_offset: 6,
             last_stable_offset: 10,
+            timestamp_offset: None,
         };
 
         let offset_inner = OffsetInner::FromEnd(6);
@@ -436,10 +481,29 @@ mod tests {
             partition_index: 0,
             start_offset: 0,
             last_stable_offset: 10,
+            timestamp_offset: None,
         };
 
         let offset_inner = OffsetInner::FromEnd(100);
         let absolute = offset_inner.resolve(&offsets);
         assert_eq!(absolute, 0);
     }
+
+    #[test]
+    fn test_offset_timestamp() {
+        let mut offsets = FetchOffsetPartitionResponse {
+            error_code: Default::default(),
+            partition_index: 0,
+            start_offset: 5,
+            last_stable_offset: 10,
+            timestamp_offset: Some(7),
+        };
+
+        let offset_inner = OffsetInner::FromTimestamp(1000);
+        assert_eq!(offset_inner.resolve(&offsets), 7);
+
+        // all records are older than timestamp
+        offsets.timestamp_offset = None;
+        assert_eq!(offset_inner.resolve(&offsets), 10);
+    }
 }
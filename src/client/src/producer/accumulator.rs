@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use async_lock::Mutex;
+use tokio::sync::oneshot;
+
+use dataplane::batch::MemoryRecords;
+use dataplane::core::Encoder;
+use dataplane::{Timestamp, now_timestamp};
+use dataplane::record::Record;
+use fluvio_types::PartitionId;
+
+use crate::FluvioError;
+
+pub(crate) type DeliverySender = oneshot::Sender<Result<(), FluvioError>>;
+
+/// A future which resolves once a record sent with `send_async` is delivered
+///
+/// The future resolves after the batch containing the record has been
+/// acknowledged by the SPU leading its partition, according to the
+/// producer's `Acks` setting.
+pub struct DeliveryFuture {
+    receiver: oneshot::Receiver<Result<(), FluvioError>>,
+}
+
+impl Future for DeliveryFuture {
+    type Output = Result<(), FluvioError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.receiver)
+            .poll(cx)
+            .map(|result| match result {
+                Ok(delivery) => delivery,
+                Err(_) => Err(FluvioError::Other(
+                    "producer dropped record before it was delivered".to_owned(),
+                )),
+            })
+    }
+}
+
+/// records accumulated for a partition, sent together in a single batch
+pub(crate) struct ProducerBatch {
+    pub(crate) id: u64,
+    /// time first record was added, records are timestamped by their delta from it
+    pub(crate) create_time: Timestamp,
+    pub(crate) records: MemoryRecords,
+    pub(crate) senders: Vec<DeliverySender>,
+    size: usize,
+}
+
+/// outcome of adding record to accumulator
+pub(crate) struct PushOutcome {
+    pub(crate) delivery: DeliveryFuture,
+    /// id of batch created by this record, which must be flushed after linger time
+    pub(crate) new_batch: Option<u64>,
+    /// batch which reached batch size and must be flushed now
+    pub(crate) full_batch: Option<ProducerBatch>,
+}
+
+#[derive(Default)]
+struct Batches {
+    next_id: u64,
+    partitions: HashMap<PartitionId, ProducerBatch>,
+}
+
+/// Groups records sent with `send_async` into batches per partition
+pub(crate) struct RecordAccumulator {
+    batch_size: usize,
+    batches: Mutex<Batches>,
+}
+
+impl RecordAccumulator {
+    pub(crate) fn new(batch_size: usize) -> Self {
+        Self {
+            batch_size,
+            batches: Mutex::new(Batches::default()),
+        }
+    }
+
+    /// add record to current batch of partition, record is timestamped when it is added
+    pub(crate) async fn push(&self, partition: PartitionId, mut record: Record) -> PushOutcome {
+        let (sender, receiver) = oneshot::channel();
+        let now = now_timestamp();
+
+        let mut batches = self.batches.lock().await;
+        let mut new_batch = None;
+        if !batches.partitions.contains_key(&partition) {
+            let id = batches.next_id;
+            batches.next_id += 1;
+            new_batch = Some(id);
+            batches.partitions.insert(
+                partition,
+                ProducerBatch {
+                    id,
+                    create_time: now,
+                    records: vec![],
+                    senders: vec![],
+                    size: 0,
+                },
+            );
+        }
+
+        let mut full_batch = None;
+        if let Some(batch) = batches.partitions.get_mut(&partition) {
+            record
+                .preamble
+                .set_timestamp_delta((now - batch.create_time).max(0));
+            let record_size = record.write_size(0);
+            batch.records.push(record);
+            batch.senders.push(sender);
+            batch.size += record_size;
+            if batch.size >= self.batch_size {
+                full_batch = batches.partitions.remove(&partition);
+            }
+        }
+
+        PushOutcome {
+            delivery: DeliveryFuture { receiver },
+            new_batch: new_batch.filter(|_| full_batch.is_none()),
+            full_batch,
+        }
+    }
+
+    /// remove batch of partition.
+    /// With `id`, batch is only removed if it is still the batch with that id
+    pub(crate) async fn take(
+        &self,
+        partition: PartitionId,
+        id: Option<u64>,
+    ) -> Option<ProducerBatch> {
+        let mut batches = self.batches.lock().await;
+        let current = batches.partitions.get(&partition).map(|batch| batch.id);
+        if current.is_some() && (id.is_none() || current == id) {
+            batches.partitions.remove(&partition)
+        } else {
+            None
+        }
+    }
+
+    /// partitions which have batch accumulating records
+    pub(crate) async fn partitions(&self) -> Vec<PartitionId> {
+        let batches = self.batches.lock().await;
+        batches.partitions.keys().copied().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[fluvio_future::test_async]
+    async fn test_accumulator_batch_size() -> Result<(), ()> {
+        let record_size = Record::new("A").write_size(0);
+        let accumulator = RecordAccumulator::new(record_size * 2);
+
+        let first = accumulator.push(0, Record::new("A")).await;
+        assert_eq!(first.new_batch, Some(0));
+        assert!(first.full_batch.is_none());
+
+        let other = accumulator.push(1, Record::new("B")).await;
+        assert_eq!(other.new_batch, Some(1));
+
+        let second = accumulator.push(0, Record::new("C")).await;
+        assert!(second.new_batch.is_none());
+        let batch = second.full_batch.expect("full batch");
+        assert_eq!(batch.id, 0);
+        assert!(batch.create_time > 0);
+        assert_eq!(batch.records.len(), 2);
+        assert_eq!(batch.records[0].preamble.timestamp_delta(), 0);
+        assert_eq!(batch.senders.len(), 2);
+
+        // full batch was removed, so next record starts new batch
+        let third = accumulator.push(0, Record::new("D")).await;
+        assert_eq!(third.new_batch, Some(2));
+
+        Ok(())
+    }
+
+    #[fluvio_future::test_async]
+    async fn test_accumulator_take() -> Result<(), ()> {
+        let accumulator = RecordAccumulator::new(1024);
+
+        accumulator.push(0, Record::new("A")).await;
+        accumulator.push(1, Record::new("B")).await;
+
+        // linger of stale batch doesn't take current batch
+        assert!(accumulator.take(0, Some(5)).await.is_none());
+        let batch = accumulator.take(0, Some(0)).await.expect("batch");
+        assert_eq!(batch.records.len(), 1);
+        assert!(accumulator.take(0, Some(0)).await.is_none());
+
+        assert_eq!(accumulator.partitions().await, vec![1]);
+        let batch = accumulator.take(1, None).await.expect("batch");
+        assert_eq!(batch.records.len(), 1);
+        assert!(accumulator.take(1, None).await.is_none());
+        assert!(accumulator.partitions().await.is_empty());
+
+        Ok(())
+    }
+
+    #[fluvio_future::test_async]
+    async fn test_delivery_dropped() -> Result<(), ()> {
+        let accumulator = RecordAccumulator::new(1024);
+
+        let outcome = accumulator.push(0, Record::new("A")).await;
+        let batch = accumulator.take(0, None).await;
+        drop(batch);
+        assert!(outcome.delivery.await.is_err());
+
+        Ok(())
+    }
+}
@@ -1,16 +1,23 @@
 use std::sync::Arc;
 use std::collections::HashMap;
-use tracing::instrument;
+use std::time::Duration;
+use tracing::{debug, error, instrument};
 use siphasher::sip::SipHasher;
 use async_lock::Mutex;
+use derive_builder::Builder;
+use fluvio_future::task::spawn;
+use fluvio_future::timer::sleep;
 
-use dataplane::ReplicaKey;
+use dataplane::{ReplicaKey, Timestamp, now_timestamp};
 use dataplane::produce::DefaultProduceRequest;
 use dataplane::produce::DefaultPartitionRequest;
 use dataplane::produce::DefaultTopicRequest;
+use dataplane::produce::ProduceResponse;
+pub use dataplane::produce::Acks;
 use dataplane::batch::{Batch, MemoryRecords};
 use dataplane::record::Record;
-pub use dataplane::record::{RecordKey, RecordData};
+pub use dataplane::record::{RecordKey, RecordData, Header};
+pub use dataplane::compression::Compression;
 
 use crate::FluvioError;
 use crate::spu::SpuPool;
@@ -18,26 +25,107 @@ use fluvio_types::{SpuId, PartitionId};
 use crate::sync::StoreContext;
 use crate::metadata::partition::PartitionSpec;
 
+mod accumulator;
+mod partition_sender;
+
+pub use self::accumulator::DeliveryFuture;
+use self::accumulator::{RecordAccumulator, ProducerBatch, DeliverySender};
+use self::partition_sender::PartitionSenders;
+
 /// An interface for producing events to a particular topic
 ///
 /// A `TopicProducer` allows you to send events to the specific
 /// topic it was initialized for. Once you have a `TopicProducer`,
 /// you can send events to the topic, choosing which partition
 /// each event should be delivered to.
+///
+/// Records sent with [`send_async`] are accumulated per partition and
+/// sent in batches in the background, see [`ProducerConfig`].
+/// Batches of each partition are sent in order, one at a time.
+///
+/// [`send_async`]: struct.TopicProducer.html#method.send_async
+/// [`ProducerConfig`]: struct.ProducerConfig.html
 pub struct TopicProducer {
     topic: String,
     pool: Arc<SpuPool>,
     partitioner: Arc<Mutex<dyn Partitioner + Send + Sync>>,
+    config: Arc<ProducerConfig>,
+    accumulator: Arc<RecordAccumulator>,
+    partition_senders: Arc<PartitionSenders>,
+}
+
+/// Configures the behavior of producer
+#[derive(Debug, Builder)]
+#[builder(build_fn(private, name = "build_impl"))]
+pub struct ProducerConfig {
+    /// codec used to compress records of each batch
+    #[builder(default)]
+    pub(crate) compression: Compression,
+    /// acknowledgments required from SPU before records are considered sent
+    #[builder(default)]
+    pub(crate) acks: Acks,
+    /// time for SPU to complete produce request, including replication with `Acks::All`
+    #[builder(default = "DEFAULT_PRODUCE_TIMEOUT")]
+    pub(crate) timeout: Duration,
+    /// size in bytes at which records accumulated for a partition by `send_async` are sent
+    #[builder(default = "DEFAULT_BATCH_SIZE")]
+    pub(crate) batch_size: usize,
+    /// maximum time records are held by `send_async` waiting for more records of same partition
+    #[builder(default = "DEFAULT_LINGER")]
+    pub(crate) linger: Duration,
+}
+
+const DEFAULT_PRODUCE_TIMEOUT: Duration = Duration::from_millis(1500);
+const DEFAULT_BATCH_SIZE: usize = 16_384;
+const DEFAULT_LINGER: Duration = Duration::from_millis(100);
+
+impl Default for ProducerConfig {
+    fn default() -> Self {
+        Self {
+            compression: Compression::default(),
+            acks: Acks::default(),
+            timeout: DEFAULT_PRODUCE_TIMEOUT,
+            batch_size: DEFAULT_BATCH_SIZE,
+            linger: DEFAULT_LINGER,
+        }
+    }
+}
+
+impl ProducerConfig {
+    pub fn builder() -> ProducerConfigBuilder {
+        ProducerConfigBuilder::default()
+    }
+}
+
+impl ProducerConfigBuilder {
+    pub fn build(&self) -> Result<ProducerConfig, FluvioError> {
+        let config = self.build_impl().map_err(|e| {
+            FluvioError::ProducerConfig(format!("Missing required config option: {}", e))
+        })?;
+        Ok(config)
+    }
 }
 
 impl TopicProducer {
-    pub(crate) fn new(topic: String, pool: Arc<SpuPool>) -> Self {
-        let config = PartitionerConfig { partition_count: 1 };
-        let partitioner = Arc::new(Mutex::new(SiphashRoundRobinPartitioner::new(config)));
+    pub(crate) fn new(topic: String, pool: Arc<SpuPool>, config: ProducerConfig) -> Self {
+        let partitioner_config = PartitionerConfig { partition_count: 1 };
+        let partitioner = Arc::new(Mutex::new(SiphashRoundRobinPartitioner::new(
+            partitioner_config,
+        )));
+        let accumulator = Arc::new(RecordAccumulator::new(config.batch_size));
+        let config = Arc::new(config);
+        let partition_senders = Arc::new(PartitionSenders::new(
+            topic.clone(),
+            pool.clone(),
+            config.clone(),
+        ));
         Self {
             topic,
             pool,
             partitioner,
+            config,
+            accumulator,
+            partition_senders,
         }
     }
 
@@ -69,6 +157,40 @@ impl TopicProducer {
         Ok(())
     }
 
+    /// Sends a key/value record with headers to this producer's Topic.
+    ///
+    /// Headers are delivered to consumers and SmartStreams along with the record.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use fluvio::{TopicProducer, FluvioError, Header};
+    /// # async fn example(producer: &TopicProducer) -> Result<(), FluvioError> {
+    /// let headers = vec![Header::new("content-type", "application/json")];
+    /// producer.send_with_headers("Key", r#"{"name":"fluvio"}"#, headers).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[instrument(
+        skip(self, key, value, headers),
+        fields(topic = %self.topic),
+    )]
+    pub async fn send_with_headers<K, V, H>(
+        &self,
+        key: K,
+        value: V,
+        headers: H,
+    ) -> Result<(), FluvioError>
+    where
+        K: Into<RecordKey>,
+        V: Into<RecordData>,
+        H: IntoIterator<Item = Header>,
+    {
+        let mut record = Record::new_key_value(key, value);
+        record.headers.extend(headers);
+        self.send_records(vec![record]).await
+    }
+
     #[instrument(
         skip(self, records),
         fields(topic = %self.topic),
@@ -79,6 +201,93 @@ impl TopicProducer {
         V: Into<RecordData>,
         I: IntoIterator<Item = (K, V)>,
     {
+        let entries = records
+            .into_iter()
+            .map::<(RecordKey, RecordData), _>(|(k, v)| (k.into(), v.into()))
+            .map(Record::from)
+            .collect();
+        self.send_records(entries).await
+    }
+
+    /// Adds a key/value record to the batch of its partition, without waiting for it to be sent
+    ///
+    /// A batch is sent when it reaches the configured batch size, when the linger
+    /// time of its first record expires, or when [`flush`] is called. The returned
+    /// [`DeliveryFuture`] resolves once the batch containing the record is acknowledged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use fluvio::{TopicProducer, FluvioError};
+    /// # async fn example(producer: &TopicProducer) -> Result<(), FluvioError> {
+    /// let mut deliveries = vec![];
+    /// for i in 0..100 {
+    ///     deliveries.push(producer.send_async(format!("key-{}", i), "value").await?);
+    /// }
+    /// producer.flush().await?;
+    /// for delivery in deliveries {
+    ///     delivery.await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`flush`]: struct.TopicProducer.html#method.flush
+    /// [`DeliveryFuture`]: struct.DeliveryFuture.html
+    #[instrument(
+        skip(self, key, value),
+        fields(topic = %self.topic),
+    )]
+    pub async fn send_async<K, V>(&self, key: K, value: V) -> Result<DeliveryFuture, FluvioError>
+    where
+        K: Into<RecordKey>,
+        V: Into<RecordData>,
+    {
+        let record = Record::from((key.into(), value.into()));
+        let (partition, record) = self
+            .partition_records(vec![record])
+            .await?
+            .pop()
+            .ok_or_else(|| FluvioError::Other("record was not assigned a partition".to_owned()))?;
+        let queue = self.partition_senders.queue(partition).await;
+        let outcome = queue.push(&self.accumulator, record).await?;
+
+        if let Some(id) = outcome.new_batch {
+            let linger = self.config.linger;
+            let accumulator = self.accumulator.clone();
+            spawn(async move {
+                sleep(linger).await;
+                debug!(partition, "linger expired, sending batch");
+                if let Err(err) = queue.take(&accumulator, Some(id)).await {
+                    error!(partition, "error queueing batch: {}", err);
+                }
+            });
+        }
+
+        Ok(outcome.delivery)
+    }
+
+    /// Sends all records accumulated by `send_async` and waits for them to be acknowledged
+    ///
+    /// Also waits for batches which were already being sent. Returns first error
+    /// of batches sent since previous flush.
+    #[instrument(
+        skip(self),
+        fields(topic = %self.topic),
+    )]
+    pub async fn flush(&self) -> Result<(), FluvioError> {
+        for partition in self.accumulator.partitions().await {
+            let queue = self.partition_senders.queue(partition).await;
+            queue.take(&self.accumulator, None).await?;
+        }
+        self.partition_senders.flush().await
+    }
+
+    /// assign partition to each record
+    async fn partition_records(
+        &self,
+        entries: Vec<Record>,
+    ) -> Result<Vec<(PartitionId, Record)>, FluvioError> {
         let topics = self.pool.metadata.topics();
         let topic_spec = topics
             .lookup_by_key(&self.topic)
@@ -88,11 +297,6 @@ impl TopicProducer {
         let partition_count = topic_spec.partitions();
         let partition_config = PartitionerConfig { partition_count };
 
-        let entries = records
-            .into_iter()
-            .map::<(RecordKey, RecordData), _>(|(k, v)| (k.into(), v.into()))
-            .map(Record::from);
-
         // Calculate the partition for each entry
         // Use a block scope to ensure we drop the partitioner lock
         let records_by_partition = {
@@ -109,6 +313,12 @@ impl TopicProducer {
             iter
         };
 
+        Ok(records_by_partition)
+    }
+
+    async fn send_records(&self, entries: Vec<Record>) -> Result<(), FluvioError> {
+        let records_by_partition = self.partition_records(entries).await?;
+
         // Group all of the records by the partitions they belong to, then
         // group all of the partitions by the SpuId that leads that partition
         let partitions_by_spu = group_by_spu(
@@ -119,17 +329,150 @@ impl TopicProducer {
         .await?;
 
         // Create one request per SPU leader
-        let requests = assemble_requests(&self.topic, partitions_by_spu);
+        let requests = assemble_requests(
+            &self.topic,
+            partitions_by_spu,
+            &HashMap::new(),
+            &self.config,
+        )?;
 
         for (leader, request) in requests {
             let spu_client = self.pool.create_serial_socket_from_leader(leader).await?;
-            spu_client.send_receive(request).await?;
+            if self.config.acks == Acks::None {
+                // SPU doesn't respond, so only wait for request to be sent
+                spu_client.send_only(request).await?;
+            } else {
+                let response = spu_client.send_receive(request).await?;
+                check_produce_response(&response)?;
+            }
         }
 
         Ok(())
     }
 }
 
+/// send accumulated batches and resolve delivery of their records.
+/// returns first error encountered
+async fn send_batches(
+    topic: &str,
+    pool: &SpuPool,
+    config: &ProducerConfig,
+    batches: Vec<(PartitionId, ProducerBatch)>,
+) -> Result<(), FluvioError> {
+    let mut senders: HashMap<PartitionId, Vec<DeliverySender>> = HashMap::new();
+    let mut create_times: HashMap<PartitionId, Timestamp> = HashMap::new();
+    let mut records_by_partition = vec![];
+    for (partition, batch) in batches {
+        create_times.insert(partition, batch.create_time);
+        senders
+            .entry(partition)
+            .or_insert_with(Vec::new)
+            .extend(batch.senders);
+        records_by_partition.extend(batch.records.into_iter().map(|record| (partition, record)));
+    }
+
+    let requests = match group_by_spu(topic, pool.metadata.partitions(), records_by_partition)
+        .await
+        .and_then(|partitions_by_spu| {
+            assemble_requests(topic, partitions_by_spu, &create_times, config)
+        }) {
+        Ok(requests) => requests,
+        Err(err) => {
+            for partition_senders in senders.into_iter().map(|(_, senders)| senders) {
+                deliver(partition_senders, || {
+                    Err(FluvioError::Other(err.to_string()))
+                });
+            }
+            return Err(err);
+        }
+    };
+
+    let mut result = Ok(());
+    for (leader, request) in requests {
+        let partitions: Vec<PartitionId> = request
+            .topics
+            .iter()
+            .flat_map(|topic_request| topic_request.partitions.iter())
+            .map(|partition_request| partition_request.partition_index)
+            .collect();
+
+        let response = match pool.create_serial_socket_from_leader(leader).await {
+            // with acks = 0, SPU doesn't respond, records are delivered once request is sent
+            Ok(spu_client) if config.acks == Acks::None => spu_client
+                .send_only(request)
+                .await
+                .map(|_| None)
+                .map_err(|err| err.into()),
+            Ok(spu_client) => spu_client
+                .send_receive(request)
+                .await
+                .map(Some)
+                .map_err(|err| err.into()),
+            Err(err) => Err(err),
+        };
+
+        match response {
+            Ok(None) => {
+                for partition in partitions {
+                    if let Some(partition_senders) = senders.remove(&partition) {
+                        deliver(partition_senders, || Ok(()));
+                    }
+                }
+            }
+            Ok(Some(response)) => {
+                for topic_response in &response.responses {
+                    for partition_response in &topic_response.partitions {
+                        let partition = partition_response.partition_index;
+                        let code = &partition_response.error_code;
+                        if let Some(partition_senders) = senders.remove(&partition) {
+                            deliver(partition_senders, || {
+                                if code.is_error() {
+                                    Err(FluvioError::ProducePartition(
+                                        topic.to_owned(),
+                                        partition,
+                                        code.clone(),
+                                    ))
+                                } else {
+                                    Ok(())
+                                }
+                            });
+                        }
+                    }
+                }
+                if result.is_ok() {
+                    result = check_produce_response(&response);
+                }
+            }
+            Err(err) => {
+                error!(leader, "error sending produce request: {}", err);
+                for partition in partitions {
+                    if let Some(partition_senders) = senders.remove(&partition) {
+                        deliver(partition_senders, || {
+                            Err(FluvioError::Other(err.to_string()))
+                        });
+                    }
+                }
+                if result.is_ok() {
+                    result = Err(err);
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// resolve delivery futures of records
+fn deliver<F>(senders: Vec<DeliverySender>, result: F)
+where
+    F: Fn() -> Result<(), FluvioError>,
+{
+    for sender in senders {
+        // receiver may have been dropped if caller doesn't wait for delivery
+        let _ = sender.send(result());
+    }
+}
+
 async fn group_by_spu(
     topic: &str,
     partitions: &StoreContext<PartitionSpec>,
@@ -154,12 +497,33 @@ async fn group_by_spu(
     Ok(map)
 }
 
+/// return error of first partition which failed
+fn check_produce_response(response: &ProduceResponse) -> Result<(), FluvioError> {
+    for topic_response in &response.responses {
+        for partition_response in &topic_response.partitions {
+            if partition_response.error_code.is_error() {
+                return Err(FluvioError::ProducePartition(
+                    topic_response.name.clone(),
+                    partition_response.partition_index,
+                    partition_response.error_code.clone(),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// records of partition in `create_times` were accumulated since that time and carry their
+/// timestamp delta from it, records of other partitions are created now
 fn assemble_requests(
     topic: &str,
     partitions_by_spu: HashMap<SpuId, HashMap<PartitionId, MemoryRecords>>,
-) -> Vec<(SpuId, DefaultProduceRequest)> {
+    create_times: &HashMap<PartitionId, Timestamp>,
+    config: &ProducerConfig,
+) -> Result<Vec<(SpuId, DefaultProduceRequest)>, FluvioError> {
     let mut requests: Vec<(SpuId, DefaultProduceRequest)> =
         Vec::with_capacity(partitions_by_spu.len());
+    let now = now_timestamp();
 
     for (leader, partitions) in partitions_by_spu {
         let mut request = DefaultProduceRequest::default();
@@ -174,17 +538,20 @@ fn assemble_requests(
                 partition_index: partition,
                 ..Default::default()
             };
-            partition_request.records.batches.push(Batch::from(records));
+            let mut batch = Batch::from(records);
+            batch.set_first_create_time(create_times.get(&partition).copied().unwrap_or(now));
+            batch.compress(config.compression)?;
+            partition_request.records.batches.push(batch);
             topic_request.partitions.push(partition_request);
         }
 
-        request.acks = 1;
-        request.timeout_ms = 1500;
+        request.acks = config.acks.value();
+        request.timeout_ms = config.timeout.as_millis() as i32;
         request.topics.push(topic_request);
         requests.push((leader, request));
     }
 
-    requests
+    Ok(requests)
 }
 
 /// A trait for defining a partitioning strategy for key/value records.
@@ -394,13 +761,23 @@ mod tests {
             pbs
         };
 
-        let requests = assemble_requests("TOPIC", partitions_by_spu);
+        let config = ProducerConfig::builder()
+            .acks(Acks::All)
+            .build()
+            .expect("config");
+        // records of partition 1 were accumulated earlier
+        let mut create_times = HashMap::new();
+        create_times.insert(1, 1000);
+        let requests = assemble_requests("TOPIC", partitions_by_spu, &create_times, &config)
+            .expect("requests");
         assert_eq!(requests.len(), 2);
 
         // SPU 0
         {
             let (spu0, request) = requests.iter().find(|(spu, _)| *spu == 0).unwrap();
             assert_eq!(*spu0, 0);
+            assert_eq!(request.acks, -1);
+            assert_eq!(request.timeout_ms, 1500);
             assert_eq!(request.topics.len(), 1);
             let topic_request = request.topics.get(0).unwrap();
             assert_eq!(topic_request.name, "TOPIC");
@@ -414,6 +791,7 @@ mod tests {
             assert_eq!(partition_0_request.records.batches.len(), 1);
             let batch = partition_0_request.records.batches.get(0).unwrap();
             assert_eq!(batch.records().len(), 2);
+            assert!(batch.get_header().record_timestamp(0) > 0);
             let record_0_0 = batch.records().get(0).unwrap();
             assert_eq!(record_0_0.value.as_ref(), b"A");
             let record_0_1 = batch.records().get(1).unwrap();
@@ -427,6 +805,7 @@ mod tests {
             assert_eq!(partition_1_request.records.batches.len(), 1);
             let batch = partition_1_request.records.batches.get(0).unwrap();
             assert_eq!(batch.records().len(), 2);
+            assert_eq!(batch.get_header().record_timestamp(0), 1000);
             let record_1_0 = batch.records().get(0).unwrap();
             assert_eq!(record_1_0.value.as_ref(), b"C");
             let record_1_1 = batch.records().get(1).unwrap();
@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_channel::{bounded, Receiver, Sender};
+use async_lock::Mutex;
+use tokio::sync::oneshot;
+use tracing::{debug, error};
+
+use fluvio_future::task::spawn;
+use fluvio_types::PartitionId;
+
+use crate::FluvioError;
+use crate::spu::SpuPool;
+
+use dataplane::record::Record;
+
+use super::ProducerConfig;
+use super::send_batches;
+use super::accumulator::{ProducerBatch, PushOutcome, RecordAccumulator};
+
+/// number of batches which can be queued for partition before `send_async` waits
+const PARTITION_QUEUE_SIZE: usize = 16;
+
+type FlushSender = oneshot::Sender<Result<(), FluvioError>>;
+
+enum PartitionMessage {
+    /// batch to be sent to leader of partition
+    Batch(ProducerBatch),
+    /// notify once all previously queued batches are sent
+    Flush(FlushSender),
+}
+
+/// Queue of batches of single partition.
+/// Batches are removed from accumulator and queued while queue is locked,
+/// so a batch can't be queued before batch which was removed ahead of it.
+pub(crate) struct PartitionQueue {
+    partition: PartitionId,
+    sender: Mutex<Sender<PartitionMessage>>,
+}
+
+impl PartitionQueue {
+    fn new(partition: PartitionId, sender: Sender<PartitionMessage>) -> Self {
+        Self {
+            partition,
+            sender: Mutex::new(sender),
+        }
+    }
+
+    /// add record to accumulator, queue batch of partition if it is full
+    pub(crate) async fn push(
+        &self,
+        accumulator: &RecordAccumulator,
+        record: Record,
+    ) -> Result<PushOutcome, FluvioError> {
+        let sender = self.sender.lock().await;
+        let mut outcome = accumulator.push(self.partition, record).await;
+        if let Some(batch) = outcome.full_batch.take() {
+            debug!(partition = self.partition, "batch is full, sending");
+            self.queue(&sender, PartitionMessage::Batch(batch)).await?;
+        }
+        Ok(outcome)
+    }
+
+    /// queue batch of partition accumulating records, if any.
+    /// With `id`, batch is only queued if it is still the batch with that id
+    pub(crate) async fn take(
+        &self,
+        accumulator: &RecordAccumulator,
+        id: Option<u64>,
+    ) -> Result<(), FluvioError> {
+        let sender = self.sender.lock().await;
+        if let Some(batch) = accumulator.take(self.partition, id).await {
+            self.queue(&sender, PartitionMessage::Batch(batch)).await?;
+        }
+        Ok(())
+    }
+
+    async fn flush(&self, flushed: FlushSender) -> Result<(), FluvioError> {
+        let sender = self.sender.lock().await;
+        self.queue(&sender, PartitionMessage::Flush(flushed)).await
+    }
+
+    async fn queue(
+        &self,
+        sender: &Sender<PartitionMessage>,
+        message: PartitionMessage,
+    ) -> Result<(), FluvioError> {
+        sender.send(message).await.map_err(|_| {
+            FluvioError::Other(format!("sender of partition {} terminated", self.partition))
+        })
+    }
+}
+
+/// Sends batches accumulated by `send_async`.
+/// Each partition has single task which sends its batches one at a time,
+/// so batches of partition are written in the order they were queued.
+pub(crate) struct PartitionSenders {
+    topic: String,
+    pool: Arc<SpuPool>,
+    config: Arc<ProducerConfig>,
+    queues: Mutex<HashMap<PartitionId, Arc<PartitionQueue>>>,
+}
+
+impl PartitionSenders {
+    pub(crate) fn new(topic: String, pool: Arc<SpuPool>, config: Arc<ProducerConfig>) -> Self {
+        Self {
+            topic,
+            pool,
+            config,
+            queues: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// queue of partition, sender task of partition is started on first use
+    pub(crate) async fn queue(&self, partition: PartitionId) -> Arc<PartitionQueue> {
+        let mut queues = self.queues.lock().await;
+        queues
+            .entry(partition)
+            .or_insert_with(|| {
+                Arc::new(PartitionQueue::new(
+                    partition,
+                    self.start_partition(partition),
+                ))
+            })
+            .clone()
+    }
+
+    /// wait until all batches queued so far are sent.
+    /// returns first error of batches sent since previous flush
+    pub(crate) async fn flush(&self) -> Result<(), FluvioError> {
+        let queues: Vec<Arc<PartitionQueue>> = self.queues.lock().await.values().cloned().collect();
+
+        let mut flushes = vec![];
+        for queue in queues {
+            let (sender, receiver) = oneshot::channel();
+            queue.flush(sender).await?;
+            flushes.push(receiver);
+        }
+
+        let mut result = Ok(());
+        for flush in flushes {
+            let flushed = flush.await.unwrap_or_else(|_| {
+                Err(FluvioError::Other(
+                    "partition sender terminated before flush".to_owned(),
+                ))
+            });
+            if result.is_ok() {
+                result = flushed;
+            }
+        }
+        result
+    }
+
+    /// spawn task sending batches of partition
+    fn start_partition(&self, partition: PartitionId) -> Sender<PartitionMessage> {
+        let (sender, receiver) = bounded(PARTITION_QUEUE_SIZE);
+        let topic = self.topic.clone();
+        let pool = self.pool.clone();
+        let config = self.config.clone();
+        spawn(async move {
+            debug!(%topic, partition, "starting partition sender");
+            dispatch_partition(&topic, &pool, &config, partition, receiver).await;
+            debug!(%topic, partition, "partition sender terminated");
+        });
+        sender
+    }
+}
+
+/// send batches of partition until producer is dropped
+async fn dispatch_partition(
+    topic: &str,
+    pool: &SpuPool,
+    config: &ProducerConfig,
+    partition: PartitionId,
+    receiver: Receiver<PartitionMessage>,
+) {
+    let mut last_error = None;
+    while let Ok(message) = receiver.recv().await {
+        match message {
+            PartitionMessage::Batch(batch) => {
+                if let Err(err) = send_batches(topic, pool, config, vec![(partition, batch)]).await
+                {
+                    error!(partition, "error sending batch: {}", err);
+                    if last_error.is_none() {
+                        last_error = Some(err);
+                    }
+                }
+            }
+            PartitionMessage::Flush(flushed) => {
+                let result = match last_error.take() {
+                    Some(err) => Err(err),
+                    None => Ok(()),
+                };
+                // caller may have stopped waiting for flush
+                let _ = flushed.send(result);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use async_channel::{bounded, Receiver};
+    use fluvio_future::timer::sleep;
+    use futures_util::future::join;
+
+    use dataplane::core::Encoder;
+    use dataplane::record::Record;
+
+    use super::{PartitionMessage, PartitionQueue, RecordAccumulator};
+
+    /// values of records of batches queued so far, in queue order
+    fn queued_values(receiver: &Receiver<PartitionMessage>) -> Vec<String> {
+        let mut values = vec![];
+        while let Ok(message) = receiver.try_recv() {
+            if let PartitionMessage::Batch(batch) = message {
+                for record in batch.records {
+                    values.push(String::from_utf8_lossy(record.value.as_ref()).to_string());
+                }
+            }
+        }
+        values
+    }
+
+    #[fluvio_future::test_async]
+    async fn test_full_batch_races_linger() -> Result<(), ()> {
+        let record_size = Record::new("0").write_size(0);
+        let accumulator = RecordAccumulator::new(record_size * 3);
+        let (sender, receiver) = bounded(1024);
+        let queue = PartitionQueue::new(0, sender);
+
+        // lingers of batches expire while following records fill next batches
+        let (linger_sender, linger_receiver) = bounded(1024);
+        let produce = async {
+            for i in 0..200 {
+                let outcome = queue
+                    .push(&accumulator, Record::new(i.to_string()))
+                    .await
+                    .expect("push");
+                if let Some(id) = outcome.new_batch {
+                    linger_sender.send(id).await.expect("linger");
+                }
+                if i % 7 == 0 {
+                    sleep(Duration::from_millis(0)).await;
+                }
+            }
+            linger_sender.close();
+        };
+        let linger = async {
+            while let Ok(id) = linger_receiver.recv().await {
+                queue.take(&accumulator, Some(id)).await.expect("take");
+            }
+        };
+        join(produce, linger).await;
+        queue.take(&accumulator, None).await.expect("flush");
+
+        let expected: Vec<String> = (0..200).map(|i: i32| i.to_string()).collect();
+        assert_eq!(queued_values(&receiver), expected);
+
+        Ok(())
+    }
+}
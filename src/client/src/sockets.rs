@@ -8,11 +8,13 @@ use tracing::{debug, trace, instrument};
 use dataplane::api::RequestMessage;
 use dataplane::api::Request;
 use dataplane::versions::{ApiVersions, ApiVersionsRequest, ApiVersionsResponse};
+use dataplane::authenticate::AuthenticateRequest;
 use fluvio_socket::SocketError;
 use fluvio_socket::{FluvioSocket, SharedMultiplexerSocket};
 use fluvio_future::net::{DomainConnector, DefaultDomainConnector};
 
 use crate::FluvioError;
+use crate::config::Credentials;
 
 /// Frame with request and response
 pub(crate) trait SerialFrame: Display {
@@ -47,6 +49,10 @@ impl VersionedSocket {
         mut socket: FluvioSocket,
         config: Arc<ClientConfig>,
     ) -> Result<Self, FluvioError> {
+        if let Some(credentials) = &config.credentials {
+            Self::authenticate(&mut socket, &config.client_id, credentials).await?;
+        }
+
         // now get versions
         // Query for API versions
 
@@ -66,6 +72,32 @@ impl VersionedSocket {
         })
     }
 
+    /// authenticate must be first request when cluster requires credentials
+    async fn authenticate(
+        socket: &mut FluvioSocket,
+        client_id: &str,
+        credentials: &Credentials,
+    ) -> Result<(), FluvioError> {
+        let request = match credentials {
+            Credentials::Password { username, password } => {
+                AuthenticateRequest::password(username.clone(), password.clone())
+            }
+            Credentials::Token { token } => AuthenticateRequest::token(token.clone()),
+        };
+        let mut req_msg = RequestMessage::new_request(request);
+        req_msg.get_mut_header().set_client_id(client_id);
+
+        debug!("authenticating");
+        let response = socket.send(&req_msg).await?.response;
+        if response.error_code.is_error() {
+            return Err(FluvioError::Authentication(
+                response.error_code.to_sentence(),
+            ));
+        }
+        debug!(principal = %response.principal, "authenticated");
+        Ok(())
+    }
+
     pub fn split(self) -> (FluvioSocket, Arc<ClientConfig>, Versions) {
         (self.socket, self.config, self.versions)
     }
@@ -76,6 +108,7 @@ pub struct ClientConfig {
     addr: String,
     client_id: String,
     connector: DomainConnector,
+    credentials: Option<Credentials>,
 }
 
 impl fmt::Display for ClientConfig {
@@ -96,6 +129,7 @@ impl ClientConfig {
             addr: addr.into(),
             client_id: "fluvio".to_owned(),
             connector,
+            credentials: None,
         }
     }
 
@@ -117,6 +151,12 @@ impl ClientConfig {
         self
     }
 
+    /// set credentials used to authenticate each connection
+    pub fn with_credentials(mut self, credentials: Option<Credentials>) -> Self {
+        self.credentials = credentials;
+        self
+    }
+
     pub fn set_addr(&mut self, domain: String) {
         self.addr = domain
     }
@@ -141,6 +181,7 @@ impl ClientConfig {
             addr: self.addr.clone(),
             client_id: self.client_id.clone(),
             connector,
+            credentials: self.credentials.clone(),
         }
     }
 }
@@ -180,6 +221,7 @@ impl Versions {
 }
 
 /// Connection that perform request/response
+#[derive(Clone)]
 pub struct VersionedSerialSocket {
     socket: SharedMultiplexerSocket,
     config: Arc<ClientConfig>,
@@ -222,6 +264,17 @@ impl VersionedSerialSocket {
         self.socket.send_and_receive(req_msg).await
     }
 
+    /// send request without waiting for reply.
+    /// Server must not respond to request, otherwise response is discarded.
+    #[instrument(level = "trace", skip(self, request))]
+    pub async fn send_only<R>(&self, request: R) -> Result<(), SocketError>
+    where
+        R: Request + Send + Sync,
+    {
+        let req_msg = self.new_request(request, self.versions.lookup_version(R::API_KEY));
+        self.socket.send_only(req_msg).await
+    }
+
     /// create new request based on version
     #[instrument(level = "trace", skip(self, request, version))]
     fn new_request<R>(&self, request: R, version: Option<i16>) -> RequestMessage<R>
@@ -0,0 +1,242 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::stream::{BoxStream, Stream, StreamExt, select_all, unfold};
+use tokio::select;
+use tracing::{debug, error, instrument};
+
+use dataplane::{ErrorCode, ReplicaKey};
+use fluvio_future::timer::sleep;
+use fluvio_sc_schema::ApiError;
+use fluvio_types::SpuId;
+
+use crate::FluvioError;
+use crate::offset::Offset;
+use crate::consumer::{ConsumerConfig, PartitionConsumer, Record};
+use crate::spu::SpuPool;
+
+/// maximum time to wait for new leader before reconnecting to partition
+const LEADER_CHANGE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// An interface for consuming events from all partitions of a topic
+///
+/// A `TopicConsumer` opens a stream to the leader of each partition and
+/// merges them into a single stream. Each [`Record`] is tagged with the
+/// partition it was read from. Order is preserved within a partition, but
+/// not across partitions.
+///
+/// When the leader of a partition changes, the stream of that partition is
+/// re-opened to the new leader, continuing after the last record received.
+///
+/// # Example
+///
+/// ```no_run
+/// # use fluvio::{Fluvio, Offset, FluvioError};
+/// # mod futures {
+/// #     pub use futures_util::stream::StreamExt;
+/// # }
+/// # async fn example(fluvio: &Fluvio) -> Result<(), FluvioError> {
+/// use futures::StreamExt;
+/// let consumer = fluvio.topic_consumer("my-topic").await?;
+/// let mut stream = consumer.stream(Offset::beginning()).await?;
+/// while let Some(Ok(record)) = stream.next().await {
+///     let value = String::from_utf8_lossy(record.value());
+///     println!("Got record from partition {}: {}", record.partition(), value);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Record`]: consumer/struct.Record.html
+pub struct TopicConsumer {
+    topic: String,
+    pool: Arc<SpuPool>,
+}
+
+impl TopicConsumer {
+    pub(crate) fn new(topic: String, pool: Arc<SpuPool>) -> Self {
+        Self { topic, pool }
+    }
+
+    /// Returns the name of the Topic that this consumer reads from
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    /// Returns the IDs of the partitions of the topic, in ascending order
+    pub async fn partitions(&self) -> Vec<i32> {
+        let store = self.pool.metadata.partitions().store().read().await;
+        let mut partitions: Vec<i32> = store
+            .keys()
+            .filter(|replica| replica.topic == self.topic)
+            .map(|replica| replica.partition)
+            .collect();
+        partitions.sort_unstable();
+        partitions
+    }
+
+    /// Continuously streams events from all partitions of the topic
+    ///
+    /// Each partition starts streaming from `offset`, relative to that partition.
+    #[instrument(skip(self, offset))]
+    pub async fn stream(
+        &self,
+        offset: Offset,
+    ) -> Result<impl Stream<Item = Result<Record, FluvioError>>, FluvioError> {
+        let config = ConsumerConfig::builder().build()?;
+        self.stream_with_config(offset, config).await
+    }
+
+    /// Continuously streams events from all partitions of the topic
+    /// using a specific fetching configuration
+    #[instrument(skip(self, offset, config))]
+    pub async fn stream_with_config(
+        &self,
+        offset: Offset,
+        config: ConsumerConfig,
+    ) -> Result<impl Stream<Item = Result<Record, FluvioError>>, FluvioError> {
+        let partitions = self.partitions().await;
+        if partitions.is_empty() {
+            return Err(FluvioError::TopicNotFound(self.topic.clone()));
+        }
+        debug!(?partitions, "streaming from partitions");
+
+        let streams = partitions.into_iter().map(|partition| {
+            let follower = LeaderFollower {
+                topic: self.topic.clone(),
+                partition,
+                pool: self.pool.clone(),
+                offset: offset.clone(),
+                config: config.clone(),
+                next_offset: None,
+                leader: None,
+                stream: None,
+            };
+            follower.into_stream()
+        });
+
+        Ok(select_all(streams))
+    }
+}
+
+/// stream from partition which is re-opened to new leader when leader is lost
+struct LeaderFollower {
+    topic: String,
+    partition: i32,
+    pool: Arc<SpuPool>,
+    offset: Offset,
+    config: ConsumerConfig,
+    /// offset after last record received, used when stream is re-opened
+    next_offset: Option<i64>,
+    leader: Option<SpuId>,
+    stream: Option<BoxStream<'static, Result<Record, FluvioError>>>,
+}
+
+impl LeaderFollower {
+    fn into_stream(self) -> BoxStream<'static, Result<Record, FluvioError>> {
+        unfold(Some(self), |follower| async move {
+            let mut follower = follower?;
+            loop {
+                if follower.stream.is_none() {
+                    match follower.open().await {
+                        Ok(stream) => follower.stream = Some(stream),
+                        Err(err @ FluvioError::PartitionNotFound(_, _)) => {
+                            return Some((Err(err), None))
+                        }
+                        Err(err) => {
+                            error!(
+                                partition = follower.partition,
+                                "error opening partition stream: {}", err
+                            );
+                            follower.wait_for_leader_change().await;
+                            continue;
+                        }
+                    }
+                }
+
+                match follower.stream.as_mut()?.next().await {
+                    Some(Ok(record)) => {
+                        follower.next_offset = Some(record.offset() + 1);
+                        return Some((Ok(record), Some(follower)));
+                    }
+                    Some(Err(err)) if !is_leader_lost(&err) => {
+                        return Some((Err(err), Some(follower)));
+                    }
+                    Some(Err(err)) => debug!(
+                        partition = follower.partition,
+                        "partition leader lost: {}", err
+                    ),
+                    None => debug!(partition = follower.partition, "partition stream ended"),
+                }
+
+                follower.stream = None;
+                follower.wait_for_leader_change().await;
+            }
+        })
+        .boxed()
+    }
+
+    fn replica(&self) -> ReplicaKey {
+        ReplicaKey::new(&self.topic, self.partition)
+    }
+
+    /// current leader of partition from metadata store
+    async fn current_leader(&self) -> Option<SpuId> {
+        self.pool
+            .metadata
+            .partitions()
+            .store()
+            .value(&self.replica())
+            .await
+            .map(|partition| partition.spec().leader)
+    }
+
+    /// open stream to current leader, continuing after last record received
+    async fn open(
+        &mut self,
+    ) -> Result<BoxStream<'static, Result<Record, FluvioError>>, FluvioError> {
+        let offset = match self.next_offset {
+            Some(next_offset) => Offset::absolute(next_offset)?,
+            None => self.offset.clone(),
+        };
+        self.leader = self.current_leader().await;
+        debug!(partition = self.partition, leader = ?self.leader, ?offset, "opening partition stream");
+
+        let consumer =
+            PartitionConsumer::new(self.topic.clone(), self.partition, self.pool.clone());
+        let stream = consumer
+            .stream_with_config(offset, self.config.clone())
+            .await?;
+        Ok(stream.boxed())
+    }
+
+    /// wait until metadata store has new leader for partition or timeout expires
+    async fn wait_for_leader_change(&self) {
+        let mut listener = self.pool.metadata.partitions().store().change_listener();
+        let mut timer = sleep(LEADER_CHANGE_TIMEOUT);
+        loop {
+            select! {
+                _ = listener.listen() => {
+                    listener.sync_changes().await;
+                    if self.current_leader().await != self.leader {
+                        debug!(partition = self.partition, "partition leader changed");
+                        return;
+                    }
+                },
+                _ = &mut timer => {
+                    debug!(partition = self.partition, "no leader change, reconnecting");
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// stream is closed or SPU is no longer leader of partition
+fn is_leader_lost(err: &FluvioError) -> bool {
+    matches!(
+        err,
+        FluvioError::Socket(_)
+            | FluvioError::AdminApi(ApiError::Code(ErrorCode::NotLeaderForPartition, _))
+    )
+}
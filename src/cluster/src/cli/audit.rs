@@ -0,0 +1,73 @@
+//!
+//! # Audit Log
+//!
+//! CLI tree to query admin actions recorded by SC
+//!
+use structopt::StructOpt;
+
+use fluvio::Fluvio;
+use fluvio::dataplane::now_timestamp;
+use fluvio::metadata::audit::AuditLogRequest;
+
+use crate::cli::ClusterCliError;
+
+#[derive(Debug, StructOpt)]
+pub struct AuditOpt {
+    /// Only show actions done by principal
+    #[structopt(long)]
+    principal: Option<String>,
+
+    /// Only show actions on object type, ex: topic, partition, spu-group
+    #[structopt(long = "type", value_name = "type")]
+    object_type: Option<String>,
+
+    /// Only show actions in last number of minutes
+    #[structopt(long, value_name = "minutes")]
+    since: Option<u64>,
+
+    /// Max number of newest records to show
+    #[structopt(long, default_value = "100")]
+    max: u32,
+}
+
+impl AuditOpt {
+    pub async fn process(self, fluvio: &Fluvio) -> Result<(), ClusterCliError> {
+        let since = self
+            .since
+            .map(|minutes| now_timestamp() - (minutes * 60 * 1000) as i64);
+
+        let request = AuditLogRequest {
+            principal: self.principal,
+            object_type: self.object_type.map(|ty| ty.replace('-', "")),
+            since,
+            max_records: self.max,
+        };
+
+        let admin = fluvio.admin().await;
+        let records = admin.audit_log(request).await?;
+        if records.is_empty() {
+            println!("no audit records found");
+        }
+        for record in records {
+            let principal = if record.principal.is_empty() {
+                "-"
+            } else {
+                &record.principal
+            };
+            println!(
+                "{} {} {} {} \"{}\" {}{}",
+                record.timestamp,
+                principal,
+                record.action,
+                record.object_type,
+                record.key,
+                if record.success { "ok" } else { "failed" },
+                record
+                    .message
+                    .map(|message| format!(": {}", message))
+                    .unwrap_or_default()
+            );
+        }
+        Ok(())
+    }
+}
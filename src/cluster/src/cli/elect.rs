@@ -0,0 +1,75 @@
+//!
+//! # Elect Leaders
+//!
+//! CLI tree to move leadership of partitions back to their preferred replica
+//!
+use std::convert::TryFrom;
+
+use structopt::StructOpt;
+
+use fluvio::Fluvio;
+use fluvio::metadata::partition::{PartitionSpec, ReplicaKey};
+
+use crate::cli::ClusterCliError;
+
+#[derive(Debug, StructOpt)]
+pub struct ElectLeadersOpt {
+    /// Topics to elect leaders for, all topics if not specified
+    #[structopt(value_name = "topic")]
+    topics: Vec<String>,
+
+    /// Report partitions whose leader is not preferred replica without electing
+    #[structopt(long)]
+    dry_run: bool,
+}
+
+impl ElectLeadersOpt {
+    pub async fn process(self, fluvio: &Fluvio) -> Result<(), ClusterCliError> {
+        let admin = fluvio.admin().await;
+
+        if self.dry_run {
+            let mut not_preferred = 0;
+            for partition in admin.list::<PartitionSpec, _>(vec![]).await? {
+                let key = ReplicaKey::try_from(partition.name.clone())
+                    .map_err(|err| ClusterCliError::Other(err.to_string()))?;
+                if !self.topics.is_empty() && !self.topics.contains(&key.topic) {
+                    continue;
+                }
+                if let Some(preferred) = partition.spec.preferred_leader() {
+                    if preferred != partition.spec.leader {
+                        println!(
+                            "partition \"{}\" leader: {} preferred: {}",
+                            partition.name, partition.spec.leader, preferred
+                        );
+                        not_preferred += 1;
+                    }
+                }
+            }
+            if not_preferred == 0 {
+                println!("all partitions are led by preferred replica");
+            }
+            return Ok(());
+        }
+
+        let results = admin.elect_leaders(self.topics).await?;
+        if results.is_empty() {
+            println!("all partitions are led by preferred replica");
+        }
+        for result in results {
+            if result.is_elected() {
+                println!(
+                    "partition \"{}\" leader moved to {}",
+                    result.partition, result.leader
+                );
+            } else {
+                println!(
+                    "partition \"{}\" leader can't be moved to {}: {}",
+                    result.partition,
+                    result.leader,
+                    result.error_message.unwrap_or_default()
+                );
+            }
+        }
+        Ok(())
+    }
+}
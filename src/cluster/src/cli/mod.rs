@@ -9,6 +9,9 @@ mod start;
 mod delete;
 mod util;
 mod check;
+mod reassign;
+mod elect;
+mod audit;
 mod error;
 
 use start::StartOpt;
@@ -17,6 +20,9 @@ use delete::DeleteOpt;
 use check::CheckOpt;
 use group::SpuGroupCmd;
 use spu::SpuCmd;
+use reassign::ReassignPartitionOpt;
+use elect::ElectLeadersOpt;
+use audit::AuditOpt;
 
 pub use self::error::ClusterCliError;
 
@@ -57,6 +63,18 @@ pub enum ClusterCmd {
     /// SPGs are groups of SPUs in a cluster which are managed together.
     #[structopt(name = "spg")]
     SPUGroup(SpuGroupCmd),
+
+    /// Move replicas of a partition to different SPUs
+    #[structopt(name = "reassign")]
+    Reassign(ReassignPartitionOpt),
+
+    /// Move leadership of partitions back to their preferred replica
+    #[structopt(name = "elect-leaders")]
+    ElectLeaders(ElectLeadersOpt),
+
+    /// Show admin actions recorded by SC
+    #[structopt(name = "audit")]
+    Audit(AuditOpt),
 }
 
 impl ClusterCmd {
@@ -88,6 +106,18 @@ impl ClusterCmd {
                 let fluvio = target.connect().await?;
                 group.process(out, &fluvio).await?;
             }
+            Self::Reassign(reassign) => {
+                let fluvio = target.connect().await?;
+                reassign.process(&fluvio).await?;
+            }
+            Self::ElectLeaders(elect) => {
+                let fluvio = target.connect().await?;
+                elect.process(&fluvio).await?;
+            }
+            Self::Audit(audit) => {
+                let fluvio = target.connect().await?;
+                audit.process(&fluvio).await?;
+            }
         }
 
         Ok(())
@@ -0,0 +1,48 @@
+//!
+//! # Reassign Partition
+//!
+//! CLI tree to move replicas of partition to different SPUs
+//!
+use structopt::StructOpt;
+
+use fluvio::Fluvio;
+use fluvio::metadata::partition::ReplicaKey;
+use fluvio::metadata::objects::PartitionUpdate;
+
+use crate::cli::ClusterCliError;
+
+#[derive(Debug, StructOpt)]
+pub struct ReassignPartitionOpt {
+    /// Topic of partition
+    #[structopt(value_name = "topic")]
+    topic: String,
+
+    /// Partition to reassign
+    #[structopt(value_name = "partition")]
+    partition: i32,
+
+    /// Comma separated ids of SPUs which will host replicas of partition.
+    ///
+    /// First SPU becomes leader if current leader is not in the list.
+    /// Existing replicas are removed after new replicas have caught up with leader.
+    #[structopt(
+        short = "r",
+        long = "replicas",
+        value_name = "ids",
+        required = true,
+        use_delimiter = true
+    )]
+    replicas: Vec<i32>,
+}
+
+impl ReassignPartitionOpt {
+    pub async fn process(self, fluvio: &Fluvio) -> Result<(), ClusterCliError> {
+        let key = ReplicaKey::new(self.topic, self.partition);
+        let admin = fluvio.admin().await;
+        admin
+            .update(key.to_string(), PartitionUpdate::replicas(self.replicas))
+            .await?;
+        println!("reassignment of partition \"{}\" started", key);
+        Ok(())
+    }
+}
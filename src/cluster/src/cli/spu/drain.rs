@@ -0,0 +1,73 @@
+//!
+//! # Drain SPU
+//!
+//! CLI tree to move all replicas off SPU before it is decommissioned
+//!
+use std::convert::TryFrom;
+
+use structopt::StructOpt;
+
+use fluvio::Fluvio;
+use fluvio::metadata::partition::{PartitionSpec, ReplicaKey, drain_spu_plan};
+use fluvio::metadata::objects::PartitionUpdate;
+use fluvio_controlplane_metadata::spu::SpuSpec;
+
+use crate::cli::ClusterCliError;
+
+#[derive(Debug, StructOpt)]
+pub struct DrainSpuOpt {
+    /// Id of SPU to move replicas from
+    #[structopt(value_name = "id")]
+    id: i32,
+
+    /// Print reassignment plan without submitting it
+    #[structopt(long)]
+    dry_run: bool,
+}
+
+impl DrainSpuOpt {
+    pub async fn process(self, fluvio: &Fluvio) -> Result<(), ClusterCliError> {
+        let admin = fluvio.admin().await;
+
+        let candidates: Vec<i32> = admin
+            .list::<SpuSpec, _>(vec![])
+            .await?
+            .into_iter()
+            .filter(|spu| spu.status.is_online() && spu.spec.id != self.id)
+            .map(|spu| spu.spec.id)
+            .collect();
+
+        let mut partitions = vec![];
+        for partition in admin.list::<PartitionSpec, _>(vec![]).await? {
+            let key = ReplicaKey::try_from(partition.name)
+                .map_err(|err| ClusterCliError::Other(err.to_string()))?;
+            partitions.push((key, partition.spec));
+        }
+
+        let plan = drain_spu_plan(
+            self.id,
+            partitions.iter().map(|(key, spec)| (key, spec)),
+            &candidates,
+        )
+        .map_err(ClusterCliError::Other)?;
+
+        if plan.is_empty() {
+            println!("spu {} has no replicas", self.id);
+            return Ok(());
+        }
+
+        for (key, replicas) in plan {
+            println!("partition \"{}\" replicas: {:?}", key, replicas);
+            if !self.dry_run {
+                admin
+                    .update(key.to_string(), PartitionUpdate::replicas(replicas))
+                    .await?;
+            }
+        }
+
+        if !self.dry_run {
+            println!("reassignment of replicas on spu {} started", self.id);
+        }
+        Ok(())
+    }
+}
@@ -239,6 +239,7 @@ impl ClusterUninstaller {
         let _ = self.remove_custom_objects("topics", ns, None, false);
         let _ = self.remove_finalizers_for_partitions(ns).await;
         let _ = self.remove_custom_objects("partitions", ns, None, true);
+        let _ = self.remove_custom_objects("smartmodules", ns, None, false);
         let _ = self.remove_custom_objects("statefulset", ns, None, false);
         let _ = self.remove_custom_objects("persistentvolumeclaims", ns, Some("app=spu"), false);
 
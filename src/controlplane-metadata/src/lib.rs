@@ -2,6 +2,7 @@ pub mod spu;
 pub mod topic;
 pub mod partition;
 pub mod spg;
+pub mod smartmodule;
 pub mod message;
 
 pub use fluvio_stream_model::core;
@@ -25,6 +26,7 @@ pub mod extended {
         SpuGroup,
         Topic,
         Partition,
+        SmartModule,
     }
 
     pub trait SpecExt: Spec {
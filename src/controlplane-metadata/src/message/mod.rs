@@ -8,4 +8,7 @@ pub use self::replica_msg::ReplicaMsg;
 pub use self::replica_msg::ReplicaMsgs;
 
 use crate::spu::SpuSpec;
+use crate::smartmodule::SmartModule;
+
 pub type SpuMsg = Message<SpuSpec>;
+pub type SmartModuleMsg = Message<SmartModule>;
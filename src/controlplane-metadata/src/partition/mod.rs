@@ -2,6 +2,7 @@ mod spec;
 mod status;
 mod policy;
 mod replica;
+mod reassign;
 pub mod store;
 
 pub use self::spec::*;
@@ -9,6 +10,7 @@ pub use self::status::*;
 pub use dataplane::ReplicaKey;
 pub use self::policy::*;
 pub use self::replica::*;
+pub use self::reassign::*;
 
 #[cfg(feature = "k8")]
 mod k8;
@@ -0,0 +1,116 @@
+//!
+//! # Partition Reassignment
+//!
+//! Plan to move replicas of partitions off SPU before it is decommissioned.
+//!
+use std::collections::BTreeMap;
+
+use fluvio_types::SpuId;
+
+use super::{PartitionSpec, ReplicaKey};
+
+/// Compute target replicas for every partition which has replica on `spu`.
+/// Replica on `spu` is replaced by one of `candidates` which hosts fewest replicas,
+/// so partitions are spread evenly over remaining SPUs.
+/// Replacement is appended to the end, so an existing follower is preferred as new leader.
+pub fn drain_spu_plan<'a, I>(
+    spu: SpuId,
+    partitions: I,
+    candidates: &[SpuId],
+) -> Result<Vec<(ReplicaKey, Vec<SpuId>)>, String>
+where
+    I: IntoIterator<Item = (&'a ReplicaKey, &'a PartitionSpec)>,
+{
+    let partitions: Vec<(&ReplicaKey, &PartitionSpec)> = partitions.into_iter().collect();
+
+    // replicas hosted by each candidate
+    let mut load: BTreeMap<SpuId, usize> = candidates
+        .iter()
+        .filter(|candidate| **candidate != spu)
+        .map(|candidate| (*candidate, 0))
+        .collect();
+    for (_, spec) in &partitions {
+        for replica in &spec.replicas {
+            if let Some(count) = load.get_mut(replica) {
+                *count += 1;
+            }
+        }
+    }
+
+    let mut plan = vec![];
+    for (key, spec) in partitions {
+        if !spec.has_spu(&spu) {
+            continue;
+        }
+
+        let replacement = load
+            .iter()
+            .filter(|(candidate, _)| !spec.has_spu(candidate))
+            .min_by_key(|(_, count)| **count)
+            .map(|(candidate, _)| *candidate)
+            .ok_or_else(|| format!("no SPU available to move replica of {}", key))?;
+
+        if let Some(count) = load.get_mut(&replacement) {
+            *count += 1;
+        }
+
+        let mut target: Vec<SpuId> = spec
+            .replicas
+            .iter()
+            .filter(|replica| **replica != spu)
+            .cloned()
+            .collect();
+        target.push(replacement);
+        plan.push((key.clone(), target));
+    }
+
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_drain_spu_plan() {
+        let partitions = vec![
+            (
+                ReplicaKey::new("topic", 0),
+                PartitionSpec::from(vec![5001, 5002]),
+            ),
+            (
+                ReplicaKey::new("topic", 1),
+                PartitionSpec::from(vec![5002, 5003]),
+            ),
+            (
+                ReplicaKey::new("topic", 2),
+                PartitionSpec::from(vec![5003, 5001]),
+            ),
+        ];
+
+        let plan = drain_spu_plan(
+            5001,
+            partitions.iter().map(|(key, spec)| (key, spec)),
+            &[5001, 5002, 5003, 5004],
+        )
+        .expect("plan");
+
+        // 5004 hosts no replicas so both replicas of 5001 are moved there
+        assert_eq!(
+            plan,
+            vec![
+                (ReplicaKey::new("topic", 0), vec![5002, 5004]),
+                (ReplicaKey::new("topic", 2), vec![5003, 5004]),
+            ]
+        );
+
+        // no SPU left which doesn't host partition
+        assert!(drain_spu_plan(
+            5001,
+            partitions.iter().map(|(key, spec)| (key, spec)),
+            &[5002, 5003],
+        )
+        .is_err());
+    }
+}
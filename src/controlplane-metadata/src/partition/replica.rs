@@ -8,6 +8,7 @@ use crate::partition::ReplicaKey;
 use crate::core::{MetadataItem};
 use crate::store::MetadataStoreObject;
 use crate::partition::PartitionSpec;
+use crate::topic::TopicConfig;
 use super::store::*;
 
 /// Metadata about Replica send from SC
@@ -17,6 +18,8 @@ pub struct Replica {
     pub leader: SpuId,
     pub replicas: Vec<SpuId>,
     pub is_being_deleted: bool,
+    #[fluvio(min_version = 1)]
+    pub config: TopicConfig,
 }
 
 impl Replica {
@@ -35,6 +38,7 @@ impl Replica {
             leader,
             replicas,
             is_being_deleted,
+            config: TopicConfig::default(),
         }
     }
 }
@@ -53,6 +57,7 @@ where
             leader: inner.spec.leader,
             replicas: inner.spec.replicas,
             is_being_deleted,
+            config: inner.spec.config,
         }
     }
 }
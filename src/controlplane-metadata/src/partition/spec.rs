@@ -7,6 +7,8 @@
 use fluvio_types::SpuId;
 use dataplane::core::{Encoder, Decoder};
 
+use crate::topic::TopicConfig;
+
 /// Spec for Partition
 /// Each partition has replicas spread among SPU
 /// one of replica is leader which is duplicated in the leader field
@@ -19,6 +21,19 @@ use dataplane::core::{Encoder, Decoder};
 pub struct PartitionSpec {
     pub leader: SpuId,
     pub replicas: Vec<SpuId>,
+    #[fluvio(min_version = 1)]
+    #[cfg_attr(
+        feature = "use_serde",
+        serde(default, skip_serializing_if = "TopicConfig::is_empty")
+    )]
+    pub config: TopicConfig,
+    /// replicas which partition is being reassigned to, empty if no reassignment is in progress
+    #[fluvio(min_version = 1)]
+    #[cfg_attr(
+        feature = "use_serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub target_replicas: Vec<SpuId>,
 }
 
 impl std::default::Default for PartitionSpec {
@@ -26,13 +41,41 @@ impl std::default::Default for PartitionSpec {
         PartitionSpec {
             leader: 0,
             replicas: Vec::default(),
+            config: TopicConfig::default(),
+            target_replicas: vec![],
         }
     }
 }
 
 impl PartitionSpec {
     pub fn new(leader: SpuId, replicas: Vec<SpuId>) -> Self {
-        Self { leader, replicas }
+        Self {
+            leader,
+            replicas,
+            config: TopicConfig::default(),
+            target_replicas: vec![],
+        }
+    }
+
+    /// config inherited from topic
+    pub fn with_config(mut self, config: TopicConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// start reassignment of partition to target replicas
+    pub fn with_target_replicas(mut self, target_replicas: Vec<SpuId>) -> Self {
+        self.target_replicas = target_replicas;
+        self
+    }
+
+    pub fn is_reassigning(&self) -> bool {
+        !self.target_replicas.is_empty()
+    }
+
+    /// first replica is preferred leader
+    pub fn preferred_leader(&self) -> Option<SpuId> {
+        self.replicas.first().cloned()
     }
 
     pub fn has_spu(&self, spu: &SpuId) -> bool {
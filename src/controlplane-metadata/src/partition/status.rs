@@ -142,6 +142,23 @@ impl PartitionStatus {
         candiate_spu
     }
 
+    /// true if follower has replicated all records committed by leader
+    pub fn is_caught_up(&self, spu: SpuId) -> bool {
+        if self.leader.spu == spu {
+            return true;
+        }
+        self.replicas
+            .iter()
+            .any(|status| status.spu == spu && status.leo >= 0 && status.leo >= self.leader.hw)
+    }
+
+    /// remove status of followers which are no longer replicas
+    pub fn retain_replicas(&mut self, replicas: &[SpuId]) {
+        self.replicas
+            .retain(|status| replicas.contains(&status.spu));
+        self.update_lrs();
+    }
+
     /// merge status from spu
     /// ignore changes from spu = -1 or offsets = -1
     pub fn merge(&mut self, other: Self) {
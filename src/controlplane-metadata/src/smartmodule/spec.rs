@@ -0,0 +1,172 @@
+#![allow(clippy::assign_op_pattern)]
+
+//!
+//! # SmartModule Spec
+//!
+//! SmartModules are compiled WASM SmartStreams stored in the cluster by name,
+//! so that consumers can refer to them without uploading the module on every fetch.
+//!
+use std::fmt;
+
+use dataplane::core::{Encoder, Decoder};
+
+use crate::core::MetadataItem;
+use super::store::SmartModuleMetadata;
+
+#[derive(Encoder, Decoder, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "use_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct SmartModuleSpec {
+    pub kind: SmartModuleKind,
+    /// compiled WASM module
+    #[cfg_attr(feature = "use_serde", serde(with = "base64_serde"))]
+    pub wasm: Vec<u8>,
+    /// hex encoded SHA-256 digest of the WASM module, computed by the SC
+    #[cfg_attr(feature = "use_serde", serde(default))]
+    pub checksum: String,
+}
+
+impl SmartModuleSpec {
+    pub fn new(kind: SmartModuleKind, wasm: Vec<u8>) -> Self {
+        Self {
+            kind,
+            wasm,
+            checksum: String::new(),
+        }
+    }
+}
+
+impl fmt::Display for SmartModuleSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "kind: {}, size: {}, checksum: {}",
+            self.kind,
+            self.wasm.len(),
+            self.checksum
+        )
+    }
+}
+
+#[derive(Encoder, Decoder, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "use_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum SmartModuleKind {
+    Filter,
+    Map,
+    ArrayMap,
+    FilterMap,
+    Aggregate,
+}
+
+impl Default for SmartModuleKind {
+    fn default() -> Self {
+        Self::Filter
+    }
+}
+
+impl SmartModuleKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Filter => "filter",
+            Self::Map => "map",
+            Self::ArrayMap => "array-map",
+            Self::FilterMap => "filter-map",
+            Self::Aggregate => "aggregate",
+        }
+    }
+}
+
+impl fmt::Display for SmartModuleKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+impl std::str::FromStr for SmartModuleKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "filter" => Ok(Self::Filter),
+            "map" => Ok(Self::Map),
+            "array-map" => Ok(Self::ArrayMap),
+            "filter-map" => Ok(Self::FilterMap),
+            "aggregate" => Ok(Self::Aggregate),
+            _ => Err(format!("invalid SmartModule kind: {}", s)),
+        }
+    }
+}
+
+/// SmartModule sent from SC to SPUs
+#[derive(Encoder, Decoder, Default, Debug, Clone, PartialEq)]
+pub struct SmartModule {
+    pub name: String,
+    pub spec: SmartModuleSpec,
+}
+
+impl<C> From<SmartModuleMetadata<C>> for SmartModule
+where
+    C: MetadataItem,
+{
+    fn from(item: SmartModuleMetadata<C>) -> Self {
+        Self {
+            name: item.key,
+            spec: item.spec,
+        }
+    }
+}
+
+impl fmt::Display for SmartModule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.name, self.spec)
+    }
+}
+
+/// WASM bytes are stored as base64 string in K8 objects
+#[cfg(feature = "use_serde")]
+mod base64_serde {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(wasm: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&base64::encode(wasm))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let encoded = String::deserialize(deserializer)?;
+        base64::decode(encoded).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::SmartModuleKind;
+
+    #[test]
+    fn test_smartmodule_kind_parse() {
+        for kind in &[
+            SmartModuleKind::Filter,
+            SmartModuleKind::Map,
+            SmartModuleKind::ArrayMap,
+            SmartModuleKind::FilterMap,
+            SmartModuleKind::Aggregate,
+        ] {
+            let parsed: SmartModuleKind = kind.label().parse().expect("parse");
+            assert_eq!(&parsed, kind);
+        }
+        assert!("reduce".parse::<SmartModuleKind>().is_err());
+    }
+}
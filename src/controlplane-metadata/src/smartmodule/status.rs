@@ -0,0 +1,22 @@
+#![allow(clippy::assign_op_pattern)]
+
+//!
+//! # SmartModule Status
+//!
+use std::fmt;
+
+use dataplane::core::{Encoder, Decoder};
+
+#[derive(Encoder, Decoder, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "use_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct SmartModuleStatus {}
+
+impl fmt::Display for SmartModuleStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SmartModuleStatus")
+    }
+}
@@ -0,0 +1,11 @@
+//!
+//! SmartModule
+//!
+
+use crate::store::*;
+
+use super::*;
+
+pub type SmartModuleMetadata<C> = MetadataStoreObject<SmartModuleSpec, C>;
+
+pub type SmartModuleLocalStore<C> = LocalStore<SmartModuleSpec, C>;
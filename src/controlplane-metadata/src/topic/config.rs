@@ -0,0 +1,370 @@
+//!
+//! # Topic Config
+//!
+//! Per-topic overrides of SPU storage and replication settings, as key/value pairs.
+//! Keys which are not set use the setting of SPU.
+//!
+use std::collections::BTreeMap;
+
+use fluvio_types::defaults::{SPU_LOG_SEGMENT_MAX_BYTES, SPU_LOG_MAX_BATCH_SIZE};
+use fluvio_types::defaults::{SPU_MIN_IN_SYNC_REPLICAS, SPU_LOG_TOMBSTONE_RETENTION_SECONDS};
+
+use dataplane::core::{Encoder, Decoder};
+
+/// segments older than this are removed
+pub const RETENTION_SECONDS: &str = "retention.seconds";
+/// size at which active segment is rolled over
+pub const SEGMENT_MAX_BYTES: &str = "segment.max.bytes";
+/// oldest segments are removed when partition exceeds this size
+pub const PARTITION_MAX_BYTES: &str = "partition.max.bytes";
+/// largest batch of records accepted by SPU
+pub const MAX_MESSAGE_BYTES: &str = "max.message.bytes";
+/// writes with acks = all are rejected if fewer replicas are in sync
+pub const MIN_INSYNC_REPLICAS: &str = "min.insync.replicas";
+/// allow replica which is not in sync to become leader, records may be lost
+pub const UNCLEAN_LEADER_ELECTION: &str = "unclean.leader.election.enable";
+/// how records which are no longer needed are removed, "delete" or "compact"
+pub const CLEANUP_POLICY: &str = "cleanup.policy";
+/// how long tombstones are kept by compaction, only with "compact" cleanup policy
+pub const TOMBSTONE_RETENTION_SECONDS: &str = "tombstone.retention.seconds";
+/// source of record timestamps, "create-time" or "log-append-time"
+pub const TIMESTAMP_TYPE: &str = "timestamp.type";
+
+/// keys stored in topic config
+pub const TOPIC_CONFIG_KEYS: &[&str] = &[
+    RETENTION_SECONDS,
+    SEGMENT_MAX_BYTES,
+    PARTITION_MAX_BYTES,
+    MAX_MESSAGE_BYTES,
+    MIN_INSYNC_REPLICAS,
+    UNCLEAN_LEADER_ELECTION,
+    CLEANUP_POLICY,
+    TOMBSTONE_RETENTION_SECONDS,
+    TIMESTAMP_TYPE,
+];
+
+#[derive(Debug, Clone, Default, PartialEq, Encoder, Decoder)]
+#[cfg_attr(
+    feature = "use_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct TopicConfig {
+    values: BTreeMap<String, String>,
+}
+
+impl TopicConfig {
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(|value| value.as_str())
+    }
+
+    /// set value of key, key must be one of `TOPIC_CONFIG_KEYS`
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            RETENTION_SECONDS | SEGMENT_MAX_BYTES | MAX_MESSAGE_BYTES => {
+                parse_positive::<u32>(key, value)?;
+            }
+            PARTITION_MAX_BYTES => {
+                parse_positive::<u64>(key, value)?;
+            }
+            MIN_INSYNC_REPLICAS => {
+                parse_positive::<u16>(key, value)?;
+            }
+            UNCLEAN_LEADER_ELECTION => {
+                value.parse::<bool>().map_err(|_| {
+                    format!(
+                        "invalid value for {}: {}, must be true or false",
+                        key, value
+                    )
+                })?;
+            }
+            CLEANUP_POLICY => {
+                if value != CleanupPolicy::Delete.label() && value != COMPACT {
+                    return Err(format!(
+                        "invalid value for {}: {}, must be delete or compact",
+                        key, value
+                    ));
+                }
+            }
+            TOMBSTONE_RETENTION_SECONDS => {
+                value.parse::<u32>().map_err(|_| {
+                    format!(
+                        "invalid value for {}: {}, must be number of seconds",
+                        key, value
+                    )
+                })?;
+            }
+            TIMESTAMP_TYPE => {
+                value.parse::<TimestampType>()?;
+            }
+            _ => return Err(format!("unknown topic config: {}", key)),
+        }
+        self.values.insert(key.to_owned(), value.to_owned());
+        Ok(())
+    }
+
+    /// check all keys and values are valid
+    pub fn validate(&self) -> Result<(), String> {
+        let mut config = Self::default();
+        for (key, value) in &self.values {
+            config.set(key, value)?;
+        }
+        if self.get(TOMBSTONE_RETENTION_SECONDS).is_some()
+            && self.get(CLEANUP_POLICY) != Some(COMPACT)
+        {
+            return Err(format!(
+                "{} is only valid with compact {}",
+                TOMBSTONE_RETENTION_SECONDS, CLEANUP_POLICY
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.values.iter()
+    }
+
+    pub fn retention_seconds(&self) -> Option<u32> {
+        self.parsed(RETENTION_SECONDS)
+    }
+
+    pub fn segment_max_bytes(&self) -> Option<u32> {
+        self.parsed(SEGMENT_MAX_BYTES)
+    }
+
+    pub fn partition_max_bytes(&self) -> Option<u64> {
+        self.parsed(PARTITION_MAX_BYTES)
+    }
+
+    pub fn max_message_bytes(&self) -> Option<u32> {
+        self.parsed(MAX_MESSAGE_BYTES)
+    }
+
+    pub fn min_in_sync_replicas(&self) -> Option<u16> {
+        self.parsed(MIN_INSYNC_REPLICAS)
+    }
+
+    pub fn unclean_leader_election(&self) -> bool {
+        self.parsed(UNCLEAN_LEADER_ELECTION).unwrap_or(false)
+    }
+
+    /// cleanup policy, if none, default policy of SPU is used
+    pub fn cleanup_policy(&self) -> Option<CleanupPolicy> {
+        match self.get(CLEANUP_POLICY)? {
+            COMPACT => Some(CleanupPolicy::Compact(CompactionPolicy {
+                tombstone_retention_seconds: self
+                    .parsed(TOMBSTONE_RETENTION_SECONDS)
+                    .unwrap_or(SPU_LOG_TOMBSTONE_RETENTION_SECONDS),
+            })),
+            _ => Some(CleanupPolicy::Delete),
+        }
+    }
+
+    /// how timestamps of records are assigned
+    pub fn timestamp_type(&self) -> TimestampType {
+        self.parsed(TIMESTAMP_TYPE).unwrap_or_default()
+    }
+
+    /// built-in default of SPU for key which is not set in config.
+    /// SPU may be started with different setting, so this is not necessarily value used by topic.
+    /// none if there is no limit by default
+    pub fn default_value(key: &str) -> Option<String> {
+        match key {
+            SEGMENT_MAX_BYTES => Some(SPU_LOG_SEGMENT_MAX_BYTES.to_string()),
+            MAX_MESSAGE_BYTES => Some(SPU_LOG_MAX_BATCH_SIZE.to_string()),
+            MIN_INSYNC_REPLICAS => Some(SPU_MIN_IN_SYNC_REPLICAS.to_string()),
+            UNCLEAN_LEADER_ELECTION => Some(false.to_string()),
+            CLEANUP_POLICY => Some(CleanupPolicy::default().label().to_owned()),
+            TOMBSTONE_RETENTION_SECONDS => Some(SPU_LOG_TOMBSTONE_RETENTION_SECONDS.to_string()),
+            TIMESTAMP_TYPE => Some(TimestampType::default().to_string()),
+            _ => None,
+        }
+    }
+
+    fn parsed<T: std::str::FromStr>(&self, key: &str) -> Option<T> {
+        self.get(key).and_then(|value| value.parse().ok())
+    }
+}
+
+/// How records which are no longer needed are removed from partition
+#[derive(Debug, Clone, PartialEq)]
+pub enum CleanupPolicy {
+    /// remove segments which are older than retention period or exceed partition size
+    Delete,
+    /// keep only latest record for each key
+    Compact(CompactionPolicy),
+}
+
+const COMPACT: &str = "compact";
+
+impl Default for CleanupPolicy {
+    fn default() -> Self {
+        Self::Delete
+    }
+}
+
+impl CleanupPolicy {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Delete => "delete",
+            Self::Compact(_) => COMPACT,
+        }
+    }
+}
+
+impl std::fmt::Display for CleanupPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Delete => write!(f, "delete"),
+            Self::Compact(policy) => write!(
+                f,
+                "compact(tombstone retention: {}s)",
+                policy.tombstone_retention_seconds
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompactionPolicy {
+    /// how long tombstones (records with key but empty value) are kept after compaction
+    pub tombstone_retention_seconds: u32,
+}
+
+impl Default for CompactionPolicy {
+    fn default() -> Self {
+        Self {
+            tombstone_retention_seconds: SPU_LOG_TOMBSTONE_RETENTION_SECONDS,
+        }
+    }
+}
+
+/// Source of record timestamps
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimestampType {
+    /// timestamp assigned by producer when record is created
+    CreateTime,
+    /// timestamp assigned by SPU when record is appended to log
+    LogAppendTime,
+}
+
+impl Default for TimestampType {
+    fn default() -> Self {
+        Self::CreateTime
+    }
+}
+
+impl TimestampType {
+    pub fn is_create_time(&self) -> bool {
+        matches!(self, Self::CreateTime)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::CreateTime => "create-time",
+            Self::LogAppendTime => "log-append-time",
+        }
+    }
+}
+
+impl std::fmt::Display for TimestampType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+impl std::str::FromStr for TimestampType {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "create-time" => Ok(Self::CreateTime),
+            "log-append-time" => Ok(Self::LogAppendTime),
+            _ => Err(format!("unknown timestamp type: {}", value)),
+        }
+    }
+}
+
+fn parse_positive<T>(key: &str, value: &str) -> Result<T, String>
+where
+    T: std::str::FromStr + PartialOrd + Default,
+{
+    match value.parse::<T>() {
+        Ok(parsed) if parsed > T::default() => Ok(parsed),
+        _ => Err(format!(
+            "invalid value for {}: {}, must be positive integer",
+            key, value
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_topic_config() {
+        let mut config = TopicConfig::default();
+        assert!(config.set(RETENTION_SECONDS, "3600").is_ok());
+        assert!(config.set(PARTITION_MAX_BYTES, "10737418240").is_ok());
+        assert!(config.set(SEGMENT_MAX_BYTES, "0").is_err());
+        assert!(config.set(MAX_MESSAGE_BYTES, "abc").is_err());
+        assert!(config.set("unknown", "1").is_err());
+
+        assert_eq!(config.retention_seconds(), Some(3600));
+        assert_eq!(config.partition_max_bytes(), Some(10737418240));
+        assert_eq!(config.segment_max_bytes(), None);
+        assert_eq!(
+            TopicConfig::default_value(SEGMENT_MAX_BYTES),
+            Some(SPU_LOG_SEGMENT_MAX_BYTES.to_string())
+        );
+        assert_eq!(TopicConfig::default_value(RETENTION_SECONDS), None);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_cleanup_policy_config() {
+        let mut config = TopicConfig::default();
+        assert_eq!(config.cleanup_policy(), None);
+        assert_eq!(config.timestamp_type(), TimestampType::CreateTime);
+
+        assert!(config.set(CLEANUP_POLICY, "remove").is_err());
+        assert!(config.set(TIMESTAMP_TYPE, "now").is_err());
+        assert!(config.set(CLEANUP_POLICY, "delete").is_ok());
+        assert!(config.set(TIMESTAMP_TYPE, "log-append-time").is_ok());
+        assert_eq!(config.cleanup_policy(), Some(CleanupPolicy::Delete));
+        assert_eq!(config.timestamp_type(), TimestampType::LogAppendTime);
+
+        // tombstone retention requires compaction
+        assert!(config.set(TOMBSTONE_RETENTION_SECONDS, "3600").is_ok());
+        assert!(config.validate().is_err());
+        assert!(config.set(CLEANUP_POLICY, "compact").is_ok());
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            config.cleanup_policy(),
+            Some(CleanupPolicy::Compact(CompactionPolicy {
+                tombstone_retention_seconds: 3600
+            }))
+        );
+    }
+
+    #[test]
+    fn test_replication_config() {
+        let mut config = TopicConfig::default();
+        assert_eq!(config.min_in_sync_replicas(), None);
+        assert!(!config.unclean_leader_election());
+
+        assert!(config.set(MIN_INSYNC_REPLICAS, "0").is_err());
+        assert!(config.set(UNCLEAN_LEADER_ELECTION, "yes").is_err());
+        assert!(config.set(MIN_INSYNC_REPLICAS, "2").is_ok());
+        assert!(config.set(UNCLEAN_LEADER_ELECTION, "true").is_ok());
+
+        assert_eq!(config.min_in_sync_replicas(), Some(2));
+        assert!(config.unclean_leader_election());
+    }
+}
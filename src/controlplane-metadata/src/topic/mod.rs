@@ -1,9 +1,11 @@
 mod spec;
 mod status;
 pub mod store;
+pub mod config;
 
 pub use self::spec::*;
 pub use self::status::*;
+pub use self::config::{TopicConfig, CleanupPolicy, CompactionPolicy, TimestampType};
 
 pub const PENDING_REASON: &str = "waiting for live spus";
 
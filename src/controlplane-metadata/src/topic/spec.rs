@@ -7,6 +7,8 @@
 //!  * Assigned
 //!  * Computed
 //!
+//! Storage config of topic is kept with its replica parameters of either type.
+//!
 use std::io::{Error, ErrorKind};
 use std::collections::BTreeMap;
 
@@ -18,6 +20,8 @@ use dataplane::core::Version;
 use dataplane::bytes::{Buf, BufMut};
 use dataplane::core::{Encoder, Decoder};
 
+use super::config::TopicConfig;
+
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(
     feature = "use_serde",
@@ -132,6 +136,41 @@ impl TopicSpec {
         }
     }
 
+    /// storage config of topic, settings not in config use SPU default
+    pub fn config(&self) -> &TopicConfig {
+        match self {
+            Self::Computed(param) => &param.config,
+            Self::Assigned(partition_map) => &partition_map.config,
+        }
+    }
+
+    /// set config value, key must be one of `TOPIC_CONFIG_KEYS`
+    pub fn set_config(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match self {
+            Self::Computed(param) => param.config.set(key, value),
+            Self::Assigned(partition_map) => partition_map.config.set(key, value),
+        }
+    }
+
+    /// increase partition count of computed topic, existing partitions are not changed
+    pub fn set_partitions(&mut self, partitions: PartitionCount) -> Result<(), String> {
+        match self {
+            Self::Computed(param) => {
+                if partitions <= param.partitions {
+                    return Err(format!(
+                        "partition count must be greater than current count: {}",
+                        param.partitions
+                    ));
+                }
+                param.partitions = partitions;
+                Ok(())
+            }
+            Self::Assigned(_) => {
+                Err("partitions can't be added to topic with assigned replicas".to_owned())
+            }
+        }
+    }
+
     // -----------------------------------
     //  Parameter validation
     // -----------------------------------
@@ -197,7 +236,7 @@ impl Decoder for TopicSpec {
             1 => {
                 let mut param = TopicReplicaParam::default();
                 param.decode(src, version)?;
-                *self = TopicSpec::Computed(param);
+                *self = Self::Computed(param);
                 Ok(())
             }
 
@@ -273,6 +312,13 @@ pub struct TopicReplicaParam {
     pub replication_factor: ReplicationFactor,
     #[cfg_attr(feature = "use_serde", serde(skip_serializing_if = "bool::clone"))]
     pub ignore_rack_assignment: IgnoreRackAssignment,
+    /// storage config of topic
+    #[fluvio(min_version = 1)]
+    #[cfg_attr(
+        feature = "use_serde",
+        serde(default, skip_serializing_if = "TopicConfig::is_empty")
+    )]
+    pub config: TopicConfig,
 }
 
 #[allow(dead_code)]
@@ -290,6 +336,7 @@ impl TopicReplicaParam {
             partitions,
             replication_factor,
             ignore_rack_assignment,
+            config: TopicConfig::default(),
         }
     }
 }
@@ -316,11 +363,21 @@ impl std::fmt::Display for TopicReplicaParam {
 #[cfg_attr(feature = "use_serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PartitionMaps {
     maps: Vec<PartitionMap>,
+    /// storage config of topic
+    #[fluvio(min_version = 1)]
+    #[cfg_attr(
+        feature = "use_serde",
+        serde(default, skip_serializing_if = "TopicConfig::is_empty")
+    )]
+    config: TopicConfig,
 }
 
 impl From<Vec<PartitionMap>> for PartitionMaps {
     fn from(maps: Vec<PartitionMap>) -> Self {
-        Self { maps }
+        Self {
+            maps,
+            config: TopicConfig::default(),
+        }
     }
 }
 
@@ -533,6 +590,8 @@ pub mod test {
     use super::*;
     use std::io::Cursor;
 
+    use crate::topic::config::CLEANUP_POLICY;
+
     #[test]
     fn test_is_computed_topic() {
         let p1: PartitionMaps = vec![(1, vec![0]), (2, vec![2])].into();
@@ -776,6 +835,39 @@ pub mod test {
         }
     }
 
+    #[test]
+    fn test_encode_decode_config() {
+        let mut topic_spec = TopicSpec::new_computed(2, 3, None);
+        topic_spec
+            .set_config(CLEANUP_POLICY, "compact")
+            .expect("config");
+
+        // config is not available in version 0
+        let mut dest = vec![];
+        topic_spec.encode(&mut dest, 0).expect("encode");
+        let mut topic_spec_decoded = TopicSpec::default();
+        topic_spec_decoded
+            .decode(&mut Cursor::new(&dest), 0)
+            .expect("decode");
+        assert!(topic_spec_decoded.config().is_empty());
+        assert_eq!(topic_spec_decoded.partitions(), 2);
+
+        let mut dest = vec![];
+        topic_spec.encode(&mut dest, 1).expect("encode");
+        let mut topic_spec_decoded = TopicSpec::default();
+        topic_spec_decoded
+            .decode(&mut Cursor::new(&dest), 1)
+            .expect("decode");
+        assert_eq!(topic_spec_decoded, topic_spec);
+        assert_eq!(
+            topic_spec_decoded
+                .config()
+                .cleanup_policy()
+                .map(|policy| policy.label()),
+            Some("compact")
+        );
+    }
+
     #[test]
     fn test_partition_map_str() {
         // Test multiple
@@ -9,6 +9,7 @@ pub use self::spu_api::InternalSpuRequest;
 
 pub use self::requests::update_spu::*;
 pub use self::requests::update_replica::*;
+pub use self::requests::update_smartmodule::*;
 pub use self::requests::register_spu::*;
 pub use self::requests::update_lrs::*;
 pub use self::requests::remove::*;
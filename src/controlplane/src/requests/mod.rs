@@ -1,5 +1,6 @@
 pub mod update_spu;
 pub mod update_replica;
+pub mod update_smartmodule;
 pub mod register_spu;
 pub mod update_lrs;
 pub mod remove;
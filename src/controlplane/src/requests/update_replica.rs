@@ -17,6 +17,8 @@ pub struct UpdateReplicaRequest {
 
 impl Request for UpdateReplicaRequest {
     const API_KEY: u16 = InternalSpuApi::UpdateReplica as u16;
+    // version 1 adds topic storage settings to replica
+    const DEFAULT_API_VERSION: i16 = 1;
     type Response = UpdateReplicaResponse;
 }
 
@@ -0,0 +1,100 @@
+//!
+//! # Authenticate
+//!
+//! Handshake used by SC and SPU to authenticate clients without certificates.
+//! When credentials are required, it must be the first request on the connection.
+//!
+
+use fluvio_protocol::{Encoder, Decoder};
+
+use crate::ErrorCode;
+use crate::api::Request;
+
+pub const AUTHENTICATE_API_KEY: u16 = 36;
+
+// -----------------------------------
+// AuthenticateRequest
+// -----------------------------------
+
+#[derive(Decoder, Encoder, Default)]
+pub struct AuthenticateRequest {
+    pub mechanism: AuthMechanism,
+    /// empty for token mechanism
+    pub username: String,
+    /// password or bearer token
+    pub secret: String,
+}
+
+impl AuthenticateRequest {
+    pub fn password(username: String, password: String) -> Self {
+        Self {
+            mechanism: AuthMechanism::Plain,
+            username,
+            secret: password,
+        }
+    }
+
+    pub fn token(token: String) -> Self {
+        Self {
+            mechanism: AuthMechanism::Token,
+            username: String::new(),
+            secret: token,
+        }
+    }
+}
+
+// secret is not printed, requests may be logged
+impl std::fmt::Debug for AuthenticateRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthenticateRequest")
+            .field("mechanism", &self.mechanism)
+            .field("username", &self.username)
+            .finish()
+    }
+}
+
+impl Request for AuthenticateRequest {
+    const API_KEY: u16 = AUTHENTICATE_API_KEY;
+    type Response = AuthenticateResponse;
+}
+
+#[derive(Debug, Encoder, Decoder, Clone, Copy, PartialEq)]
+#[fluvio(encode_discriminant)]
+#[repr(u8)]
+pub enum AuthMechanism {
+    /// username and password
+    Plain = 0,
+    /// bearer token
+    Token = 1,
+}
+
+impl Default for AuthMechanism {
+    fn default() -> Self {
+        AuthMechanism::Plain
+    }
+}
+
+// -----------------------------------
+// AuthenticateResponse
+// -----------------------------------
+
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct AuthenticateResponse {
+    pub error_code: ErrorCode,
+    /// authenticated principal
+    pub principal: String,
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::AuthenticateRequest;
+
+    #[test]
+    fn test_debug_hides_secret() {
+        let request = AuthenticateRequest::password("alice".to_owned(), "s3cret".to_owned());
+        let debug = format!("{:?}", request);
+        assert!(debug.contains("alice"));
+        assert!(!debug.contains("s3cret"));
+    }
+}
@@ -1,7 +1,10 @@
-use std::io::Error;
+use std::borrow::Cow;
+use std::io::{Cursor, Error, ErrorKind};
 use std::mem::size_of;
 use std::fmt::Debug;
+use std::convert::TryFrom;
 
+use once_cell::sync::OnceCell;
 use tracing::trace;
 
 use crate::core::bytes::Buf;
@@ -13,7 +16,9 @@ use crate::core::Version;
 
 use crate::Offset;
 use crate::Size;
+use crate::Timestamp;
 use crate::record::Record;
+use crate::compression::{Compression, CompressionError, COMPRESSION_CODEC_MASK};
 
 pub trait BatchRecords: Default + Debug + Encoder + Decoder {
     /// how many bytes does record wants to process
@@ -33,12 +38,18 @@ pub const BATCH_PREAMBLE_SIZE: usize = size_of::<Offset>()     // Offset
 
 pub const BATCH_FILE_HEADER_SIZE: usize = BATCH_PREAMBLE_SIZE + BATCH_HEADER_SIZE;
 
+/// mask of batch attributes which is set when timestamps are assigned by SPU instead of producer
+pub const LOG_APPEND_TIME_MASK: i16 = 0x08;
+
 #[derive(Default, Debug)]
 pub struct Batch<R = MemoryRecords> {
     pub base_offset: Offset,
     pub batch_len: i32, // only for decoding
     pub header: BatchHeader,
     records: R,
+    /// records as compressed in the batch with codec used, so records are compressed only once
+    /// for both size computation and encoding. this is cleared when records are modified
+    compressed_records: OnceCell<(Compression, Vec<u8>)>,
 }
 
 impl<R> Batch<R>
@@ -65,13 +76,75 @@ where
 
     #[inline(always)]
     pub fn mut_records(&mut self) -> &mut R {
+        self.compressed_records = OnceCell::new();
         &mut self.records
     }
 
+    /// compression codec of records
+    pub fn compression(&self) -> Result<Compression, CompressionError> {
+        self.header.compression()
+    }
+
+    /// compress records using codec, codec is recorded in the attributes
+    pub fn compress(&mut self, compression: Compression) -> Result<(), CompressionError> {
+        self.header.set_compression(compression);
+        self.compressed_records = OnceCell::new();
+        if compression != Compression::None {
+            let compressed = compression.compress(&self.records.as_bytes(0)?)?;
+            let _ = self.compressed_records.set((compression, compressed));
+        }
+        Ok(())
+    }
+
+    /// records compressed with codec of this batch, none if batch is not compressed.
+    /// records are compressed on first call and result is reused
+    fn compressed_records(&self, version: Version) -> Result<Option<Cow<'_, [u8]>>, Error> {
+        let compression = self.compression()?;
+        if compression == Compression::None {
+            return Ok(None);
+        }
+
+        let compress = || -> Result<Vec<u8>, Error> {
+            let records = self.records.as_bytes(version)?;
+            Ok(compression.compress(&records)?)
+        };
+        let (cached_compression, compressed) = self
+            .compressed_records
+            .get_or_try_init(|| compress().map(|compressed| (compression, compressed)))?;
+        if *cached_compression == compression {
+            Ok(Some(Cow::Borrowed(compressed)))
+        } else {
+            // codec has been changed through header after records were compressed
+            Ok(Some(Cow::Owned(compress()?)))
+        }
+    }
+
+    /// size of records as they are written in the batch.
+    /// if records can't be compressed, encoding fails with the error, so uncompressed size is returned
+    fn records_write_size(&self, version: Version) -> usize {
+        match self.compressed_records(version) {
+            Ok(Some(compressed)) => compressed.len(),
+            _ => self.records.write_size(version),
+        }
+    }
+
     pub fn get_base_offset(&self) -> Offset {
         self.base_offset
     }
 
+    /// set time when records of this batch were created by producer
+    pub fn set_create_time(&mut self, timestamp: Timestamp) {
+        self.header.attributes &= !LOG_APPEND_TIME_MASK;
+        self.header.first_timestamp = timestamp;
+        self.header.max_time_stamp = timestamp;
+    }
+
+    /// set time when this batch was appended to log, this overrides timestamps of all records
+    pub fn set_log_append_time(&mut self, timestamp: Timestamp) {
+        self.header.attributes |= LOG_APPEND_TIME_MASK;
+        self.header.max_time_stamp = timestamp;
+    }
+
     pub fn set_base_offset(&mut self, offset: Offset) {
         self.base_offset = offset;
     }
@@ -110,6 +183,42 @@ where
         self.header.decode(src, version)?;
         Ok(())
     }
+
+    /// decode records following header, records are uncompressed if needed
+    pub fn decode_records<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        match self.compression()? {
+            Compression::None => self.records.decode(src, version),
+            compression => {
+                let len = self.batch_len as usize - BATCH_HEADER_SIZE;
+                if src.remaining() < len {
+                    return Err(Error::new(
+                        ErrorKind::UnexpectedEof,
+                        format!(
+                            "not enough bytes for compressed records, {} out of {}",
+                            src.remaining(),
+                            len
+                        ),
+                    ));
+                }
+                let mut compressed = vec![0u8; len];
+                src.copy_to_slice(&mut compressed);
+                let records = compression.uncompress(&compressed)?;
+                trace!(
+                    %compression,
+                    compressed_len = len,
+                    len = records.len(),
+                    "uncompressed records"
+                );
+                self.records.decode(&mut Cursor::new(records), version)?;
+                self.compressed_records = OnceCell::new();
+                let _ = self.compressed_records.set((compression, compressed));
+                Ok(())
+            }
+        }
+    }
 }
 
 impl Batch {
@@ -127,13 +236,26 @@ impl Batch {
         };
         record.preamble.set_offset_delta(last_offset_delta);
         self.header.last_offset_delta = last_offset_delta as i32;
-        self.records.push(record)
+        self.mut_records().push(record)
     }
 
     /// computed last offset which is base offset + number of records
     pub fn computed_last_offset(&self) -> Offset {
         self.get_base_offset() + self.records.len() as Offset
     }
+
+    /// set time when first record was created by producer,
+    /// other records were created their timestamp delta later
+    pub fn set_first_create_time(&mut self, timestamp: Timestamp) {
+        let max_delta = self
+            .records
+            .iter()
+            .map(|record| record.preamble.timestamp_delta())
+            .max()
+            .unwrap_or_default();
+        self.set_create_time(timestamp);
+        self.header.max_time_stamp = timestamp + max_delta;
+    }
 }
 
 impl<T: Into<MemoryRecords>> From<T> for Batch {
@@ -167,7 +289,7 @@ where
     {
         trace!("decoding batch");
         self.decode_from_file_buf(src, version)?;
-        self.records.decode(src, version)?;
+        self.decode_records(src, version)?;
         Ok(())
     }
 }
@@ -178,7 +300,7 @@ where
     R: BatchRecords,
 {
     fn write_size(&self, version: Version) -> usize {
-        BATCH_PREAMBLE_SIZE + BATCH_HEADER_SIZE + self.records.write_size(version)
+        BATCH_PREAMBLE_SIZE + BATCH_HEADER_SIZE + self.records_write_size(version)
     }
 
     fn encode<T>(&self, dest: &mut T, version: Version) -> Result<(), Error>
@@ -186,8 +308,13 @@ where
         T: BufMut,
     {
         trace!("Encoding Batch");
+        let compressed_records = self.compressed_records(version)?;
+        let records_len = match &compressed_records {
+            Some(compressed) => compressed.len(),
+            None => self.records.write_size(version),
+        };
         self.base_offset.encode(dest, version)?;
-        let batch_len: i32 = (BATCH_HEADER_SIZE + self.records.write_size(version)) as i32;
+        let batch_len: i32 = (BATCH_HEADER_SIZE + records_len) as i32;
         batch_len.encode(dest, version)?;
 
         // encode parts of header
@@ -203,7 +330,10 @@ where
         self.header.producer_id.encode(buf, version)?;
         self.header.producer_epoch.encode(buf, version)?;
         self.header.first_sequence.encode(buf, version)?;
-        self.records.encode(buf, version)?;
+        match compressed_records {
+            Some(compressed) => buf.extend_from_slice(&compressed),
+            None => self.records.encode(buf, version)?,
+        }
 
         let crc = crc32c::crc32c(&out);
         crc.encode(dest, version)?;
@@ -212,7 +342,7 @@ where
     }
 }
 
-#[derive(Debug, Decoder, Encoder)]
+#[derive(Debug, Clone, Decoder, Encoder)]
 pub struct BatchHeader {
     pub partition_leader_epoch: i32,
     pub magic: i8,
@@ -229,6 +359,34 @@ pub struct BatchHeader {
     pub first_sequence: i32,
 }
 
+impl BatchHeader {
+    /// compression codec stored in the attributes
+    pub fn compression(&self) -> Result<Compression, CompressionError> {
+        Compression::try_from((self.attributes & COMPRESSION_CODEC_MASK) as i8)
+    }
+
+    pub fn set_compression(&mut self, compression: Compression) {
+        self.attributes = (self.attributes & !COMPRESSION_CODEC_MASK)
+            | (compression as i16 & COMPRESSION_CODEC_MASK);
+    }
+
+    /// true if timestamps were assigned by SPU when batch was appended
+    pub fn is_log_append_time(&self) -> bool {
+        self.attributes & LOG_APPEND_TIME_MASK != 0
+    }
+
+    /// timestamp of record with given delta, -1 if batch doesn't have timestamp
+    pub fn record_timestamp(&self, timestamp_delta: i64) -> Timestamp {
+        if self.is_log_append_time() {
+            self.max_time_stamp
+        } else if self.first_timestamp > 0 {
+            self.first_timestamp + timestamp_delta
+        } else {
+            -1
+        }
+    }
+}
+
 impl Default for BatchHeader {
     fn default() -> Self {
         BatchHeader {
@@ -303,6 +461,94 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_record_timestamp() -> Result<(), IoError> {
+        let mut batch = Batch::from(vec![Record::new("a"), Record::new("b")]);
+        assert_eq!(batch.get_header().record_timestamp(0), -1);
+
+        batch.mut_records()[1].preamble.set_timestamp_delta(5);
+        batch.set_create_time(1000);
+        let decoded = Batch::<MemoryRecords>::decode_from(&mut Cursor::new(batch.as_bytes(0)?), 0)?;
+        assert!(!decoded.get_header().is_log_append_time());
+        let timestamps: Vec<_> = decoded
+            .records()
+            .iter()
+            .map(|record| {
+                decoded
+                    .get_header()
+                    .record_timestamp(record.preamble.timestamp_delta())
+            })
+            .collect();
+        assert_eq!(timestamps, vec![1000, 1005]);
+
+        batch.set_log_append_time(2000);
+        let decoded = Batch::<MemoryRecords>::decode_from(&mut Cursor::new(batch.as_bytes(0)?), 0)?;
+        assert!(decoded.get_header().is_log_append_time());
+        assert_eq!(decoded.get_header().record_timestamp(5), 2000);
+
+        batch.set_first_create_time(3000);
+        assert!(!batch.get_header().is_log_append_time());
+        assert_eq!(batch.get_header().first_timestamp, 3000);
+        assert_eq!(batch.get_header().max_time_stamp, 3005);
+        Ok(())
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn test_encode_and_decode_compressed_batch() -> Result<(), IoError> {
+        use crate::compression::Compression;
+
+        let codecs = [
+            Compression::Gzip,
+            Compression::Snappy,
+            Compression::Lz4,
+            Compression::Zstd,
+        ];
+        for compression in codecs.iter() {
+            let mut batch = Batch::from(vec![
+                Record::new("fluvio ".repeat(20)),
+                Record::new("compression"),
+            ]);
+            batch.compress(*compression)?;
+            assert_eq!(batch.compression()?, *compression);
+
+            let bytes = batch.as_bytes(0)?;
+            assert_eq!(bytes.len(), batch.write_size(0));
+            assert!(
+                bytes.len() < Batch::from(vec![Record::new("fluvio ".repeat(20))]).write_size(0)
+            );
+
+            let decoded = Batch::<MemoryRecords>::decode_from(&mut Cursor::new(bytes.clone()), 0)?;
+            assert_eq!(decoded.compression()?, *compression);
+            assert_eq!(decoded.records().len(), 2);
+            assert_eq!(decoded.records()[1].get_offset_delta(), 1);
+            assert_eq!(decoded.records()[1].value.as_ref(), b"compression");
+
+            // decoded batch is encoded without compressing again
+            assert_eq!(decoded.as_bytes(0)?, bytes);
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn test_encode_batch_with_codec_in_header() -> Result<(), IoError> {
+        use crate::compression::Compression;
+
+        let mut batch = Batch::from(vec![Record::new("fluvio ".repeat(20))]);
+        batch.get_mut_header().set_compression(Compression::Gzip);
+
+        // size and encoding use same compressed records
+        let size = batch.write_size(0);
+        assert_eq!(batch.as_bytes(0)?.len(), size);
+
+        // error is returned from encoding, not hidden by size
+        batch.get_mut_header().attributes = 0x07;
+        assert!(batch.as_bytes(0).is_err());
+        Ok(())
+    }
+
     /*  raw batch encoded
 
     0000   02 00 00 00 45 00 00 c7 00 00 40 00 40 06 00 00
@@ -1,10 +1,21 @@
 use std::convert::TryFrom;
 use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::derive::{Encoder, Decoder};
 
 pub type Offset = i64;
 pub type Size = u32;
+/// milliseconds since unix epoch
+pub type Timestamp = i64;
+
+/// current time in milliseconds since unix epoch
+pub fn now_timestamp() -> Timestamp {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as Timestamp
+}
 
 #[derive(Debug, Encoder, Decoder, Clone)]
 #[fluvio(encode_discriminant)]
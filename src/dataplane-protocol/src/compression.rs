@@ -0,0 +1,202 @@
+//!
+//! # Batch Compression
+//!
+//! Codecs used to compress records section of a batch.
+//! Codec is stored in lowest 3 bits of batch attributes, same as Kafka.
+//!
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::{Error as IoError, ErrorKind};
+use std::str::FromStr;
+
+/// mask of batch attributes which contains compression codec
+pub const COMPRESSION_CODEC_MASK: i16 = 0x07;
+
+#[derive(thiserror::Error, Debug)]
+pub enum CompressionError {
+    #[error(transparent)]
+    Io(#[from] IoError),
+    #[error("unknown compression codec: {0}")]
+    UnknownCodec(String),
+    #[error("compression codec: {0} is not supported in this build")]
+    UnsupportedCodec(Compression),
+    #[cfg(feature = "compression")]
+    #[error("snappy error: {0}")]
+    Snappy(#[from] snap::Error),
+}
+
+impl From<CompressionError> for IoError {
+    fn from(err: CompressionError) -> Self {
+        match err {
+            CompressionError::Io(err) => err,
+            err => IoError::new(ErrorKind::InvalidData, err.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i8)]
+pub enum Compression {
+    None = 0,
+    Gzip = 1,
+    Snappy = 2,
+    Lz4 = 3,
+    Zstd = 4,
+}
+
+impl Default for Compression {
+    fn default() -> Self {
+        Compression::None
+    }
+}
+
+impl TryFrom<i8> for Compression {
+    type Error = CompressionError;
+
+    fn try_from(codec: i8) -> Result<Self, Self::Error> {
+        match codec {
+            0 => Ok(Compression::None),
+            1 => Ok(Compression::Gzip),
+            2 => Ok(Compression::Snappy),
+            3 => Ok(Compression::Lz4),
+            4 => Ok(Compression::Zstd),
+            _ => Err(CompressionError::UnknownCodec(codec.to_string())),
+        }
+    }
+}
+
+impl FromStr for Compression {
+    type Err = CompressionError;
+
+    fn from_str(codec: &str) -> Result<Self, Self::Err> {
+        match codec.to_lowercase().as_str() {
+            "none" => Ok(Compression::None),
+            "gzip" => Ok(Compression::Gzip),
+            "snappy" => Ok(Compression::Snappy),
+            "lz4" => Ok(Compression::Lz4),
+            "zstd" => Ok(Compression::Zstd),
+            _ => Err(CompressionError::UnknownCodec(codec.to_owned())),
+        }
+    }
+}
+
+impl fmt::Display for Compression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let label = match self {
+            Compression::None => "none",
+            Compression::Gzip => "gzip",
+            Compression::Snappy => "snappy",
+            Compression::Lz4 => "lz4",
+            Compression::Zstd => "zstd",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+#[cfg(feature = "compression")]
+impl Compression {
+    /// compress bytes using this codec
+    pub fn compress(&self, src: &[u8]) -> Result<Vec<u8>, CompressionError> {
+        use std::io::Write;
+
+        match self {
+            Compression::None => Ok(src.to_vec()),
+            Compression::Gzip => {
+                let mut encoder =
+                    flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(src)?;
+                Ok(encoder.finish()?)
+            }
+            Compression::Snappy => Ok(snap::raw::Encoder::new().compress_vec(src)?),
+            Compression::Lz4 => {
+                let mut encoder = lz4_flex::frame::FrameEncoder::new(Vec::new());
+                encoder.write_all(src)?;
+                encoder
+                    .finish()
+                    .map_err(|err| IoError::new(ErrorKind::Other, err.to_string()).into())
+            }
+            Compression::Zstd => Ok(zstd::encode_all(src, zstd::DEFAULT_COMPRESSION_LEVEL)?),
+        }
+    }
+
+    /// uncompress bytes which has been compressed using this codec
+    pub fn uncompress(&self, src: &[u8]) -> Result<Vec<u8>, CompressionError> {
+        use std::io::Read;
+
+        match self {
+            Compression::None => Ok(src.to_vec()),
+            Compression::Gzip => {
+                let mut output = Vec::new();
+                flate2::read::GzDecoder::new(src).read_to_end(&mut output)?;
+                Ok(output)
+            }
+            Compression::Snappy => Ok(snap::raw::Decoder::new().decompress_vec(src)?),
+            Compression::Lz4 => {
+                let mut output = Vec::new();
+                lz4_flex::frame::FrameDecoder::new(src).read_to_end(&mut output)?;
+                Ok(output)
+            }
+            Compression::Zstd => Ok(zstd::decode_all(src)?),
+        }
+    }
+}
+
+#[cfg(not(feature = "compression"))]
+impl Compression {
+    /// compress bytes using this codec
+    pub fn compress(&self, src: &[u8]) -> Result<Vec<u8>, CompressionError> {
+        match self {
+            Compression::None => Ok(src.to_vec()),
+            codec => Err(CompressionError::UnsupportedCodec(*codec)),
+        }
+    }
+
+    /// uncompress bytes which has been compressed using this codec
+    pub fn uncompress(&self, src: &[u8]) -> Result<Vec<u8>, CompressionError> {
+        self.compress(src)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::convert::TryFrom;
+
+    use super::Compression;
+
+    const CODECS: [Compression; 5] = [
+        Compression::None,
+        Compression::Gzip,
+        Compression::Snappy,
+        Compression::Lz4,
+        Compression::Zstd,
+    ];
+
+    #[test]
+    fn test_codec_conversion() {
+        for codec in CODECS.iter() {
+            assert_eq!(Compression::try_from(*codec as i8).expect("codec"), *codec);
+            assert_eq!(
+                codec.to_string().parse::<Compression>().expect("parse"),
+                *codec
+            );
+        }
+        assert!(Compression::try_from(5).is_err());
+        assert!("brotli".parse::<Compression>().is_err());
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn test_compress_uncompress() {
+        let data = "fluvio ".repeat(100).into_bytes();
+        for codec in CODECS.iter() {
+            let compressed = codec.compress(&data).expect("compress");
+            if *codec != Compression::None {
+                assert!(compressed.len() < data.len(), "{} should compress", codec);
+            }
+            let uncompressed = codec.uncompress(&compressed).expect("uncompress");
+            assert_eq!(uncompressed, data);
+        }
+    }
+}
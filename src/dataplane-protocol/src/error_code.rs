@@ -26,12 +26,24 @@ pub enum ErrorCode {
     OffsetOutOfRange,
     #[fluvio(tag = 6)]
     NotLeaderForPartition,
+    #[fluvio(tag = 7)]
+    RequestTimedOut,
     #[fluvio(tag = 10)]
     MessageTooLarge,
     #[fluvio(tag = 13)]
     PermissionDenied,
+    #[fluvio(tag = 19)]
+    NotEnoughReplicas,
+    #[fluvio(tag = 21)]
+    InvalidRequiredAcks,
+    #[fluvio(tag = 22)]
+    IllegalGeneration,
+    #[fluvio(tag = 25)]
+    UnknownMemberId,
     #[fluvio(tag = 56)]
     StorageError,
+    #[fluvio(tag = 58)]
+    AuthenticationFailed,
 
     // Spu errors
     #[fluvio(tag = 1000)]
@@ -64,6 +76,8 @@ pub enum ErrorCode {
     PartitionPendingInitialization,
     #[fluvio(tag = 3001)]
     PartitionNotLeader,
+    #[fluvio(tag = 3003)]
+    PartitionError,
 
     // Stream Fetch error
     #[fluvio(tag = 3002)]
@@ -72,6 +86,14 @@ pub enum ErrorCode {
     // SmartStream errors
     #[fluvio(tag = 4000)]
     SmartStreamError(SmartStreamError),
+
+    // SmartModule errors
+    #[fluvio(tag = 5000)]
+    SmartModuleError,
+    #[fluvio(tag = 5001)]
+    SmartModuleNotFound,
+    #[fluvio(tag = 5002)]
+    SmartModuleAlreadyExists,
 }
 
 impl Default for ErrorCode {
@@ -153,9 +175,15 @@ mod test {
         assert_tag!(ErrorCode::None, 0, 0);
         assert_tag!(ErrorCode::OffsetOutOfRange, 1, 0);
         assert_tag!(ErrorCode::NotLeaderForPartition, 6, 0);
+        assert_tag!(ErrorCode::RequestTimedOut, 7, 0);
         assert_tag!(ErrorCode::MessageTooLarge, 10, 0);
         assert_tag!(ErrorCode::PermissionDenied, 13, 0);
+        assert_tag!(ErrorCode::NotEnoughReplicas, 19, 0);
+        assert_tag!(ErrorCode::InvalidRequiredAcks, 21, 0);
+        assert_tag!(ErrorCode::IllegalGeneration, 22, 0);
+        assert_tag!(ErrorCode::UnknownMemberId, 25, 0);
         assert_tag!(ErrorCode::StorageError, 56, 0);
+        assert_tag!(ErrorCode::AuthenticationFailed, 58, 0);
 
         // Spu errors
         assert_tag!(ErrorCode::SpuError, 1000, 0);
@@ -175,8 +203,14 @@ mod test {
         // Partition errors
         assert_tag!(ErrorCode::PartitionPendingInitialization, 3000, 0);
         assert_tag!(ErrorCode::PartitionNotLeader, 3001, 0);
+        assert_tag!(ErrorCode::PartitionError, 3003, 0);
 
         // Stream Fetch error
         assert_tag!(ErrorCode::FetchSessionNotFoud, 3002, 0);
+
+        // SmartModule errors
+        assert_tag!(ErrorCode::SmartModuleError, 5000, 0);
+        assert_tag!(ErrorCode::SmartModuleNotFound, 5001, 0);
+        assert_tag!(ErrorCode::SmartModuleAlreadyExists, 5002, 0);
     }
 }
@@ -5,10 +5,12 @@ mod common;
 mod error_code;
 
 pub mod batch;
+pub mod compression;
 pub mod record;
 pub mod fetch;
 pub mod produce;
 pub mod versions;
+pub mod authenticate;
 pub mod smartstream;
 
 #[cfg(feature = "fixture")]
@@ -1,5 +1,7 @@
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::convert::TryFrom;
+use std::str::FromStr;
 
 use crate::core::Encoder;
 use crate::core::Decoder;
@@ -36,6 +38,73 @@ where
     pub data: PhantomData<R>,
 }
 
+/// Acknowledgments which producer requires before request is considered complete
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Acks {
+    /// no acknowledgment, producer doesn't wait for the write
+    None,
+    /// leader has written records to its log
+    Leader,
+    /// all in-sync replicas have received records, so they are committed
+    All,
+}
+
+impl Default for Acks {
+    fn default() -> Self {
+        Acks::Leader
+    }
+}
+
+impl Acks {
+    /// value of acks field in produce request
+    pub fn value(&self) -> i16 {
+        match self {
+            Acks::None => 0,
+            Acks::Leader => 1,
+            Acks::All => -1,
+        }
+    }
+}
+
+impl TryFrom<i16> for Acks {
+    type Error = i16;
+
+    fn try_from(acks: i16) -> Result<Self, Self::Error> {
+        match acks {
+            0 => Ok(Acks::None),
+            1 => Ok(Acks::Leader),
+            -1 => Ok(Acks::All),
+            invalid => Err(invalid),
+        }
+    }
+}
+
+impl FromStr for Acks {
+    type Err = String;
+
+    fn from_str(acks: &str) -> Result<Self, Self::Err> {
+        match acks.to_lowercase().as_str() {
+            "0" | "none" => Ok(Acks::None),
+            "1" | "leader" => Ok(Acks::Leader),
+            "-1" | "all" => Ok(Acks::All),
+            _ => Err(format!(
+                "invalid acks: {}, valid values are 0, 1 or all",
+                acks
+            )),
+        }
+    }
+}
+
+impl<R> ProduceRequest<R>
+where
+    R: Encoder + Decoder + Default + Debug,
+{
+    /// acknowledgments requested by producer, error contains invalid value
+    pub fn required_acks(&self) -> Result<Acks, i16> {
+        Acks::try_from(self.acks)
+    }
+}
+
 impl<R> Request for ProduceRequest<R>
 where
     R: Debug + Decoder + Encoder,
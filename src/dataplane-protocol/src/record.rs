@@ -185,6 +185,96 @@ impl Decoder for RecordData {
     }
 }
 
+/// Key/value metadata attached to a record, such as tracing id or content type.
+///
+/// Key is a UTF-8 string, value is arbitrary bytes.
+#[derive(Clone, Default, PartialEq)]
+pub struct Header {
+    pub key: String,
+    pub value: RecordData,
+}
+
+impl Header {
+    pub fn new<K, V>(key: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<RecordData>,
+    {
+        Self {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn value(&self) -> &RecordData {
+        &self.value
+    }
+}
+
+impl<K, V> From<(K, V)> for Header
+where
+    K: Into<String>,
+    V: Into<RecordData>,
+{
+    fn from((key, value): (K, V)) -> Self {
+        Self::new(key, value)
+    }
+}
+
+impl Debug for Header {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {:?}", self.key, self.value)
+    }
+}
+
+impl Display for Header {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}={}", self.key, self.value)
+    }
+}
+
+impl Encoder for Header {
+    fn write_size(&self, version: Version) -> usize {
+        let key_len = self.key.len() as i64;
+        key_len.var_write_size() + self.key.len() + self.value.write_size(version)
+    }
+
+    fn encode<T>(&self, dest: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        let key_len = self.key.len() as i64;
+        key_len.encode_varint(dest)?;
+        dest.put_slice(self.key.as_bytes());
+        self.value.encode(dest, version)
+    }
+}
+
+impl Decoder for Header {
+    fn decode<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        let mut key_len: i64 = 0;
+        key_len.decode_varint(src)?;
+        if key_len < 0 || src.remaining() < key_len as usize {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "not enough bytes for header key",
+            ));
+        }
+        let mut key = vec![0; key_len as usize];
+        src.copy_to_slice(&mut key);
+        self.key = String::from_utf8(key)
+            .map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()))?;
+        self.value.decode(src, version)
+    }
+}
+
 /// Represents sets of batches in storage
 //  It is written consequently with len as prefix
 #[derive(Default, Debug)]
@@ -325,6 +415,15 @@ impl RecordHeader {
     pub fn offset_delta(&self) -> Offset {
         self.offset_delta
     }
+
+    pub fn set_timestamp_delta(&mut self, delta: i64) {
+        self.timestamp_delta = delta;
+    }
+
+    /// timestamp of record relative to first timestamp of batch
+    pub fn timestamp_delta(&self) -> i64 {
+        self.timestamp_delta
+    }
 }
 
 #[derive(Default, Clone)]
@@ -332,7 +431,7 @@ pub struct Record<B = RecordData> {
     pub preamble: RecordHeader,
     pub key: Option<B>,
     pub value: B,
-    pub headers: i64,
+    pub headers: Vec<Header>,
 }
 
 impl<B: Default> Record<B> {
@@ -355,6 +454,27 @@ impl<B: Default> Record<B> {
         self.key.as_ref()
     }
 
+    /// Returns the headers of this record
+    pub fn headers(&self) -> &[Header] {
+        &self.headers
+    }
+
+    /// Returns value of first header with given key
+    pub fn header(&self, key: &str) -> Option<&RecordData> {
+        self.headers
+            .iter()
+            .find(|header| header.key == key)
+            .map(|header| &header.value)
+    }
+
+    /// Appends header to this record
+    pub fn add_header<H>(&mut self, header: H)
+    where
+        H: Into<Header>,
+    {
+        self.headers.push(header.into());
+    }
+
     /// Consumes this record, returning the inner value
     pub fn into_value(self) -> B {
         self.value
@@ -420,7 +540,7 @@ where
         let inner_size = self.preamble.write_size(version)
             + self.key.write_size(version)
             + self.value.write_size(version)
-            + self.headers.var_write_size();
+            + headers_write_size(&self.headers, version);
         let len: i64 = inner_size as i64;
         len.var_write_size() + inner_size
     }
@@ -433,7 +553,11 @@ where
         self.preamble.encode(&mut out, version)?;
         self.key.encode(&mut out, version)?;
         self.value.encode(&mut out, version)?;
-        self.headers.encode_varint(&mut out)?;
+        let header_count = self.headers.len() as i64;
+        header_count.encode_varint(&mut out)?;
+        for header in &self.headers {
+            header.encode(&mut out, version)?;
+        }
         let len: i64 = out.len() as i64;
         trace!("record encode as {} bytes", len);
         len.encode_varint(dest)?;
@@ -466,12 +590,30 @@ where
         trace!("offset delta: {}", self.preamble.offset_delta);
         self.key.decode(src, version)?;
         self.value.decode(src, version)?;
-        self.headers.decode_varint(src)?;
+
+        // records written before headers were supported always have zero count
+        let mut header_count: i64 = 0;
+        header_count.decode_varint(src)?;
+        self.headers.clear();
+        for _ in 0..header_count.max(0) {
+            let mut header = Header::default();
+            header.decode(src, version)?;
+            self.headers.push(header);
+        }
 
         Ok(())
     }
 }
 
+fn headers_write_size(headers: &[Header], version: Version) -> usize {
+    let header_count = headers.len() as i64;
+    headers
+        .iter()
+        .fold(header_count.var_write_size(), |sum, header| {
+            sum + header.write_size(version)
+        })
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -564,6 +706,26 @@ mod test {
         assert_eq!(record.value.as_ref(), decoded.value.as_ref());
     }
 
+    #[test]
+    fn test_header_encoding() {
+        let mut record = Record::new_key_value("key", "value");
+        record.add_header(("trace-id", "abc123"));
+        record.add_header(Header::new("content-type", "application/json"));
+
+        let mut encoded = Vec::new();
+        record.encode(&mut encoded, 0).unwrap();
+        assert_eq!(encoded.len(), record.write_size(0));
+
+        let decoded = Record::<RecordData>::decode_from(&mut Cursor::new(encoded), 0).unwrap();
+        assert_eq!(decoded.headers(), record.headers());
+        assert_eq!(
+            decoded.header("trace-id").map(|value| value.as_ref()),
+            Some("abc123".as_bytes())
+        );
+        assert!(decoded.header("unknown").is_none());
+        assert_eq!(decoded.value.as_ref(), "value".as_bytes());
+    }
+
     // Test Specification:
     //
     // A record was encoded and written to a file, using the following code:
@@ -1,10 +1,11 @@
 pub use encoding::{
     SmartStreamRuntimeError, SmartStreamInternalError, SmartStreamType, SmartStreamInput,
-    SmartStreamAggregateInput, SmartStreamOutput,
+    SmartStreamAggregateInput, SmartStreamOutput, SmartStreamExtraParams,
 };
 
 mod encoding {
     use std::fmt;
+    use std::collections::BTreeMap;
     use crate::Offset;
     use crate::record::{Record, RecordData};
     use fluvio_protocol::{Encoder, Decoder};
@@ -16,6 +17,35 @@ mod encoding {
         pub base_offset: Offset,
         /// The records for the SmartStream to process
         pub record_data: Vec<u8>,
+        /// Parameters given by the consumer
+        pub params: SmartStreamExtraParams,
+    }
+
+    /// Key/value parameters given by the consumer to configure a SmartStream
+    #[derive(Debug, Default, Clone, PartialEq, Encoder, Decoder)]
+    pub struct SmartStreamExtraParams {
+        inner: BTreeMap<String, String>,
+    }
+
+    impl SmartStreamExtraParams {
+        /// Returns the value of the parameter, if it was given
+        pub fn get(&self, key: &str) -> Option<&String> {
+            self.inner.get(key)
+        }
+
+        pub fn insert(&mut self, key: String, value: String) -> Option<String> {
+            self.inner.insert(key, value)
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.inner.is_empty()
+        }
+    }
+
+    impl From<BTreeMap<String, String>> for SmartStreamExtraParams {
+        fn from(inner: BTreeMap<String, String>) -> Self {
+            Self { inner }
+        }
     }
 
     /// A type to pass input to an Aggregate SmartStream WASM module
@@ -79,6 +109,9 @@ mod encoding {
         pub record_key: Option<RecordData>,
         /// The Record value that caused this error
         pub record_value: RecordData,
+        /// Position of the SmartStream in the chain that had a runtime error
+        #[fluvio(min_version = 16)]
+        pub stage: u32,
     }
 
     impl SmartStreamRuntimeError {
@@ -98,6 +131,7 @@ mod encoding {
                 kind,
                 record_key,
                 record_value,
+                stage: 0,
             }
         }
     }
@@ -115,10 +149,11 @@ mod encoding {
                 "{}\n\n\
                 SmartStream Info: \n    \
                 Type: {}\n    \
+                Stage: {}\n    \
                 Offset: {}\n    \
                 Key: {}\n    \
                 Value: {}",
-                self.hint, self.kind, self.offset, key, value,
+                self.hint, self.kind, self.stage, self.offset, key, value,
             )
         }
     }
@@ -135,6 +170,8 @@ mod encoding {
         Filter,
         Map,
         Aggregate,
+        ArrayMap,
+        FilterMap,
     }
 
     impl Default for SmartStreamType {
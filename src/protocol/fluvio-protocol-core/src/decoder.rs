@@ -1,9 +1,11 @@
 use std::cmp::Ord;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
 use std::io::Error;
 use std::io::ErrorKind;
 use std::io::Read;
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use bytes::Buf;
 use bytes::BufMut;
@@ -133,6 +135,84 @@ where
     }
 }
 
+impl<K, V> Decoder for HashMap<K, V>
+where
+    K: Decoder + Eq + Hash,
+    V: Decoder,
+{
+    fn decode<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        let mut len: u16 = 0;
+        len.decode(src, version)?;
+
+        let mut map: HashMap<K, V> = HashMap::with_capacity(len as usize);
+        for _i in 0..len {
+            let mut key = K::default();
+            key.decode(src, version)?;
+            let mut value = V::default();
+            value.decode(src, version)?;
+            map.insert(key, value);
+        }
+
+        *self = map;
+        Ok(())
+    }
+}
+
+impl<M> Decoder for Box<M>
+where
+    M: Decoder,
+{
+    fn decode<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        (**self).decode(src, version)
+    }
+}
+
+impl<M, const N: usize> Decoder for [M; N]
+where
+    M: Decoder,
+    [M; N]: Default,
+{
+    fn decode<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        for value in self.iter_mut() {
+            value.decode(src, version)?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_decoder_tuple {
+    ($($name:ident $index:tt),+) => {
+        impl<$($name),+> Decoder for ($($name,)+)
+        where
+            $($name: Decoder,)+
+        {
+            fn decode<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error>
+            where
+                T: Buf,
+            {
+                $(self.$index.decode(src, version)?;)+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_decoder_tuple!(A 0);
+impl_decoder_tuple!(A 0, B 1);
+impl_decoder_tuple!(A 0, B 1, C 2);
+impl_decoder_tuple!(A 0, B 1, C 2, D 3);
+impl_decoder_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_decoder_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+
 impl Decoder for bool {
     fn decode<T>(&mut self, src: &mut T, _version: Version) -> Result<(), Error>
     where
@@ -265,6 +345,82 @@ impl Decoder for i64 {
     }
 }
 
+impl Decoder for u64 {
+    fn decode<T>(&mut self, src: &mut T, _version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        if src.remaining() < 8 {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "can't read u64"));
+        }
+        let value = src.get_u64();
+        trace!("u64: {:#x} => {}", &value, &value);
+        *self = value;
+        Ok(())
+    }
+}
+
+impl Decoder for f32 {
+    fn decode<T>(&mut self, src: &mut T, _version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        if src.remaining() < 4 {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "can't read f32"));
+        }
+        *self = src.get_f32();
+        Ok(())
+    }
+}
+
+impl Decoder for f64 {
+    fn decode<T>(&mut self, src: &mut T, _version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        if src.remaining() < 8 {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "can't read f64"));
+        }
+        *self = src.get_f64();
+        Ok(())
+    }
+}
+
+impl Decoder for Duration {
+    fn decode<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        let mut secs: u64 = 0;
+        secs.decode(src, version)?;
+        let mut nanos: u32 = 0;
+        nanos.decode(src, version)?;
+
+        if nanos >= 1_000_000_000 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid duration nanos: {}", nanos),
+            ));
+        }
+
+        *self = Duration::new(secs, nanos);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl Decoder for uuid::Uuid {
+    fn decode<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: Buf,
+    {
+        let mut bytes = [0_u8; 16];
+        bytes.decode(src, version)?;
+        *self = uuid::Uuid::from_bytes(bytes);
+        Ok(())
+    }
+}
+
 impl DecoderVarInt for i64 {
     fn decode_varint<T>(&mut self, src: &mut T) -> Result<(), Error>
     where
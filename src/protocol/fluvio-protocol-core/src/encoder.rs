@@ -1,9 +1,11 @@
 // decode values
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
 use std::io::Error;
 use std::io::ErrorKind;
 use std::io::Write;
 use std::marker::PhantomData;
+use std::time::Duration;
 
 use bytes::BufMut;
 use bytes::Bytes;
@@ -147,6 +149,103 @@ where
     }
 }
 
+impl<K, V> Encoder for HashMap<K, V>
+where
+    K: Encoder + Eq + Hash,
+    V: Encoder,
+{
+    fn write_size(&self, version: Version) -> usize {
+        let mut len: usize = (0_u16).write_size(version);
+
+        for (key, value) in self.iter() {
+            len += key.write_size(version);
+            len += value.write_size(version);
+        }
+
+        len
+    }
+
+    fn encode<T>(&self, dest: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        let len = self.len() as u16;
+        len.encode(dest, version)?;
+
+        for (key, value) in self.iter() {
+            key.encode(dest, version)?;
+            value.encode(dest, version)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<M> Encoder for Box<M>
+where
+    M: Encoder,
+{
+    fn write_size(&self, version: Version) -> usize {
+        (**self).write_size(version)
+    }
+
+    fn encode<T>(&self, dest: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        (**self).encode(dest, version)
+    }
+}
+
+/// fixed size array is encoded without length
+impl<M, const N: usize> Encoder for [M; N]
+where
+    M: Encoder,
+{
+    fn write_size(&self, version: Version) -> usize {
+        self.iter()
+            .fold(0, |sum, val| sum + val.write_size(version))
+    }
+
+    fn encode<T>(&self, dest: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        for value in self.iter() {
+            value.encode(dest, version)?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_encoder_tuple {
+    ($($name:ident $index:tt),+) => {
+        impl<$($name),+> Encoder for ($($name,)+)
+        where
+            $($name: Encoder,)+
+        {
+            fn write_size(&self, version: Version) -> usize {
+                0 $(+ self.$index.write_size(version))+
+            }
+
+            fn encode<T>(&self, dest: &mut T, version: Version) -> Result<(), Error>
+            where
+                T: BufMut,
+            {
+                $(self.$index.encode(dest, version)?;)+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_encoder_tuple!(A 0);
+impl_encoder_tuple!(A 0, B 1);
+impl_encoder_tuple!(A 0, B 1, C 2);
+impl_encoder_tuple!(A 0, B 1, C 2, D 3);
+impl_encoder_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_encoder_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+
 impl Encoder for bool {
     fn write_size(&self, _version: Version) -> usize {
         1
@@ -314,6 +413,96 @@ impl Encoder for i64 {
     }
 }
 
+impl Encoder for u64 {
+    fn write_size(&self, _version: Version) -> usize {
+        8
+    }
+
+    fn encode<T>(&self, dest: &mut T, _version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        if dest.remaining_mut() < 8 {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "not enough capacity for u64",
+            ));
+        }
+        dest.put_u64(*self);
+        Ok(())
+    }
+}
+
+impl Encoder for f32 {
+    fn write_size(&self, _version: Version) -> usize {
+        4
+    }
+
+    fn encode<T>(&self, dest: &mut T, _version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        if dest.remaining_mut() < 4 {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "not enough capacity for f32",
+            ));
+        }
+        dest.put_f32(*self);
+        Ok(())
+    }
+}
+
+impl Encoder for f64 {
+    fn write_size(&self, _version: Version) -> usize {
+        8
+    }
+
+    fn encode<T>(&self, dest: &mut T, _version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        if dest.remaining_mut() < 8 {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "not enough capacity for f64",
+            ));
+        }
+        dest.put_f64(*self);
+        Ok(())
+    }
+}
+
+/// duration is encoded as seconds (u64) followed by sub second nanos (u32)
+impl Encoder for Duration {
+    fn write_size(&self, version: Version) -> usize {
+        self.as_secs().write_size(version) + self.subsec_nanos().write_size(version)
+    }
+
+    fn encode<T>(&self, dest: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        self.as_secs().encode(dest, version)?;
+        self.subsec_nanos().encode(dest, version)
+    }
+}
+
+/// uuid is encoded as 16 bytes without length
+#[cfg(feature = "uuid")]
+impl Encoder for uuid::Uuid {
+    fn write_size(&self, version: Version) -> usize {
+        self.as_bytes().write_size(version)
+    }
+
+    fn encode<T>(&self, dest: &mut T, version: Version) -> Result<(), Error>
+    where
+        T: BufMut,
+    {
+        self.as_bytes().encode(dest, version)
+    }
+}
+
 impl EncoderVarInt for i64 {
     fn var_write_size(&self) -> usize {
         variant_size(*self)
@@ -0,0 +1,25 @@
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::io::Error;
+
+use fluvio_protocol_core::{Decoder, Encoder};
+use fluvio_protocol_derive::{Decoder, Encoder};
+
+#[derive(Encoder, Default, Decoder, Debug)]
+pub struct MapHolder {
+    values: HashMap<String, Vec<i32>>,
+}
+
+#[test]
+fn test_encode_hashmap() -> Result<(), Error> {
+    let mut v1 = MapHolder::default();
+    v1.values.insert("a".to_owned(), vec![0, 2]); // 3 (key) + 4 (vec len) + 8  = 15
+    v1.values.insert("b".to_owned(), vec![1]); //  3 (key) + 4 (vec len) + 4  = 11
+    let mut src = vec![];
+    v1.encode(&mut src, 0)?;
+    assert_eq!(src.len(), 28);
+    assert_eq!(v1.write_size(0), 28);
+    let v2 = MapHolder::decode_from(&mut Cursor::new(src), 0)?;
+    assert_eq!(v2.values, v1.values);
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use std::io::Cursor;
+use std::io::Error;
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use fluvio_protocol_core::{Decoder, Encoder};
+use fluvio_protocol_derive::{Decoder, Encoder};
+
+#[derive(Encoder, Decoder, Default, Debug, PartialEq)]
+pub struct Child {
+    flag: bool,
+}
+
+#[derive(Encoder, Decoder, Default, Debug, PartialEq)]
+pub struct Record {
+    offset: u64,
+    ratio: f32,
+    score: f64,
+    pair: (i32, String),
+    checksum: [u8; 4],
+    child: Box<Child>,
+    timeout: Duration,
+    id: Uuid,
+}
+
+#[test]
+fn test_encode_decode_types() -> Result<(), Error> {
+    let record = Record {
+        offset: u64::MAX,
+        ratio: 0.5,
+        score: -1.25,
+        pair: (3, "ab".to_owned()),
+        checksum: [1, 2, 3, 4],
+        child: Box::new(Child { flag: true }),
+        timeout: Duration::new(5, 10),
+        id: Uuid::from_bytes([7; 16]),
+    };
+
+    let mut dest = vec![];
+    record.encode(&mut dest, 0)?;
+    // 8 + 4 + 8 + (4 + 4) + 4 + 1 + (8 + 4) + 16
+    assert_eq!(dest.len(), 61);
+    assert_eq!(record.write_size(0), dest.len());
+    assert_eq!(dest[0..8], [0xff; 8]);
+    assert_eq!(dest[28..32], [1, 2, 3, 4]);
+
+    let decoded = Record::decode_from(&mut Cursor::new(dest), 0)?;
+    assert_eq!(decoded, record);
+    Ok(())
+}
+
+#[test]
+fn test_decode_u64_not_enough() {
+    let data = [0x01, 0x02, 0x03, 0x04];
+    let result = u64::decode_from(&mut Cursor::new(data), 0);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_decode_invalid_duration() {
+    let mut dest = vec![];
+    (5_u64, 1_000_000_000_u32)
+        .encode(&mut dest, 0)
+        .expect("encode");
+    let result = Duration::decode_from(&mut Cursor::new(dest), 0);
+    assert!(result.is_err());
+}
+
+#[derive(Encoder, Decoder, Default, Debug)]
+struct VersionedRecord {
+    value: u64,
+    #[fluvio(min_version = 1)]
+    timeout: Duration,
+}
+
+#[test]
+fn test_version_types() -> Result<(), Error> {
+    let record = VersionedRecord {
+        value: 1,
+        timeout: Duration::from_millis(1500),
+    };
+
+    let mut dest = vec![];
+    record.encode(&mut dest, 0)?;
+    assert_eq!(dest.len(), 8);
+    assert_eq!(record.write_size(0), 8);
+
+    let mut dest = vec![];
+    record.encode(&mut dest, 1)?;
+    assert_eq!(dest.len(), 20);
+    assert_eq!(record.write_size(1), 20);
+
+    let decoded = VersionedRecord::decode_from(&mut Cursor::new(dest), 1)?;
+    assert_eq!(decoded.value, 1);
+    assert_eq!(decoded.timeout, Duration::from_millis(1500));
+    Ok(())
+}
@@ -31,7 +31,11 @@ impl RunCmd {
                 fluvio_spu::main_loop(opt);
             }
             Self::SC(opt) => {
-                fluvio_sc::k8::main_k8_loop(opt);
+                if opt.local_metadata().is_some() {
+                    fluvio_sc::local::main_local_loop(opt);
+                } else {
+                    fluvio_sc::k8::main_k8_loop(opt);
+                }
             }
             Self::Metadata(meta) => {
                 meta.process()?;
@@ -22,6 +22,12 @@ pub enum AdminPublicApiKey {
     Delete = 1002,
     List = 1003,
     Watch = 1004,
+    JoinGroup = 1005,
+    GroupHeartbeat = 1006,
+    LeaveGroup = 1007,
+    Update = 1008,
+    ElectLeaders = 1009,
+    AuditLog = 1010,
 }
 
 impl Default for AdminPublicApiKey {
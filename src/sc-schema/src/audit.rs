@@ -0,0 +1,76 @@
+//!
+//! # Audit Log
+//!
+//! Administrative requests handled by SC are recorded with identity of caller and result.
+//! Records can be queried with filters, newest records are returned.
+//!
+
+use dataplane::api::Request;
+use dataplane::core::{Encoder, Decoder};
+
+use crate::AdminPublicApiKey;
+use crate::AdminRequest;
+
+/// Query audit log, all filters are optional
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct AuditLogRequest {
+    pub principal: Option<String>,
+    /// object type, ex: Topic
+    pub object_type: Option<String>,
+    /// only records at or after timestamp in milliseconds since unix epoch
+    pub since: Option<i64>,
+    /// max number of newest records to return
+    pub max_records: u32,
+}
+
+impl Request for AuditLogRequest {
+    const API_KEY: u16 = AdminPublicApiKey::AuditLog as u16;
+    const DEFAULT_API_VERSION: i16 = 0;
+    type Response = AuditLogResponse;
+}
+
+impl AdminRequest for AuditLogRequest {}
+
+/// Matching records ordered from oldest to newest
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct AuditLogResponse {
+    pub records: Vec<AuditRecord>,
+}
+
+#[derive(Encoder, Decoder, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "use_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct AuditRecord {
+    /// milliseconds since unix epoch
+    pub timestamp: i64,
+    /// authenticated principal, empty if authorization is not enabled
+    pub principal: String,
+    pub object_type: String,
+    pub key: String,
+    /// ex: Create, Delete, Update
+    pub action: String,
+    pub success: bool,
+    pub message: Option<String>,
+}
+
+impl AuditLogRequest {
+    /// check if record matches filters
+    pub fn matches(&self, record: &AuditRecord) -> bool {
+        self.principal
+            .as_ref()
+            .map(|principal| principal == &record.principal)
+            .unwrap_or(true)
+            && self
+                .object_type
+                .as_ref()
+                .map(|ty| ty.eq_ignore_ascii_case(&record.object_type))
+                .unwrap_or(true)
+            && self
+                .since
+                .map(|since| record.timestamp >= since)
+                .unwrap_or(true)
+    }
+}
@@ -0,0 +1,95 @@
+//!
+//! # Consumer Group
+//!
+//! Membership of consumer groups coordinated by SC.
+//! Partitions of topic are assigned across live members of group.
+//! Members must send heartbeat within session timeout to remain in group.
+//!
+//! When partitions move between members, a partition is only given to its new member
+//! after the previous member has acknowledged a generation which no longer includes it,
+//! so two members never consume same partition at the same time.
+//!
+
+use dataplane::api::Request;
+use dataplane::core::{Encoder, Decoder};
+use dataplane::ErrorCode;
+
+use crate::AdminPublicApiKey;
+
+/// Partitions assigned to member for a generation of group.
+/// Generation is incremented whenever members or partitions change.
+#[derive(Encoder, Decoder, Default, Debug, Clone, PartialEq)]
+pub struct GroupAssignment {
+    pub generation: i32,
+    pub partitions: Vec<i32>,
+}
+
+/// Join consumer group of topic
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct JoinGroupRequest {
+    pub group: String,
+    pub topic: String,
+    /// member is removed if no heartbeat is received within timeout
+    pub session_timeout_ms: u32,
+    /// highest generation known to member, ex: generation group last committed offsets in.
+    /// Group which is created again starts above it, so commits of new group are not fenced
+    #[fluvio(min_version = 1)]
+    pub min_generation: i32,
+}
+
+impl Request for JoinGroupRequest {
+    const API_KEY: u16 = AdminPublicApiKey::JoinGroup as u16;
+    const DEFAULT_API_VERSION: i16 = 1;
+    type Response = JoinGroupResponse;
+}
+
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct JoinGroupResponse {
+    pub error_code: ErrorCode,
+    /// id assigned by coordinator, used for heartbeat and leave
+    pub member_id: String,
+    pub assignment: GroupAssignment,
+}
+
+/// Keep member alive and receive current assignment
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct GroupHeartbeatRequest {
+    pub group: String,
+    pub topic: String,
+    pub member_id: String,
+    /// generation of assignment member has switched to, it no longer consumes other partitions
+    #[fluvio(min_version = 1)]
+    pub generation: i32,
+}
+
+impl Request for GroupHeartbeatRequest {
+    const API_KEY: u16 = AdminPublicApiKey::GroupHeartbeat as u16;
+    const DEFAULT_API_VERSION: i16 = 1;
+    type Response = GroupHeartbeatResponse;
+}
+
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct GroupHeartbeatResponse {
+    /// UnknownMemberId if member has expired, member must join again
+    pub error_code: ErrorCode,
+    pub assignment: GroupAssignment,
+}
+
+/// Leave group, partitions are reassigned to remaining members
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct LeaveGroupRequest {
+    pub group: String,
+    pub topic: String,
+    pub member_id: String,
+}
+
+impl Request for LeaveGroupRequest {
+    const API_KEY: u16 = AdminPublicApiKey::LeaveGroup as u16;
+    const DEFAULT_API_VERSION: i16 = 0;
+    type Response = LeaveGroupResponse;
+}
+
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct LeaveGroupResponse {
+    pub error_code: ErrorCode,
+}
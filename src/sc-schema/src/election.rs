@@ -0,0 +1,69 @@
+//!
+//! # Preferred Leader Election
+//!
+//! Moves leadership of partitions back to preferred replica, which is first replica of partition.
+//! Leadership is only moved if preferred replica is online and has caught up with current leader.
+//!
+
+use dataplane::api::Request;
+use dataplane::core::{Encoder, Decoder};
+use dataplane::ErrorCode;
+use fluvio_types::SpuId;
+
+use crate::AdminPublicApiKey;
+use crate::AdminRequest;
+
+/// Elect preferred leader for partitions of topics
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct ElectLeadersRequest {
+    /// if empty, election is performed for all topics
+    pub topics: Vec<String>,
+}
+
+impl Request for ElectLeadersRequest {
+    const API_KEY: u16 = AdminPublicApiKey::ElectLeaders as u16;
+    const DEFAULT_API_VERSION: i16 = 0;
+    type Response = ElectLeadersResponse;
+}
+
+impl AdminRequest for ElectLeadersRequest {}
+
+/// Result of election for partitions whose leader was not preferred
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct ElectLeadersResponse {
+    pub results: Vec<ElectionResult>,
+}
+
+#[derive(Encoder, Decoder, Default, Debug, Clone, PartialEq)]
+pub struct ElectionResult {
+    /// partition name, ex: topic-0
+    pub partition: String,
+    /// preferred leader
+    pub leader: SpuId,
+    pub error_code: ErrorCode,
+    pub error_message: Option<String>,
+}
+
+impl ElectionResult {
+    pub fn elected(partition: String, leader: SpuId) -> Self {
+        Self {
+            partition,
+            leader,
+            error_code: ErrorCode::None,
+            error_message: None,
+        }
+    }
+
+    pub fn failed(partition: String, leader: SpuId, reason: String) -> Self {
+        Self {
+            partition,
+            leader,
+            error_code: ErrorCode::PartitionError,
+            error_message: Some(reason),
+        }
+    }
+
+    pub fn is_elected(&self) -> bool {
+        self.error_code.is_ok()
+    }
+}
@@ -1,9 +1,13 @@
 pub mod topic;
 pub mod spu;
 pub mod spg;
+pub mod smartmodule;
 pub mod partition;
 pub mod versions;
 pub mod objects;
+pub mod consumer_group;
+pub mod election;
+pub mod audit;
 mod apis;
 mod request;
 mod response;
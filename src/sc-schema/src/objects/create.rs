@@ -20,7 +20,7 @@ pub struct CreateRequest {
 
 impl Request for CreateRequest {
     const API_KEY: u16 = AdminPublicApiKey::Create as u16;
-    const DEFAULT_API_VERSION: i16 = 0;
+    const DEFAULT_API_VERSION: i16 = 1;
     type Response = Status;
 }
 
@@ -39,11 +39,13 @@ mod create {
     use fluvio_controlplane_metadata::topic::TopicSpec;
     use fluvio_controlplane_metadata::spu::CustomSpuSpec;
     use fluvio_controlplane_metadata::spg::SpuGroupSpec;
+    use fluvio_controlplane_metadata::smartmodule::SmartModuleSpec;
     use super::*;
 
     const TOPIC: u8 = 0;
     const CUSTOM_SPU: u8 = 1;
     const SPG: u8 = 2;
+    const SMART_MODULE: u8 = 3;
 
     #[derive(Debug)]
     /// enum of spec that can be created
@@ -51,6 +53,7 @@ mod create {
         Topic(TopicSpec),
         CustomSpu(CustomSpuSpec),
         SpuGroup(SpuGroupSpec),
+        SmartModule(SmartModuleSpec),
     }
 
     impl Default for AllCreatableSpec {
@@ -68,6 +71,7 @@ mod create {
                     Self::Topic(s) => s.write_size(version),
                     Self::CustomSpu(s) => s.write_size(version),
                     Self::SpuGroup(s) => s.write_size(version),
+                    Self::SmartModule(s) => s.write_size(version),
                 }
         }
 
@@ -94,6 +98,12 @@ mod create {
                     typ.encode(dest, version)?;
                     s.encode(dest, version)?;
                 }
+
+                Self::SmartModule(s) => {
+                    let typ: u8 = SMART_MODULE;
+                    typ.encode(dest, version)?;
+                    s.encode(dest, version)?;
+                }
             }
 
             Ok(())
@@ -131,6 +141,13 @@ mod create {
                     Ok(())
                 }
 
+                SMART_MODULE => {
+                    let mut response = SmartModuleSpec::default();
+                    response.decode(src, version)?;
+                    *self = Self::SmartModule(response);
+                    Ok(())
+                }
+
                 // Unexpected type
                 _ => Err(Error::new(
                     ErrorKind::InvalidData,
@@ -15,6 +15,7 @@ use fluvio_controlplane_metadata::topic::TopicSpec;
 use fluvio_controlplane_metadata::spu::CustomSpuSpec;
 use fluvio_controlplane_metadata::spu::CustomSpuKey;
 use fluvio_controlplane_metadata::spg::SpuGroupSpec;
+use fluvio_controlplane_metadata::smartmodule::SmartModuleSpec;
 use fluvio_controlplane_metadata::core::Spec;
 use fluvio_controlplane_metadata::core::Removable;
 
@@ -36,6 +37,7 @@ pub enum DeleteRequest {
     Topic(String),
     CustomSpu(CustomSpuKey),
     SpuGroup(String),
+    SmartModule(String),
 }
 
 impl Default for DeleteRequest {
@@ -51,6 +53,7 @@ impl DeleteRequest {
             Self::Topic(_) => TopicSpec::LABEL,
             Self::CustomSpu(_) => CustomSpuSpec::LABEL,
             Self::SpuGroup(_) => SpuGroupSpec::LABEL,
+            Self::SmartModule(_) => SmartModuleSpec::LABEL,
         }
     }
 }
@@ -72,6 +75,7 @@ impl Encoder for DeleteRequest {
                 Self::Topic(s) => s.write_size(version),
                 Self::CustomSpu(s) => s.write_size(version),
                 Self::SpuGroup(s) => s.write_size(version),
+                Self::SmartModule(s) => s.write_size(version),
             }
     }
 
@@ -86,6 +90,7 @@ impl Encoder for DeleteRequest {
             Self::Topic(s) => s.encode(dest, version)?,
             Self::CustomSpu(s) => s.encode(dest, version)?,
             Self::SpuGroup(s) => s.encode(dest, version)?,
+            Self::SmartModule(s) => s.encode(dest, version)?,
         }
 
         Ok(())
@@ -123,6 +128,13 @@ impl Decoder for DeleteRequest {
                 Ok(())
             }
 
+            SmartModuleSpec::LABEL => {
+                let mut response = String::default();
+                response.decode(src, version)?;
+                *self = Self::SmartModule(response);
+                Ok(())
+            }
+
             // Unexpected type
             _ => Err(Error::new(
                 ErrorKind::InvalidData,
@@ -14,6 +14,7 @@ use fluvio_controlplane_metadata::core::*;
 use fluvio_controlplane_metadata::topic::TopicSpec;
 use fluvio_controlplane_metadata::spu::*;
 use fluvio_controlplane_metadata::spg::SpuGroupSpec;
+use fluvio_controlplane_metadata::smartmodule::SmartModuleSpec;
 use fluvio_controlplane_metadata::store::*;
 use fluvio_controlplane_metadata::partition::PartitionSpec;
 use crate::AdminPublicApiKey;
@@ -43,6 +44,7 @@ pub enum ListRequest {
     SpuGroup(Vec<NameFilter>),
     CustomSpu(Vec<NameFilter>),
     Partition(Vec<NameFilter>),
+    SmartModule(Vec<NameFilter>),
 }
 
 impl Default for ListRequest {
@@ -53,7 +55,7 @@ impl Default for ListRequest {
 
 impl Request for ListRequest {
     const API_KEY: u16 = AdminPublicApiKey::List as u16;
-    const DEFAULT_API_VERSION: i16 = 0;
+    const DEFAULT_API_VERSION: i16 = 1;
     type Response = ListResponse;
 }
 
@@ -66,6 +68,7 @@ pub enum ListResponse {
     CustomSpu(Vec<Metadata<CustomSpuSpec>>),
     SpuGroup(Vec<Metadata<SpuGroupSpec>>),
     Partition(Vec<Metadata<PartitionSpec>>),
+    SmartModule(Vec<Metadata<SmartModuleSpec>>),
 }
 
 impl Default for ListResponse {
@@ -155,6 +158,7 @@ mod encoding {
                 Self::SpuGroup(_) => SpuGroupSpec::LABEL,
                 Self::CustomSpu(_) => CustomSpuSpec::LABEL,
                 Self::Partition(_) => PartitionSpec::LABEL,
+                Self::SmartModule(_) => SmartModuleSpec::LABEL,
             }
         }
     }
@@ -170,6 +174,7 @@ mod encoding {
                     Self::SpuGroup(s) => s.write_size(version),
                     Self::Spu(s) => s.write_size(version),
                     Self::Partition(s) => s.write_size(version),
+                    Self::SmartModule(s) => s.write_size(version),
                 }
         }
 
@@ -186,6 +191,7 @@ mod encoding {
                 Self::SpuGroup(s) => s.encode(dest, version)?,
                 Self::Spu(s) => s.encode(dest, version)?,
                 Self::Partition(s) => s.encode(dest, version)?,
+                Self::SmartModule(s) => s.encode(dest, version)?,
             }
 
             Ok(())
@@ -237,6 +243,13 @@ mod encoding {
                     Ok(())
                 }
 
+                SmartModuleSpec::LABEL => {
+                    let mut response: Vec<NameFilter> = vec![];
+                    response.decode(src, version)?;
+                    *self = Self::SmartModule(response);
+                    Ok(())
+                }
+
                 // Unexpected type
                 _ => Err(Error::new(
                     ErrorKind::InvalidData,
@@ -255,6 +268,7 @@ mod encoding {
                 Self::SpuGroup(_) => SpuGroupSpec::LABEL,
                 Self::CustomSpu(_) => CustomSpuSpec::LABEL,
                 Self::Partition(_) => PartitionSpec::LABEL,
+                Self::SmartModule(_) => SmartModuleSpec::LABEL,
             }
         }
     }
@@ -270,6 +284,7 @@ mod encoding {
                     Self::SpuGroup(s) => s.write_size(version),
                     Self::Spu(s) => s.write_size(version),
                     Self::Partition(s) => s.write_size(version),
+                    Self::SmartModule(s) => s.write_size(version),
                 }
         }
 
@@ -286,6 +301,7 @@ mod encoding {
                 Self::SpuGroup(s) => s.encode(dest, version)?,
                 Self::Spu(s) => s.encode(dest, version)?,
                 Self::Partition(s) => s.encode(dest, version)?,
+                Self::SmartModule(s) => s.encode(dest, version)?,
             }
 
             Ok(())
@@ -337,6 +353,13 @@ mod encoding {
                     Ok(())
                 }
 
+                SmartModuleSpec::LABEL => {
+                    let mut response: Vec<Metadata<SmartModuleSpec>> = vec![];
+                    response.decode(src, version)?;
+                    *self = Self::SmartModule(response);
+                    Ok(())
+                }
+
                 // Unexpected type
                 _ => Err(Error::new(
                     ErrorKind::InvalidData,
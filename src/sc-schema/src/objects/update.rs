@@ -0,0 +1,167 @@
+//!
+//! # Update object
+//!
+//! Changes spec of existing object.
+//!
+#![allow(clippy::assign_op_pattern)]
+
+use std::fmt::Debug;
+
+use dataplane::core::{Encoder, Decoder};
+use dataplane::api::Request;
+use fluvio_types::SpuId;
+
+use crate::Status;
+use crate::AdminPublicApiKey;
+use crate::AdminRequest;
+
+pub use update::*;
+
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct UpdateRequest {
+    pub name: String,
+    pub spec: AllUpdatableSpec,
+}
+
+impl Request for UpdateRequest {
+    const API_KEY: u16 = AdminPublicApiKey::Update as u16;
+    const DEFAULT_API_VERSION: i16 = 1;
+    type Response = Status;
+}
+
+impl AdminRequest for UpdateRequest {}
+
+/// Changes to existing topic
+#[derive(Encoder, Decoder, Default, Debug, Clone, PartialEq)]
+pub struct TopicUpdate {
+    /// new partition count, must be greater than current count.
+    /// existing partitions are not changed
+    pub partitions: i32,
+}
+
+impl TopicUpdate {
+    pub fn partitions(partitions: i32) -> Self {
+        Self { partitions }
+    }
+}
+
+/// Move replicas of partition to different SPUs
+#[derive(Encoder, Decoder, Default, Debug, Clone, PartialEq)]
+pub struct PartitionUpdate {
+    /// new replicas of partition, first replica becomes leader if current leader is removed
+    pub replicas: Vec<SpuId>,
+}
+
+impl PartitionUpdate {
+    pub fn replicas(replicas: Vec<SpuId>) -> Self {
+        Self { replicas }
+    }
+}
+
+#[allow(clippy::module_inception)]
+mod update {
+
+    use std::io::Error;
+    use std::io::ErrorKind;
+
+    use tracing::trace;
+
+    use dataplane::core::Version;
+    use dataplane::bytes::{Buf, BufMut};
+    use super::*;
+
+    const TOPIC: u8 = 0;
+    const PARTITION: u8 = 1;
+
+    #[derive(Debug)]
+    /// enum of spec that can be updated
+    pub enum AllUpdatableSpec {
+        Topic(TopicUpdate),
+        Partition(PartitionUpdate),
+    }
+
+    impl Default for AllUpdatableSpec {
+        fn default() -> Self {
+            Self::Topic(TopicUpdate::default())
+        }
+    }
+
+    impl From<TopicUpdate> for AllUpdatableSpec {
+        fn from(update: TopicUpdate) -> Self {
+            Self::Topic(update)
+        }
+    }
+
+    impl From<PartitionUpdate> for AllUpdatableSpec {
+        fn from(update: PartitionUpdate) -> Self {
+            Self::Partition(update)
+        }
+    }
+
+    impl Encoder for AllUpdatableSpec {
+        fn write_size(&self, version: Version) -> usize {
+            let type_size = (0u8).write_size(version);
+
+            type_size
+                + match self {
+                    Self::Topic(s) => s.write_size(version),
+                    Self::Partition(s) => s.write_size(version),
+                }
+        }
+
+        // encode match
+        fn encode<T>(&self, dest: &mut T, version: Version) -> Result<(), Error>
+        where
+            T: BufMut,
+        {
+            match self {
+                Self::Topic(s) => {
+                    let typ: u8 = TOPIC;
+                    typ.encode(dest, version)?;
+                    s.encode(dest, version)?;
+                }
+
+                Self::Partition(s) => {
+                    let typ: u8 = PARTITION;
+                    typ.encode(dest, version)?;
+                    s.encode(dest, version)?;
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    impl Decoder for AllUpdatableSpec {
+        fn decode<T>(&mut self, src: &mut T, version: Version) -> Result<(), Error>
+        where
+            T: Buf,
+        {
+            let mut typ: u8 = 0;
+            typ.decode(src, version)?;
+            trace!("decoded type: {}", typ);
+
+            match typ {
+                TOPIC => {
+                    let mut response = TopicUpdate::default();
+                    response.decode(src, version)?;
+                    *self = Self::Topic(response);
+                    Ok(())
+                }
+
+                PARTITION => {
+                    let mut response = PartitionUpdate::default();
+                    response.decode(src, version)?;
+                    *self = Self::Partition(response);
+                    Ok(())
+                }
+
+                // Unexpected type
+                _ => Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("invalid spec type {}", typ),
+                )),
+            }
+        }
+    }
+}
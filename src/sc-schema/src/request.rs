@@ -19,6 +19,9 @@ use dataplane::core::Encoder;
 use dataplane::versions::ApiVersionsRequest;
 
 use super::objects::*;
+use super::consumer_group::*;
+use super::election::*;
+use super::audit::*;
 use super::AdminPublicApiKey;
 
 #[derive(Debug, Encoder)]
@@ -29,6 +32,12 @@ pub enum AdminPublicRequest {
     DeleteRequest(RequestMessage<DeleteRequest>),
     ListRequest(RequestMessage<ListRequest>),
     WatchRequest(RequestMessage<WatchRequest>),
+    UpdateRequest(RequestMessage<UpdateRequest>),
+    JoinGroupRequest(RequestMessage<JoinGroupRequest>),
+    GroupHeartbeatRequest(RequestMessage<GroupHeartbeatRequest>),
+    LeaveGroupRequest(RequestMessage<LeaveGroupRequest>),
+    ElectLeadersRequest(RequestMessage<ElectLeadersRequest>),
+    AuditLogRequest(RequestMessage<AuditLogRequest>),
 }
 
 impl Default for AdminPublicRequest {
@@ -59,6 +68,16 @@ impl ApiMessage for AdminPublicRequest {
             AdminPublicApiKey::Delete => api_decode!(Self, DeleteRequest, src, header),
             AdminPublicApiKey::List => api_decode!(Self, ListRequest, src, header),
             AdminPublicApiKey::Watch => api_decode!(Self, WatchRequest, src, header),
+            AdminPublicApiKey::Update => api_decode!(Self, UpdateRequest, src, header),
+
+            AdminPublicApiKey::JoinGroup => api_decode!(Self, JoinGroupRequest, src, header),
+            AdminPublicApiKey::GroupHeartbeat => {
+                api_decode!(Self, GroupHeartbeatRequest, src, header)
+            }
+            AdminPublicApiKey::LeaveGroup => api_decode!(Self, LeaveGroupRequest, src, header),
+
+            AdminPublicApiKey::ElectLeaders => api_decode!(Self, ElectLeadersRequest, src, header),
+            AdminPublicApiKey::AuditLog => api_decode!(Self, AuditLogRequest, src, header),
         }
     }
 }
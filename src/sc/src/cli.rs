@@ -11,6 +11,7 @@ use std::process;
 use std::io::Error as IoError;
 use std::io::ErrorKind;
 use std::path::PathBuf;
+use std::time::Duration;
 use std::convert::TryFrom;
 
 use tracing::info;
@@ -22,6 +23,8 @@ use k8_client::K8Config;
 use fluvio_future::openssl::TlsAcceptor;
 use fluvio_future::openssl::SslVerifyMode;
 
+use fluvio_auth::token::CredentialStore;
+
 use crate::services::auth::basic::BasicRbacPolicy;
 use crate::error::ScError;
 use crate::config::ScConfig;
@@ -36,6 +39,10 @@ pub struct ScOpt {
     /// running in local mode only
     local: bool,
 
+    /// Directory to persist metadata instead of Kubernetes
+    #[structopt(long = "local-metadata", value_name = "dir")]
+    local_metadata: Option<PathBuf>,
+
     #[structopt(long)]
     /// Address for external service
     bind_public: Option<String>,
@@ -65,9 +72,25 @@ pub struct ScOpt {
     )]
     auth_policy: Option<PathBuf>,
 
+    /// credentials file with hashed passwords and tokens
+    #[structopt(long = "credentials", value_name = "credentials path", env)]
+    credentials: Option<PathBuf>,
+
+    /// file where admin actions are recorded
+    #[structopt(long = "audit-log", value_name = "audit log path", env)]
+    audit_log: Option<PathBuf>,
+
     /// only allow white list of controllers
     #[structopt(long)]
     white_list: Vec<String>,
+
+    /// Seconds between leader imbalance checks, 0 disables automatic leader rebalancing
+    #[structopt(long, value_name = "seconds")]
+    leader_rebalance_interval: Option<u64>,
+
+    /// Percentage of partitions per SPU allowed to be led by non preferred replica
+    #[structopt(long, value_name = "percent")]
+    leader_imbalance_threshold: Option<u8>,
 }
 
 impl ScOpt {
@@ -80,6 +103,11 @@ impl ScOpt {
         self.local
     }
 
+    /// directory of local metadata, metadata is stored in Kubernetes if not set
+    pub fn local_metadata(&self) -> Option<&PathBuf> {
+        self.local_metadata.as_ref()
+    }
+
     #[allow(clippy::type_complexity)]
     fn get_sc_and_k8_config(
         mut self,
@@ -114,7 +142,23 @@ impl ScOpt {
 
         config.namespace = self.namespace.unwrap();
         config.x509_auth_scopes = self.x509_auth_scopes;
+        if let Some(path) = self.credentials {
+            info!("using credentials: {:?}", path);
+            config.credentials = Some(CredentialStore::try_from(path)?);
+        }
         config.white_list = self.white_list.into_iter().collect();
+        config.audit_log = self.audit_log;
+
+        if let Some(secs) = self.leader_rebalance_interval {
+            config.leader_rebalance_interval = if secs == 0 {
+                None
+            } else {
+                Some(Duration::from_secs(secs))
+            };
+        }
+        if let Some(threshold) = self.leader_imbalance_threshold {
+            config.leader_imbalance_threshold = threshold;
+        }
 
         // Set Configuration Authorzation Policy
         let policy = match self.auth_policy {
@@ -144,6 +188,21 @@ impl ScOpt {
         }
     }
 
+    /// parse configuration without Kubernetes, namespace is only used to label metadata
+    pub fn parse_local_cli_or_exit(mut self) -> (Config, Option<(String, TlsConfig)>) {
+        if self.namespace.is_none() {
+            self.namespace = Some("default".to_owned());
+        }
+
+        match self.as_sc_config() {
+            Err(err) => {
+                print_cli_err!(err);
+                process::exit(-1);
+            }
+            Ok(config) => config,
+        }
+    }
+
     pub fn parse_cli_or_exit(self) -> (Config, K8Config, Option<(String, TlsConfig)>) {
         match self.get_sc_and_k8_config() {
             Err(err) => {
@@ -4,10 +4,12 @@
 //! Stores configuration parameter used by Streaming Controller module.
 //!
 use std::collections::HashSet;
+use std::time::Duration;
 use std::{io::Error as IoError, path::PathBuf};
 
 use fluvio_types::defaults::SC_PUBLIC_PORT;
 use fluvio_types::defaults::SC_PRIVATE_PORT;
+use fluvio_auth::token::CredentialStore;
 
 // -----------------------------------
 // Traits
@@ -26,7 +28,15 @@ pub struct ScConfig {
     pub run_k8_dispatchers: bool,
     pub namespace: String,
     pub x509_auth_scopes: Option<PathBuf>,
+    /// when set, clients must authenticate with password or token
+    pub credentials: Option<CredentialStore>,
+    /// file where admin actions are appended as json lines
+    pub audit_log: Option<PathBuf>,
     pub white_list: HashSet<String>,
+    /// how often leader imbalance is checked, none disables automatic rebalancing
+    pub leader_rebalance_interval: Option<Duration>,
+    /// percentage of partitions not led by their preferred leader allowed per spu
+    pub leader_imbalance_threshold: u8,
 }
 
 impl ::std::default::Default for ScConfig {
@@ -37,7 +47,11 @@ impl ::std::default::Default for ScConfig {
             run_k8_dispatchers: true,
             namespace: "default".to_owned(),
             x509_auth_scopes: None,
+            credentials: None,
+            audit_log: None,
             white_list: HashSet::new(),
+            leader_rebalance_interval: Some(Duration::from_secs(300)),
+            leader_imbalance_threshold: 10,
         }
     }
 }
@@ -2,6 +2,8 @@
 //! # Auth Controller
 //!
 
+use std::time::{Duration, Instant};
+
 use tracing::{debug, trace, instrument};
 
 use fluvio_future::task::spawn;
@@ -20,6 +22,8 @@ pub struct PartitionController {
     partitions: StoreContext<PartitionSpec>,
     spus: StoreContext<SpuSpec>,
     reducer: PartitionReducer,
+    leader_rebalance_interval: Option<Duration>,
+    leader_imbalance_threshold: u8,
 }
 
 impl PartitionController {
@@ -34,6 +38,8 @@ impl PartitionController {
                 ctx.partitions().store().clone(),
                 ctx.spus().store().clone(),
             ),
+            leader_rebalance_interval: ctx.config().leader_rebalance_interval,
+            leader_imbalance_threshold: ctx.config().leader_imbalance_threshold,
         };
 
         spawn(controller.dispatch_loop());
@@ -42,23 +48,39 @@ impl PartitionController {
     #[instrument(skip(self), name = "PartitionController")]
     async fn dispatch_loop(mut self) {
         use tokio::select;
+        use fluvio_future::timer::sleep;
 
         let mut spu_status_listener = self.spus.change_listener();
         let mut partition_listener = self.partitions.change_listener();
 
+        // without rebalance interval, timer never fires
+        let rebalance_interval = self
+            .leader_rebalance_interval
+            .unwrap_or_else(|| Duration::from_secs(u32::MAX as u64));
+        let mut time_left = rebalance_interval;
+
         loop {
             self.sync_spu_changes(&mut spu_status_listener).await;
             self.sync_partition_changes(&mut partition_listener).await;
 
             trace!("waiting for events");
+            let wait_time = Instant::now();
 
             select! {
 
                 _ = spu_status_listener.listen() => {
                     debug!("detected spus status changed");
+                    time_left = time_left.checked_sub(wait_time.elapsed()).unwrap_or_default();
                 },
                 _ = partition_listener.listen() => {
                     debug!("detected partition changes");
+                    time_left = time_left.checked_sub(wait_time.elapsed()).unwrap_or_default();
+                },
+                _ = sleep(time_left) => {
+                    if self.leader_rebalance_interval.is_some() {
+                        self.rebalance_leaders().await;
+                    }
+                    time_left = rebalance_interval;
                 }
 
             }
@@ -74,20 +96,18 @@ impl PartitionController {
             return;
         }
 
-        // we only care about delete timestamp changes which are in metadata only
-        let changes = listener.sync_meta_changes().await;
+        // delete timestamp changes are in metadata, reassignment progress is in spec and status
+        let changes = listener.sync_changes().await;
         if changes.is_empty() {
-            trace!("no partition metadata changes");
+            trace!("no partition changes");
             return;
         }
 
         let (updates, _) = changes.parts();
-        trace!(
-            meta_changes = &*format!("{:#?}", updates),
-            "metadata changes"
-        );
+        trace!(changes = &*format!("{:#?}", updates), "partition changes");
 
-        let actions = self.reducer.process_partition_update(updates).await;
+        let mut actions = self.reducer.update_reassignments(&updates);
+        actions.append(&mut self.reducer.process_partition_update(updates).await);
 
         debug!("generated partition actions: {}", actions.len());
         for action in actions.into_iter() {
@@ -95,6 +115,20 @@ impl PartitionController {
         }
     }
 
+    /// move leadership back to preferred replicas if leaders are imbalanced
+    #[instrument(skip(self))]
+    async fn rebalance_leaders(&mut self) {
+        let actions = self
+            .reducer
+            .rebalance_leaders(self.leader_imbalance_threshold)
+            .await;
+
+        debug!("leader rebalance actions: {}", actions.len());
+        for action in actions.into_iter() {
+            self.partitions.send_action(action).await;
+        }
+    }
+
     /// sync spu states to partition
     /// check to make sure
     async fn sync_spu_changes(&mut self, listener: &mut K8ChangeListener<SpuSpec>) {
@@ -2,6 +2,7 @@ mod controller;
 mod reducer;
 
 pub use self::controller::*;
+pub use self::reducer::preferred_leader_candidate;
 pub use common::*;
 
 mod common {
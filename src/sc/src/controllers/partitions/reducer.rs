@@ -4,9 +4,11 @@
 //! Partition metadata information on cached in the local Controller.
 //!
 use std::sync::Arc;
+use std::collections::{BTreeMap, HashSet};
 
 use tracing::{debug, warn, info, instrument};
 
+use fluvio_types::SpuId;
 use fluvio_controlplane_metadata::core::MetadataItem;
 
 use crate::stores::partition::{
@@ -101,6 +103,37 @@ impl PartitionReducer {
             .collect()
     }
 
+    ///
+    /// move partitions which are being reassigned to next step
+    ///
+    #[instrument(skip(self, updates))]
+    pub fn update_reassignments(&self, updates: &[PartitionAdminMd]) -> Vec<PartitionWSAction> {
+        updates
+            .iter()
+            .filter(|partition| {
+                partition.spec.is_reassigning() && !partition.status.is_being_deleted
+            })
+            .flat_map(reassignment_actions)
+            .collect()
+    }
+
+    ///
+    /// move leadership back to preferred replica for spus whose leader imbalance exceeds threshold
+    ///
+    #[instrument(skip(self))]
+    pub async fn rebalance_leaders(&self, threshold_percent: u8) -> Vec<PartitionWSAction> {
+        let online = self.spu_store.online_status().await;
+        let partitions: Vec<PartitionAdminMd> = self
+            .partition_store
+            .read()
+            .await
+            .values()
+            .map(|partition| partition.inner().clone())
+            .collect();
+
+        leader_rebalance_actions(&partitions, &online, threshold_percent)
+    }
+
     ///
     /// based on spu change, update election
     ///
@@ -144,13 +177,12 @@ impl PartitionReducer {
 
         let spu_status = self.spu_store.online_status().await;
 
-        let policy = SimplePolicy::new();
-
         // go thru each partitions whose leader matches offline spu.
         for partition_kv_epoch in self.partition_store.read().await.values() {
             let partition_kv = partition_kv_epoch.inner();
             // find partition who's leader is same as offline spu
             if partition_kv.spec.leader == offline_leader_spu_id {
+                let policy = SimplePolicy::for_partition(partition_kv);
                 // find suitable leader
                 if let Some(candidate_leader) =
                     partition_kv.status.candidate_leader(&spu_status, &policy)
@@ -191,12 +223,12 @@ impl PartitionReducer {
         info!(spu = %online_spu.key(),"start election spu went online" );
         let online_leader_spu_id = online_spu.spec.id;
 
-        let policy = SimplePolicy::new();
         // go thru each partitions which are not online and try to promote given online spu
 
         for partition_kv_epoch in self.partition_store.read().await.values() {
             let partition_kv = partition_kv_epoch.inner();
             if partition_kv.status.is_offline() {
+                let policy = SimplePolicy::for_partition(partition_kv);
                 // we only care about partition who is follower since, leader will set partition status when it start up
                 if partition_kv.spec.leader != online_leader_spu_id {
                     for replica_status in partition_kv.status.replica_iter() {
@@ -224,11 +256,171 @@ impl PartitionReducer {
     }
 }
 
-struct SimplePolicy {}
+/// Preferred leader which leadership of partition can be moved to.
+/// None if leader is already preferred.
+/// Preferred replica must be online and caught up with current leader,
+/// partitions being reassigned or deleted are left alone.
+pub fn preferred_leader_candidate(
+    partition: &PartitionAdminMd,
+    online: &HashSet<SpuId>,
+) -> Result<Option<SpuId>, String> {
+    let preferred = match partition.spec.preferred_leader() {
+        Some(preferred) if preferred != partition.spec.leader => preferred,
+        _ => return Ok(None),
+    };
+
+    if partition.status.is_being_deleted {
+        return Err("partition is being deleted".to_owned());
+    }
+    if partition.spec.is_reassigning() {
+        return Err("partition is being reassigned".to_owned());
+    }
+    if !online.contains(&preferred) {
+        return Err(format!("preferred leader: {} is offline", preferred));
+    }
+    if !partition.status.is_caught_up(preferred) {
+        return Err(format!(
+            "preferred leader: {} has not caught up with leader",
+            preferred
+        ));
+    }
+
+    Ok(Some(preferred))
+}
+
+/// Imbalance of spu is percentage of partitions which prefer spu as leader but are led by other spu.
+/// For spus whose imbalance exceeds threshold, leadership is moved back to them where possible.
+fn leader_rebalance_actions(
+    partitions: &[PartitionAdminMd],
+    online: &HashSet<SpuId>,
+    threshold_percent: u8,
+) -> Vec<PartitionWSAction> {
+    // preferred spu => (preferred partitions, partitions not led by preferred spu)
+    let mut by_preferred: BTreeMap<SpuId, (usize, Vec<&PartitionAdminMd>)> = BTreeMap::new();
+    for partition in partitions {
+        if let Some(preferred) = partition.spec.preferred_leader() {
+            let entry = by_preferred.entry(preferred).or_default();
+            entry.0 += 1;
+            if preferred != partition.spec.leader {
+                entry.1.push(partition);
+            }
+        }
+    }
+
+    let mut actions = vec![];
+    for (spu, (preferred_count, not_preferred)) in by_preferred {
+        if not_preferred.len() * 100 <= preferred_count * threshold_percent as usize {
+            continue;
+        }
+
+        info!(
+            spu,
+            preferred = preferred_count,
+            not_led = not_preferred.len(),
+            "leader imbalance exceeds threshold"
+        );
+        for partition in not_preferred {
+            match preferred_leader_candidate(partition, online) {
+                Ok(Some(leader)) => {
+                    let mut spec = partition.spec.clone();
+                    spec.leader = leader;
+                    actions.push(PartitionWSAction::UpdateSpec((partition.key_owned(), spec)));
+                }
+                Ok(None) => {}
+                Err(reason) => {
+                    debug!(partition = %partition.key(), %reason, "skipping preferred leader election");
+                }
+            }
+        }
+    }
+    actions
+}
+
+/// Reassignment is done in steps, each triggered by partition change:
+///  1. target replicas which are not replicas yet are added as followers
+///  2. once all target replicas have caught up with leader,
+///     first target replica becomes leader if current leader is not in target
+///  3. after new leader is online, replicas not in target are removed
+fn reassignment_actions(partition: &PartitionAdminMd) -> Vec<PartitionWSAction> {
+    let spec = &partition.spec;
+    let target = &spec.target_replicas;
+
+    let new_replicas: Vec<_> = target
+        .iter()
+        .filter(|spu| !spec.has_spu(spu))
+        .cloned()
+        .collect();
+    if !new_replicas.is_empty() {
+        info!(
+            partition = %partition.key(),
+            ?new_replicas,
+            "adding replicas for reassignment"
+        );
+        let mut new_spec = spec.clone();
+        new_spec.replicas.extend(new_replicas);
+        return vec![PartitionWSAction::UpdateSpec((
+            partition.key_owned(),
+            new_spec,
+        ))];
+    }
+
+    // wait for leader election to finish
+    if partition.status.leader.spu != spec.leader {
+        debug!(partition = %partition.key(), "waiting for leader");
+        return vec![];
+    }
+
+    if let Some(lagging) = target
+        .iter()
+        .find(|spu| !partition.status.is_caught_up(**spu))
+    {
+        debug!(partition = %partition.key(), lagging, "waiting for replica to catch up");
+        return vec![];
+    }
+
+    let mut new_spec = spec.clone();
+    if !target.contains(&spec.leader) {
+        info!(
+            partition = %partition.key(),
+            leader = target[0],
+            "switching leader for reassignment"
+        );
+        new_spec.leader = target[0];
+        return vec![PartitionWSAction::UpdateSpec((
+            partition.key_owned(),
+            new_spec,
+        ))];
+    }
+
+    info!(
+        partition = %partition.key(),
+        replicas = ?target,
+        "reassignment completed"
+    );
+    new_spec.replicas = target.clone();
+    new_spec.target_replicas = vec![];
+    let mut new_status = partition.status.clone();
+    new_status.retain_replicas(&new_spec.replicas);
+    vec![
+        PartitionWSAction::UpdateSpec((partition.key_owned(), new_spec)),
+        PartitionWSAction::UpdateStatus((partition.key_owned(), new_status)),
+    ]
+}
+
+/// Replica is suitable leader if it has all records committed by leader.
+/// If topic allows unclean leader election, any replica is suitable and committed records may be lost.
+/// Replica with least lag is preferred.
+struct SimplePolicy {
+    unclean: bool,
+}
 
 impl SimplePolicy {
-    fn new() -> Self {
-        SimplePolicy {}
+    fn new(unclean: bool) -> Self {
+        SimplePolicy { unclean }
+    }
+
+    fn for_partition(partition: &PartitionAdminMd) -> Self {
+        Self::new(partition.spec.config.unclean_leader_election())
     }
 }
 
@@ -238,9 +430,10 @@ impl ElectionPolicy for SimplePolicy {
         replica_status: &ReplicaStatus,
         leader: &ReplicaStatus,
     ) -> ElectionScoring {
-        let lag = leader.leo - replica_status.leo;
-        if lag < 4 {
-            ElectionScoring::Score(lag as u16)
+        let in_sync = replica_status.leo >= 0 && replica_status.leo >= leader.hw;
+        if in_sync || self.unclean {
+            let lag = (leader.leo - replica_status.leo).max(0);
+            ElectionScoring::Score(lag.min(u16::MAX as i64) as u16)
         } else {
             ElectionScoring::NotSuitable
         }
@@ -256,6 +449,184 @@ impl ElectionPolicy for SimplePolicy {
 #[cfg(test)]
 pub mod test {
 
+    use crate::stores::partition::PartitionStatus;
+
+    use super::*;
+
+    fn reassigning_partition(
+        leader: i32,
+        replicas: Vec<i32>,
+        target: Vec<i32>,
+        status: PartitionStatus,
+    ) -> PartitionAdminMd {
+        PartitionAdminMd::new(
+            ("topic", 0),
+            PartitionSpec::new(leader, replicas).with_target_replicas(target),
+            status,
+        )
+    }
+
+    #[test]
+    fn test_reassignment_steps() {
+        // new replica is added as follower
+        let partition = reassigning_partition(
+            5001,
+            vec![5001, 5002],
+            vec![5002, 5003],
+            PartitionStatus::new((5001, 10, 10), vec![(5002, 10, 10).into()]),
+        );
+        let actions = reassignment_actions(&partition);
+        assert_eq!(actions.len(), 1);
+        match &actions[0] {
+            PartitionWSAction::UpdateSpec((_, spec)) => {
+                assert_eq!(spec.replicas, vec![5001, 5002, 5003]);
+            }
+            _ => panic!("expected spec update"),
+        }
+
+        // wait for new replica to catch up
+        let partition = reassigning_partition(
+            5001,
+            vec![5001, 5002, 5003],
+            vec![5002, 5003],
+            PartitionStatus::new(
+                (5001, 10, 10),
+                vec![(5002, 10, 10).into(), (5003, 0, 4).into()],
+            ),
+        );
+        assert!(reassignment_actions(&partition).is_empty());
+
+        // leader is not in target, so leadership is moved
+        let caught_up = PartitionStatus::new(
+            (5001, 10, 10),
+            vec![(5002, 10, 10).into(), (5003, 10, 10).into()],
+        );
+        let partition =
+            reassigning_partition(5001, vec![5001, 5002, 5003], vec![5002, 5003], caught_up);
+        let actions = reassignment_actions(&partition);
+        match &actions[0] {
+            PartitionWSAction::UpdateSpec((_, spec)) => assert_eq!(spec.leader, 5002),
+            _ => panic!("expected spec update"),
+        }
+
+        // old replica is dropped after new leader is online
+        let partition = reassigning_partition(
+            5002,
+            vec![5001, 5002, 5003],
+            vec![5002, 5003],
+            PartitionStatus::new(
+                (5002, 10, 10),
+                vec![(5001, 10, 10).into(), (5003, 10, 10).into()],
+            ),
+        );
+        let actions = reassignment_actions(&partition);
+        assert_eq!(actions.len(), 2);
+        match &actions[0] {
+            PartitionWSAction::UpdateSpec((_, spec)) => {
+                assert_eq!(spec.replicas, vec![5002, 5003]);
+                assert!(!spec.is_reassigning());
+            }
+            _ => panic!("expected spec update"),
+        }
+        match &actions[1] {
+            PartitionWSAction::UpdateStatus((_, status)) => {
+                assert_eq!(status.replicas, vec![(5003, 10, 10).into()]);
+            }
+            _ => panic!("expected status update"),
+        }
+    }
+
+    #[test]
+    fn test_unclean_election_policy() {
+        let status = PartitionStatus::new(
+            (5001, 10, 20),
+            vec![(5002, 10, 15).into(), (5003, 5, 8).into()],
+        );
+        let online: HashSet<SpuId> = vec![5002, 5003].into_iter().collect();
+
+        // 5003 hasn't replicated all committed records
+        let clean = SimplePolicy::new(false);
+        assert!(!clean
+            .potential_leader_score(&status.replicas[1], &status.leader)
+            .is_suitable());
+        assert_eq!(status.candidate_leader(&online, &clean), Some(5002));
+
+        let online: HashSet<SpuId> = vec![5003].into_iter().collect();
+        assert_eq!(status.candidate_leader(&online, &clean), None);
+
+        let unclean = SimplePolicy::new(true);
+        assert_eq!(status.candidate_leader(&online, &unclean), Some(5003));
+    }
+
+    #[test]
+    fn test_leader_rebalance() {
+        let caught_up = |leader: i32, follower: i32| {
+            PartitionStatus::new((leader, 10, 10), vec![(follower, 10, 10).into()])
+        };
+        let partitions = vec![
+            // spu 5001 is preferred for 2 partitions and leads none
+            PartitionAdminMd::new(
+                ("topic", 0),
+                PartitionSpec::new(5002, vec![5001, 5002]),
+                caught_up(5002, 5001),
+            ),
+            PartitionAdminMd::new(
+                ("topic", 1),
+                PartitionSpec::new(5002, vec![5001, 5002]),
+                PartitionStatus::new((5002, 10, 10), vec![(5001, 5, 5).into()]),
+            ),
+            // spu 5002 is preferred for 2 partitions and leads 1
+            PartitionAdminMd::new(
+                ("topic", 2),
+                PartitionSpec::new(5002, vec![5002, 5001]),
+                caught_up(5002, 5001),
+            ),
+            PartitionAdminMd::new(
+                ("topic", 3),
+                PartitionSpec::new(5001, vec![5002, 5001]),
+                caught_up(5001, 5002),
+            ),
+        ];
+        let online: HashSet<SpuId> = vec![5001, 5002].into_iter().collect();
+
+        // lagging replica can't become leader
+        assert_eq!(
+            preferred_leader_candidate(&partitions[0], &online),
+            Ok(Some(5001))
+        );
+        assert!(preferred_leader_candidate(&partitions[1], &online).is_err());
+        assert_eq!(
+            preferred_leader_candidate(&partitions[2], &online),
+            Ok(None)
+        );
+
+        // both spus exceed threshold
+        let actions = leader_rebalance_actions(&partitions, &online, 10);
+        assert_eq!(actions.len(), 2);
+        match &actions[0] {
+            PartitionWSAction::UpdateSpec((key, spec)) => {
+                assert_eq!(key, &("topic", 0).into());
+                assert_eq!(spec.leader, 5001);
+            }
+            _ => panic!("expected spec update"),
+        }
+        match &actions[1] {
+            PartitionWSAction::UpdateSpec((key, spec)) => {
+                assert_eq!(key, &("topic", 3).into());
+                assert_eq!(spec.leader, 5002);
+            }
+            _ => panic!("expected spec update"),
+        }
+
+        // only spu 5001 exceeds 50 percent
+        let actions = leader_rebalance_actions(&partitions, &online, 50);
+        assert_eq!(actions.len(), 1);
+
+        // offline spu can't become leader
+        let online: HashSet<SpuId> = vec![5002].into_iter().collect();
+        assert!(leader_rebalance_actions(&partitions, &online, 50).is_empty());
+    }
+
     /*
     #[test_async]
     async fn test_process_partition_actions_without_partitions() -> Result<(), ()> {
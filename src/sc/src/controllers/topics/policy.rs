@@ -47,6 +47,19 @@ pub fn validate_computed_topic_parameters(param: &TopicReplicaParam) -> TopicNex
 pub async fn generate_replica_map(
     spus: &SpuAdminStore,
     param: &TopicReplicaParam,
+) -> TopicNextState {
+    extend_replica_map(spus, param, &ReplicaMap::new()).await
+}
+
+///
+/// Generate replicas for partitions which are not in current replica map.
+/// Replicas of existing partitions are kept as they are.
+///
+#[instrument(level = "trace", skip(spus, param, current))]
+pub async fn extend_replica_map(
+    spus: &SpuAdminStore,
+    param: &TopicReplicaParam,
+    current: &ReplicaMap,
 ) -> TopicNextState {
     let spu_count = spus.count().await;
     if spu_count < param.replication_factor {
@@ -59,7 +72,10 @@ pub async fn generate_replica_map(
         let reason = format!("need {} more SPU", param.replication_factor - spu_count);
         TopicStatus::set_resolution_no_resource(reason).into()
     } else {
-        let replica_map = generate_replica_map_for_topic(spus, param, None).await;
+        let mut new_partitions =
+            generate_replica_map_for_new_partitions(spus, param, current, None).await;
+        let mut replica_map = current.clone();
+        replica_map.append(&mut new_partitions);
         if !replica_map.is_empty() {
             (TopicStatus::next_resolution_provisioned(), replica_map).into()
         } else {
@@ -179,7 +195,8 @@ impl TopicNextState {
                     validate_computed_topic_parameters(param)
                 }
                 TopicResolution::Pending | TopicResolution::InsufficientResources => {
-                    let mut next_state = generate_replica_map(spu_store, param).await;
+                    let mut next_state =
+                        extend_replica_map(spu_store, param, &topic.status.replica_map).await;
                     if next_state.resolution == TopicResolution::Provisioned {
                         debug!(
                             "Topic: {} replica generate success, status is provisioned",
@@ -189,6 +206,18 @@ impl TopicNextState {
                     }
                     next_state
                 }
+                TopicResolution::Provisioned
+                    if topic.status.replica_map_cnt() < param.partitions =>
+                {
+                    debug!(
+                        "topic: {} adding partitions: {} to {}",
+                        topic.key(),
+                        topic.status.replica_map_cnt(),
+                        param.partitions
+                    );
+                    // partitions are created once new replica map is stored in status
+                    extend_replica_map(spu_store, param, &topic.status.replica_map).await
+                }
                 _ => {
                     debug!(
                         "topic: {} resolution: {:#?} ignoring",
@@ -240,15 +269,29 @@ pub async fn generate_replica_map_for_topic(
     param: &TopicReplicaParam,
     from_index: Option<i32>,
 ) -> ReplicaMap {
+    generate_replica_map_for_new_partitions(spus, param, &ReplicaMap::new(), from_index).await
+}
+
+///
+/// Generate replica map for partitions of topic which are not in current replica map
+///
+#[instrument(level = "trace", skip(spus, param, current, from_index))]
+pub async fn generate_replica_map_for_new_partitions(
+    spus: &SpuAdminStore,
+    param: &TopicReplicaParam,
+    current: &ReplicaMap,
+    from_index: Option<i32>,
+) -> ReplicaMap {
+    let first_partition = current.len() as i32;
     let in_rack_count = spus.spus_in_rack_count().await;
 
     let start_index = from_index.unwrap_or(-1);
 
     // generate partition map (with our without rack assignment)
     if param.ignore_rack_assignment || in_rack_count == 0 {
-        generate_partitions_without_rack(spus, param, start_index).await
+        generate_partitions_without_rack(spus, param, start_index, first_partition).await
     } else {
-        generate_partitions_with_rack_assignment(spus, param, start_index).await
+        generate_partitions_with_rack_assignment(spus, param, start_index, first_partition).await
     }
 }
 
@@ -259,6 +302,7 @@ async fn generate_partitions_with_rack_assignment(
     spus: &SpuAdminStore,
     param: &TopicReplicaParam,
     start_index: i32,
+    first_partition: i32,
 ) -> ReplicaMap {
     let mut partition_map = BTreeMap::new();
     let rack_map = SpuAdminStore::live_spu_rack_map_sorted(spus).await;
@@ -271,7 +315,7 @@ async fn generate_partitions_with_rack_assignment(
         thread_rng().gen_range(0..spu_cnt)
     };
 
-    for p_idx in 0..param.partitions {
+    for p_idx in first_partition..param.partitions {
         let mut replicas: Vec<i32> = vec![];
         for r_idx in 0..param.replication_factor {
             let spu_idx = ((s_idx + p_idx + r_idx) % spu_cnt) as usize;
@@ -290,6 +334,7 @@ async fn generate_partitions_without_rack(
     spus: &SpuAdminStore,
     param: &TopicReplicaParam,
     start_index: i32,
+    first_partition: i32,
 ) -> ReplicaMap {
     let mut partition_map = BTreeMap::new();
     let spu_cnt = spus.spu_used_for_replica().await;
@@ -302,7 +347,7 @@ async fn generate_partitions_without_rack(
     };
 
     let gap_max = spu_cnt - param.replication_factor + 1;
-    for p_idx in 0..param.partitions {
+    for p_idx in first_partition..param.partitions {
         let mut replicas: Vec<i32> = vec![];
         let gap_cnt = ((s_idx + p_idx) / spu_cnt) % gap_max;
         for r_idx in 0..param.replication_factor {
@@ -375,6 +420,37 @@ pub mod replica_map_test {
         Ok(())
     }
 
+    #[test_async]
+    async fn generate_replica_map_for_new_partitions_no_rack() -> Result<(), ()> {
+        let spus = SpuAdminStore::quick(vec![
+            (0, true, None),
+            (1, true, None),
+            (2, true, None),
+            (3, true, None),
+            (4, true, None),
+        ]);
+
+        // existing 2 partitions are kept, only partitions 2 and 3 are generated
+        let mut current = BTreeMap::new();
+        current.insert(0, vec![3, 4]);
+        current.insert(1, vec![4, 0]);
+
+        let param = (4, 2, false).into();
+        let new_map =
+            generate_replica_map_for_new_partitions(&spus, &param, &current, Some(3)).await;
+        let mut new_map_expected = BTreeMap::new();
+        new_map_expected.insert(2, vec![0, 2]);
+        new_map_expected.insert(3, vec![1, 3]);
+        assert_eq!(new_map, new_map_expected);
+
+        let next_state = extend_replica_map(&spus, &param, &current).await;
+        assert_eq!(next_state.resolution, TopicResolution::Provisioned);
+        assert_eq!(next_state.replica_map.len(), 4);
+        assert_eq!(next_state.replica_map.get(&0), Some(&vec![3, 4]));
+        assert_eq!(next_state.replica_map.get(&1), Some(&vec![4, 0]));
+        Ok(())
+    }
+
     #[test_async]
     async fn generate_replica_map_for_topic_3x_replicas_no_rack() -> Result<(), ()> {
         let spus = SpuAdminStore::quick(vec![
@@ -0,0 +1,228 @@
+//!
+//! # Audit Log
+//!
+//! Administrative actions are appended to file as one json record per line.
+//! If no file is configured, records are discarded.
+//!
+//! Records are written by single task which keeps file open, so callers never wait for file I/O.
+//! Most recent records are also kept in memory to answer queries, older records are only in file.
+//!
+use std::collections::VecDeque;
+use std::io::{Error as IoError, ErrorKind};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use async_channel::{unbounded, bounded, Receiver, Sender};
+use async_lock::RwLock;
+use futures_util::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use futures_util::stream::StreamExt;
+use tracing::{debug, error, info};
+
+use dataplane::now_timestamp;
+use fluvio_future::fs::{File, OpenOptions};
+use fluvio_future::task::spawn;
+use fluvio_sc_schema::audit::AuditRecord;
+
+/// max number of records kept in memory for queries
+const MAX_RECENT_RECORDS: usize = 10_000;
+
+enum AuditCommand {
+    Append(AuditRecord),
+    /// notify once all previous records are written
+    Sync(Sender<()>),
+}
+
+type RecentRecords = Arc<RwLock<VecDeque<AuditRecord>>>;
+
+#[derive(Debug)]
+pub struct AuditLog {
+    sender: Option<Sender<AuditCommand>>,
+    recent: RecentRecords,
+}
+
+impl AuditLog {
+    /// start writer task if path is configured
+    pub fn new(path: Option<PathBuf>) -> Self {
+        let recent = RecentRecords::default();
+        let sender = path.map(|path| {
+            let (sender, receiver) = unbounded();
+            let writer_recent = recent.clone();
+            spawn(async move {
+                if let Err(err) = write_records(&path, writer_recent, receiver).await {
+                    error!("audit log {:?} terminated: {}", path, err);
+                }
+            });
+            sender
+        });
+        Self { sender, recent }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.sender.is_some()
+    }
+
+    /// queue record to be appended, failure to write is logged but not returned
+    /// since it should not fail the admin request
+    pub fn record(&self, record: AuditRecord) {
+        if let Some(sender) = &self.sender {
+            debug!(?record, "audit");
+            if sender.try_send(AuditCommand::Append(record)).is_err() {
+                error!("audit log writer terminated, record discarded");
+            }
+        }
+    }
+
+    /// wait until records queued so far are written
+    pub async fn sync(&self) {
+        if let Some(sender) = &self.sender {
+            let (done, wait) = bounded(1);
+            if sender.send(AuditCommand::Sync(done)).await.is_ok() {
+                let _ = wait.recv().await;
+            }
+        }
+    }
+
+    /// recent records matching filter, ordered from oldest to newest.
+    /// Records written before recent ones are only available in file
+    pub async fn records<F>(&self, filter: F) -> Vec<AuditRecord>
+    where
+        F: Fn(&AuditRecord) -> bool,
+    {
+        self.sync().await;
+        self.recent
+            .read()
+            .await
+            .iter()
+            .filter(|record| filter(record))
+            .cloned()
+            .collect()
+    }
+}
+
+/// load recent records from file, then append queued records until audit log is dropped
+async fn write_records(
+    path: &PathBuf,
+    recent: RecentRecords,
+    receiver: Receiver<AuditCommand>,
+) -> Result<(), IoError> {
+    load_recent(path, &recent).await?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await?;
+    info!("writing audit log to {:?}", path);
+
+    while let Ok(command) = receiver.recv().await {
+        match command {
+            AuditCommand::Append(record) => {
+                let mut line = serde_json::to_string(&record)
+                    .map_err(|err| IoError::new(ErrorKind::InvalidData, err))?;
+                line.push('\n');
+                if let Err(err) = file.write_all(line.as_bytes()).await {
+                    error!("unable to write audit record to {:?}: {}", path, err);
+                }
+                push_recent(&mut *recent.write().await, record);
+            }
+            AuditCommand::Sync(done) => {
+                if let Err(err) = file.flush().await {
+                    error!("unable to flush audit log {:?}: {}", path, err);
+                }
+                let _ = done.try_send(());
+            }
+        }
+    }
+
+    debug!("audit log closed");
+    Ok(())
+}
+
+/// read records of existing file, lines which can't be parsed are skipped
+async fn load_recent(path: &PathBuf, recent: &RecentRecords) -> Result<(), IoError> {
+    // hold lock, so queries wait until existing records are loaded
+    let mut recent = recent.write().await;
+    let file = match File::open(path).await {
+        Ok(file) => file,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err),
+    };
+
+    let mut lines = BufReader::new(file).lines();
+    while let Some(line) = lines.next().await {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str(&line) {
+            Ok(record) => push_recent(&mut recent, record),
+            Err(err) => error!("invalid audit record: {}", err),
+        }
+    }
+    Ok(())
+}
+
+fn push_recent(recent: &mut VecDeque<AuditRecord>, record: AuditRecord) {
+    if recent.len() == MAX_RECENT_RECORDS {
+        recent.pop_front();
+    }
+    recent.push_back(record);
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::fs::remove_file;
+
+    use fluvio_future::test_async;
+
+    use super::*;
+
+    fn record(key: &str, success: bool) -> AuditRecord {
+        AuditRecord {
+            timestamp: now_timestamp(),
+            principal: "alice".to_owned(),
+            object_type: "Topic".to_owned(),
+            key: key.to_owned(),
+            action: "Create".to_owned(),
+            success,
+            message: None,
+        }
+    }
+
+    #[test_async]
+    async fn test_audit_log_append_read() -> Result<(), ()> {
+        let path = std::env::temp_dir().join("fluvio-sc-audit-test.log");
+        let _ = remove_file(&path);
+
+        let log = AuditLog::new(Some(path.clone()));
+        assert!(log.records(|_| true).await.is_empty());
+
+        log.record(record("test1", true));
+        log.record(record("test2", false));
+
+        let records = log.records(|_| true).await;
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].key, "test1");
+        assert!(!records[1].success);
+        assert_eq!(log.records(|record| record.success).await.len(), 1);
+        drop(log);
+
+        // records of existing file are loaded
+        let log = AuditLog::new(Some(path.clone()));
+        log.record(record("test3", true));
+        let records = log.records(|_| true).await;
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[2].key, "test3");
+
+        let _ = remove_file(&path);
+        Ok(())
+    }
+
+    #[test_async]
+    async fn test_audit_log_disabled() -> Result<(), ()> {
+        let log = AuditLog::new(None);
+        log.record(record("test1", true));
+        assert!(log.records(|_| true).await.is_empty());
+        Ok(())
+    }
+}
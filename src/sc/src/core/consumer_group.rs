@@ -0,0 +1,386 @@
+//!
+//! # Consumer Group Coordinator
+//!
+//! Tracks live members of consumer groups and assigns partitions of topic across them.
+//! Membership is kept in memory only. If SC restarts, members are told they are unknown
+//! and join again. Committed offsets are stored by SPU so they are not affected.
+//! Joining member reports highest generation it knows of, including generation offsets were
+//! last committed in, and group which is created again starts above it.
+//!
+//! Partition is not reassigned while its previous member may still consume it.
+//! Member gives up partitions by acknowledging generation of its new assignment in heartbeat.
+//!
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use async_lock::{Mutex as AsyncMutex, MutexGuard};
+use tracing::debug;
+
+use fluvio_sc_schema::consumer_group::GroupAssignment;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct GroupKey {
+    group: String,
+    topic: String,
+}
+
+impl GroupKey {
+    fn new(group: &str, topic: &str) -> Self {
+        Self {
+            group: group.to_owned(),
+            topic: topic.to_owned(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Member {
+    session_timeout: Duration,
+    last_heartbeat: Instant,
+    /// partitions member may still be consuming: partitions handed to it
+    /// which it has not given up by acknowledging newer generation
+    owned: Vec<i32>,
+}
+
+#[derive(Debug, Default)]
+struct GroupState {
+    generation: i32,
+    partition_count: i32,
+    members: BTreeMap<String, Member>,
+    assignments: BTreeMap<String, Vec<i32>>,
+}
+
+impl GroupState {
+    /// remove members which missed heartbeat, their partitions are free to be reassigned
+    fn expire_members(&mut self, now: Instant) {
+        self.members.retain(|member_id, member| {
+            let alive = now.duration_since(member.last_heartbeat) <= member.session_timeout;
+            if !alive {
+                debug!(%member_id, "consumer group member expired");
+            }
+            alive
+        });
+    }
+
+    /// assign partitions round robin across members ordered by id.
+    /// Partition still owned by another member is withheld until that member acknowledges
+    /// generation without it, so partition is revoked before it is reassigned.
+    /// Generation is incremented if any assignment has changed.
+    fn rebalance(&mut self) {
+        let member_ids: Vec<&String> = self.members.keys().collect();
+        let mut assignments: BTreeMap<String, Vec<i32>> = member_ids
+            .iter()
+            .map(|member_id| ((*member_id).clone(), vec![]))
+            .collect();
+        if !member_ids.is_empty() {
+            for partition in 0..self.partition_count {
+                let member_id = member_ids[partition as usize % member_ids.len()];
+                let owned_by_other = self
+                    .members
+                    .iter()
+                    .any(|(id, member)| id != member_id && member.owned.contains(&partition));
+                if owned_by_other {
+                    debug!(partition, %member_id, "partition not yet revoked from previous member");
+                } else if let Some(partitions) = assignments.get_mut(member_id) {
+                    partitions.push(partition);
+                }
+            }
+        }
+
+        if assignments != self.assignments {
+            self.generation += 1;
+            self.assignments = assignments;
+            debug!(
+                generation = self.generation,
+                assignments = ?self.assignments,
+                "consumer group rebalanced"
+            );
+        }
+    }
+
+    /// expire members and rebalance for current members and partitions
+    fn refresh(&mut self, partition_count: i32, now: Instant) {
+        self.expire_members(now);
+        self.partition_count = partition_count;
+        self.rebalance();
+    }
+
+    /// member has switched to assignment of generation.
+    /// Members which don't report generation are assumed to switch immediately
+    fn acknowledge(&mut self, member_id: &str, generation: Option<i32>) {
+        if generation.unwrap_or(self.generation) != self.generation {
+            return;
+        }
+        let partitions = self.assignments.get(member_id).cloned().unwrap_or_default();
+        if let Some(member) = self.members.get_mut(member_id) {
+            member.owned = partitions;
+        }
+    }
+
+    /// current assignment of member, member owns its partitions until it acknowledges newer generation
+    fn hand_out(&mut self, member_id: &str) -> GroupAssignment {
+        let partitions = self.assignments.get(member_id).cloned().unwrap_or_default();
+        if let Some(member) = self.members.get_mut(member_id) {
+            for partition in &partitions {
+                if !member.owned.contains(partition) {
+                    member.owned.push(*partition);
+                }
+            }
+        }
+        GroupAssignment {
+            generation: self.generation,
+            partitions,
+        }
+    }
+}
+
+/// Consumer groups of all topics
+#[derive(Debug, Default)]
+pub struct ConsumerGroups {
+    groups: Mutex<HashMap<GroupKey, GroupState>>,
+    offsets_topic: AsyncMutex<()>,
+}
+
+impl ConsumerGroups {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// held while internal offsets topic is checked and created
+    pub async fn offsets_topic_lock(&self) -> MutexGuard<'_, ()> {
+        self.offsets_topic.lock().await
+    }
+
+    /// add new member to group, return member id and its assignment.
+    /// Generation of group is raised to at least `min_generation` before member is added
+    pub fn join(
+        &self,
+        group: &str,
+        topic: &str,
+        partition_count: i32,
+        session_timeout: Duration,
+        min_generation: i32,
+    ) -> (String, GroupAssignment) {
+        let member_id = format!("{}-{:016x}", group, rand::random::<u64>());
+        let now = Instant::now();
+        let mut groups = self.groups.lock().unwrap();
+        let state = groups.entry(GroupKey::new(group, topic)).or_default();
+        state.generation = state.generation.max(min_generation);
+        state.members.insert(
+            member_id.clone(),
+            Member {
+                session_timeout,
+                last_heartbeat: now,
+                owned: vec![],
+            },
+        );
+        debug!(group, topic, %member_id, "consumer group member joined");
+        state.refresh(partition_count, now);
+        let assignment = state.hand_out(&member_id);
+        (member_id, assignment)
+    }
+
+    /// record heartbeat of member and generation it has switched to, return its current assignment.
+    /// None if member is not part of group
+    pub fn heartbeat(
+        &self,
+        group: &str,
+        topic: &str,
+        member_id: &str,
+        generation: Option<i32>,
+        partition_count: i32,
+    ) -> Option<GroupAssignment> {
+        let now = Instant::now();
+        let mut groups = self.groups.lock().unwrap();
+        let key = GroupKey::new(group, topic);
+        let state = groups.get_mut(&key)?;
+        state.expire_members(now);
+        let is_member = match state.members.get_mut(member_id) {
+            Some(member) => {
+                member.last_heartbeat = now;
+                true
+            }
+            None => false,
+        };
+        if is_member {
+            state.acknowledge(member_id, generation);
+        }
+        state.refresh(partition_count, now);
+        let assignment = if is_member {
+            Some(state.hand_out(member_id))
+        } else {
+            None
+        };
+        if state.members.is_empty() {
+            groups.remove(&key);
+        }
+        assignment
+    }
+
+    /// remove member from group, return false if member is not part of group
+    pub fn leave(&self, group: &str, topic: &str, member_id: &str) -> bool {
+        let mut groups = self.groups.lock().unwrap();
+        let key = GroupKey::new(group, topic);
+        let state = match groups.get_mut(&key) {
+            Some(state) => state,
+            None => return false,
+        };
+        if state.members.remove(member_id).is_none() {
+            return false;
+        }
+        debug!(group, topic, %member_id, "consumer group member left");
+        if state.members.is_empty() {
+            groups.remove(&key);
+        } else {
+            let partition_count = state.partition_count;
+            state.refresh(partition_count, Instant::now());
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use std::time::Duration;
+
+    use fluvio_sc_schema::consumer_group::GroupAssignment;
+
+    use super::ConsumerGroups;
+
+    const TIMEOUT: Duration = Duration::from_secs(60);
+
+    /// partitions of both members, sorted
+    fn combined(first: &GroupAssignment, second: &GroupAssignment) -> Vec<i32> {
+        let mut all: Vec<i32> = first
+            .partitions
+            .iter()
+            .chain(second.partitions.iter())
+            .copied()
+            .collect();
+        all.sort_unstable();
+        all
+    }
+
+    #[test]
+    fn test_group_assignment() {
+        let groups = ConsumerGroups::new();
+
+        let (member1, assignment) = groups.join("group", "topic", 3, TIMEOUT, 0);
+        assert_eq!(assignment.generation, 1);
+        assert_eq!(assignment.partitions, vec![0, 1, 2]);
+
+        // member2 only gets partitions after member1 has switched to generation 2
+        let (member2, assignment2) = groups.join("group", "topic", 3, TIMEOUT, 0);
+        assert_eq!(assignment2.generation, 2);
+        assert!(assignment2.partitions.is_empty());
+        let assignment1 = groups
+            .heartbeat("group", "topic", &member1, Some(1), 3)
+            .expect("member");
+        assert_eq!(assignment1.generation, 2);
+        assert!(!assignment1.partitions.is_empty());
+        assert!(assignment1.partitions.len() < 3);
+        let assignment2 = groups
+            .heartbeat("group", "topic", &member2, Some(2), 3)
+            .expect("member");
+        assert!(assignment2.partitions.is_empty());
+
+        let assignment1 = groups
+            .heartbeat("group", "topic", &member1, Some(2), 3)
+            .expect("member");
+        assert_eq!(assignment1.generation, 3);
+        let assignment2 = groups
+            .heartbeat("group", "topic", &member2, Some(2), 3)
+            .expect("member");
+        assert_eq!(assignment2.generation, 3);
+        assert_eq!(combined(&assignment1, &assignment2), vec![0, 1, 2]);
+
+        // group of other topic is independent
+        let (_, other) = groups.join("group", "other", 1, TIMEOUT, 0);
+        assert_eq!(other.generation, 1);
+
+        // added partition is not owned by anyone, so it is assigned right away
+        let assignment1 = groups
+            .heartbeat("group", "topic", &member1, Some(3), 4)
+            .expect("member");
+        assert_eq!(assignment1.generation, 4);
+        let assignment2 = groups
+            .heartbeat("group", "topic", &member2, Some(3), 4)
+            .expect("member");
+        assert_eq!(combined(&assignment1, &assignment2), vec![0, 1, 2, 3]);
+
+        // partitions of member which left are free
+        assert!(groups.leave("group", "topic", &member2));
+        assert!(!groups.leave("group", "topic", &member2));
+        let assignment1 = groups
+            .heartbeat("group", "topic", &member1, Some(4), 4)
+            .expect("member");
+        assert_eq!(assignment1.generation, 5);
+        assert_eq!(assignment1.partitions, vec![0, 1, 2, 3]);
+        assert!(groups
+            .heartbeat("group", "topic", &member2, Some(4), 4)
+            .is_none());
+    }
+
+    #[test]
+    fn test_group_without_generation() {
+        let groups = ConsumerGroups::new();
+
+        // members which don't report generation give up partitions on next heartbeat
+        let (member1, _) = groups.join("group", "topic", 2, TIMEOUT, 0);
+        let (member2, assignment2) = groups.join("group", "topic", 2, TIMEOUT, 0);
+        assert!(assignment2.partitions.is_empty());
+        let assignment1 = groups
+            .heartbeat("group", "topic", &member1, None, 2)
+            .expect("member");
+        let assignment2 = groups
+            .heartbeat("group", "topic", &member2, None, 2)
+            .expect("member");
+        assert_eq!(assignment2.partitions.len(), 1);
+        assert_eq!(combined(&assignment1, &assignment2), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_group_member_expired() {
+        let groups = ConsumerGroups::new();
+
+        // partitions of expired member are free
+        let (expired, _) = groups.join("group", "topic", 2, Duration::from_millis(0), 0);
+        std::thread::sleep(Duration::from_millis(5));
+        let (member, assignment) = groups.join("group", "topic", 2, TIMEOUT, 0);
+        assert_eq!(assignment.partitions, vec![0, 1]);
+
+        assert!(groups
+            .heartbeat("group", "topic", &expired, Some(1), 2)
+            .is_none());
+        assert!(groups
+            .heartbeat("group", "topic", &member, Some(2), 2)
+            .is_some());
+    }
+
+    #[test]
+    fn test_group_created_again() {
+        let groups = ConsumerGroups::new();
+
+        let (member, _) = groups.join("group", "topic", 2, TIMEOUT, 0);
+        let assignment = groups
+            .heartbeat("group", "topic", &member, Some(1), 3)
+            .expect("member");
+        let committed = assignment.generation;
+        assert_eq!(committed, 2);
+
+        // group is removed with its last member, and created again above committed generation
+        assert!(groups.leave("group", "topic", &member));
+        let (member, assignment) = groups.join("group", "topic", 3, TIMEOUT, committed);
+        assert_eq!(assignment.generation, committed + 1);
+        assert_eq!(assignment.partitions, vec![0, 1, 2]);
+
+        // lower generation reported by member doesn't lower generation of group
+        let (_, assignment) = groups.join("group", "topic", 3, TIMEOUT, 0);
+        assert_eq!(assignment.generation, committed + 2);
+        assert!(groups
+            .heartbeat("group", "topic", &member, Some(committed + 1), 3)
+            .is_some());
+    }
+}
@@ -10,8 +10,11 @@ use crate::stores::spu::*;
 use crate::stores::partition::*;
 use crate::stores::topic::*;
 use crate::stores::spg::*;
+use crate::stores::smartmodule::*;
 use crate::stores::*;
 use crate::controllers::spus::SpuStatusChannel;
+use super::ConsumerGroups;
+use super::AuditLog;
 
 pub type SharedContext = Arc<Context>;
 
@@ -23,7 +26,10 @@ pub struct Context {
     partitions: StoreContext<PartitionSpec>,
     topics: StoreContext<TopicSpec>,
     spgs: StoreContext<SpuGroupSpec>,
+    smartmodules: StoreContext<SmartModuleSpec>,
     health: SpuStatusChannel,
+    consumer_groups: ConsumerGroups,
+    audit: AuditLog,
     config: ScConfig,
 }
 
@@ -43,7 +49,10 @@ impl Context {
             partitions: StoreContext::new(),
             topics: StoreContext::new(),
             spgs: StoreContext::new(),
+            smartmodules: StoreContext::new(),
             health: SpuStatusChannel::new(),
+            consumer_groups: ConsumerGroups::new(),
+            audit: AuditLog::new(config.audit_log.clone()),
             config,
         }
     }
@@ -67,11 +76,26 @@ impl Context {
         &self.spgs
     }
 
+    /// reference to smartmodules
+    pub fn smartmodules(&self) -> &StoreContext<SmartModuleSpec> {
+        &self.smartmodules
+    }
+
     /// spu health channel
     pub fn health(&self) -> &SpuStatusChannel {
         &self.health
     }
 
+    /// consumer group coordinator
+    pub fn consumer_groups(&self) -> &ConsumerGroups {
+        &self.consumer_groups
+    }
+
+    /// audit log of admin actions
+    pub fn audit(&self) -> &AuditLog {
+        &self.audit
+    }
+
     /// reference to config
     pub fn config(&self) -> &ScConfig {
         &self.config
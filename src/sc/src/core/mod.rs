@@ -1,3 +1,7 @@
 mod context;
+mod consumer_group;
+mod audit;
 pub mod common;
 pub use self::context::*;
+pub use self::consumer_group::ConsumerGroups;
+pub use self::audit::AuditLog;
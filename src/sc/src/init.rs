@@ -5,6 +5,8 @@
 //! and receivers.
 //!
 
+use std::io::Error as IoError;
+
 use tracing::info;
 use k8_metadata_client::SharedClient;
 use k8_metadata_client::MetadataClient;
@@ -17,6 +19,7 @@ use crate::controllers::partitions::PartitionController;
 use crate::config::{ScConfig};
 use crate::services::start_internal_server;
 use crate::dispatcher::dispatcher::K8ClusterStateDispatcher;
+use crate::dispatcher::dispatcher::{LocalClusterStateDispatcher, LocalMetadataStorage};
 use crate::services::auth::basic::BasicRbacPolicy;
 
 /// start the main loop
@@ -31,13 +34,13 @@ where
     use crate::stores::topic::TopicSpec;
     use crate::stores::partition::PartitionSpec;
     use crate::stores::spg::SpuGroupSpec;
+    use crate::stores::smartmodule::SmartModuleSpec;
     info!("SC Platform Version: {}", &*crate::VERSION);
 
     let (sc_config, auth_policy) = sc_config_policy;
 
     let namespace = sc_config.namespace.clone();
     let ctx = Context::shared_metadata(sc_config);
-    let config = ctx.config();
 
     K8ClusterStateDispatcher::<SpuSpec, C>::start(
         namespace.clone(),
@@ -58,11 +61,80 @@ where
     );
 
     K8ClusterStateDispatcher::<SpuGroupSpec, C>::start(
+        namespace.clone(),
+        metadata_client.clone(),
+        ctx.spgs().clone(),
+    );
+
+    K8ClusterStateDispatcher::<SmartModuleSpec, C>::start(
         namespace,
         metadata_client,
-        ctx.spgs().clone(),
+        ctx.smartmodules().clone(),
     );
 
+    start_services(ctx, auth_policy)
+}
+
+/// start the main loop with metadata persisted in local directory instead of Kubernetes
+pub async fn start_local_main_loop(
+    sc_config_policy: (ScConfig, Option<BasicRbacPolicy>),
+    storage: LocalMetadataStorage,
+) -> Result<SharedContext, IoError> {
+    use crate::stores::spu::SpuSpec;
+    use crate::stores::topic::TopicSpec;
+    use crate::stores::partition::PartitionSpec;
+    use crate::stores::spg::SpuGroupSpec;
+    use crate::stores::smartmodule::SmartModuleSpec;
+    info!("SC Platform Version: {}", &*crate::VERSION);
+    info!("using local metadata: {:?}", storage.base_dir());
+
+    let (sc_config, auth_policy) = sc_config_policy;
+
+    let namespace = sc_config.namespace.clone();
+    let ctx = Context::shared_metadata(sc_config);
+
+    LocalClusterStateDispatcher::<SpuSpec>::start(
+        namespace.clone(),
+        storage.clone(),
+        ctx.spus().clone(),
+    )
+    .await?;
+
+    LocalClusterStateDispatcher::<TopicSpec>::start(
+        namespace.clone(),
+        storage.clone(),
+        ctx.topics().clone(),
+    )
+    .await?;
+
+    LocalClusterStateDispatcher::<PartitionSpec>::start(
+        namespace.clone(),
+        storage.clone(),
+        ctx.partitions().clone(),
+    )
+    .await?;
+
+    LocalClusterStateDispatcher::<SpuGroupSpec>::start(
+        namespace.clone(),
+        storage.clone(),
+        ctx.spgs().clone(),
+    )
+    .await?;
+
+    LocalClusterStateDispatcher::<SmartModuleSpec>::start(
+        namespace,
+        storage,
+        ctx.smartmodules().clone(),
+    )
+    .await?;
+
+    Ok(start_services(ctx, auth_policy))
+}
+
+/// start controllers and services
+fn start_services(ctx: SharedContext, auth_policy: Option<BasicRbacPolicy>) -> SharedContext {
+    let config = ctx.config();
+
     whitelist!(config, "spu", SpuController::start(ctx.clone()));
     whitelist!(config, "topic", TopicController::start(ctx.clone()));
     whitelist!(config, "partition", PartitionController::start(ctx.clone()));
@@ -84,9 +156,21 @@ where
 
         use crate::services::auth::{AuthGlobalContext, RootAuthorization};
         use crate::services::auth::basic::{BasicAuthorization, BasicRbacPolicy};
+        use crate::services::auth::token::TokenAuthorization;
 
         pub fn start(ctx: SharedContext, auth_policy_option: Option<BasicRbacPolicy>) {
-            if let Some(policy) = auth_policy_option {
+            if let Some(credentials) = ctx.config().credentials.clone() {
+                let policy = auth_policy_option.unwrap_or_default();
+                let mut authorization = TokenAuthorization::new(credentials, policy);
+                // clients with certificate authenticated by proxy don't need credentials
+                if ctx.config().x509_auth_scopes.is_some() {
+                    info!("using x509 and token authorization");
+                    authorization = authorization.with_x509_identity();
+                } else {
+                    info!("using token authorization");
+                }
+                start_public_server(AuthGlobalContext::new(ctx, Arc::new(authorization)));
+            } else if let Some(policy) = auth_policy_option {
                 info!("using basic authorization");
                 start_public_server(AuthGlobalContext::new(
                     ctx,
@@ -2,6 +2,7 @@
 pub mod config;
 #[cfg(feature = "k8")]
 pub mod k8;
+pub mod local;
 pub mod cli;
 pub mod core;
 
@@ -10,8 +11,9 @@ mod init;
 mod error;
 mod services;
 mod controllers;
+mod proxy;
 
-pub use init::start_main_loop;
+pub use init::{start_main_loop, start_local_main_loop};
 
 const VERSION: &str = include_str!("../../../VERSION");
 
@@ -0,0 +1,53 @@
+//!
+//! # Streaming Coordinator (SC) without Kubernetes
+//!
+//! Metadata is persisted in local directory, so a single node cluster can run
+//! without Kubernetes API server.
+//!
+use std::process;
+use std::time::Duration;
+
+use fluvio_future::task::run_block_on;
+use fluvio_future::timer::sleep;
+use fluvio_types::print_cli_err;
+
+use crate::cli::ScOpt;
+use crate::dispatcher::dispatcher::LocalMetadataStorage;
+use crate::init::start_local_main_loop;
+use crate::proxy;
+
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub fn main_local_loop(opt: ScOpt) {
+    let metadata_dir = opt
+        .local_metadata()
+        .cloned()
+        .expect("local metadata directory");
+
+    // parse configuration (program exits on error)
+    let ((sc_config, auth_policy), tls_option) = opt.parse_local_cli_or_exit();
+
+    println!("starting sc server with local metadata: {}", VERSION);
+
+    run_block_on(async move {
+        let storage = LocalMetadataStorage::new(metadata_dir);
+        if let Err(err) = start_local_main_loop((sc_config.clone(), auth_policy), storage).await {
+            print_cli_err!(err);
+            process::exit(-1);
+        }
+
+        if let Some((proxy_port, tls_config)) = tls_option {
+            let tls_acceptor = tls_config
+                .try_build_tls_acceptor()
+                .expect("can't build tls acceptor");
+            proxy::start_proxy(sc_config, (tls_acceptor, proxy_port)).await;
+        }
+
+        println!("Streaming Controller started successfully");
+
+        // do inifinite loop
+        loop {
+            sleep(Duration::from_secs(60)).await;
+        }
+    });
+}
@@ -0,0 +1,31 @@
+//!
+//! # TLS proxy for public service
+//!
+use std::process;
+use tracing::info;
+
+use fluvio_types::print_cli_err;
+pub use fluvio_future::openssl::TlsAcceptor;
+
+use fluvio_auth::x509::X509Authenticator;
+use flv_tls_proxy::{start as proxy_start, start_with_authenticator as proxy_start_with_authenticator};
+
+use crate::config::ScConfig;
+
+pub async fn start_proxy(config: ScConfig, acceptor: (TlsAcceptor, String)) {
+    let (tls_acceptor, proxy_addr) = acceptor;
+    let target = config.public_endpoint;
+    info!("starting TLS proxy: {}", proxy_addr);
+
+    let result = if let Some(x509_auth_scopes) = config.x509_auth_scopes {
+        let authenticator = Box::new(X509Authenticator::new(&x509_auth_scopes));
+        proxy_start_with_authenticator(&proxy_addr, tls_acceptor, target, authenticator).await
+    } else {
+        proxy_start(&proxy_addr, tls_acceptor, target).await
+    };
+
+    if let Err(err) = result {
+        print_cli_err!(err);
+        process::exit(-1);
+    }
+}
@@ -1,17 +1,23 @@
-pub mod basic;
+pub mod basic {
+    pub use fluvio_auth::basic::*;
+}
+
+pub mod token {
+    pub use fluvio_auth::token::*;
+}
 
 pub use common::*;
+pub use fluvio_auth::{RootAuthorization, RootAuthContext};
 
 mod common {
 
     use std::sync::Arc;
     use std::fmt::Debug;
 
-    use async_trait::async_trait;
-
-    use fluvio_auth::{AuthContext, Authorization, TypeAction, InstanceAction, AuthError};
-    use fluvio_socket::FluvioSocket;
-    use fluvio_controlplane_metadata::extended::ObjectType;
+    use dataplane::core::{Encoder, Decoder};
+    use fluvio_auth::{AuthContext, AuthError, InstanceAction, TypeAction};
+    use fluvio_controlplane_metadata::extended::SpecExt;
+    use fluvio_sc_schema::objects::Metadata;
 
     use crate::core::SharedContext;
 
@@ -29,53 +35,6 @@ mod common {
         }
     }
 
-    /// Authorization that allows anything
-    /// Used for personal development
-    #[derive(Debug, Clone)]
-    pub struct RootAuthorization {}
-
-    #[async_trait]
-    impl Authorization for RootAuthorization {
-        type Context = RootAuthContext;
-
-        async fn create_auth_context(
-            &self,
-            _socket: &mut FluvioSocket,
-        ) -> Result<Self::Context, AuthError> {
-            Ok(RootAuthContext {})
-        }
-    }
-
-    impl RootAuthorization {
-        pub fn new() -> Self {
-            Self {}
-        }
-    }
-
-    #[derive(Debug)]
-    pub struct RootAuthContext {}
-
-    #[async_trait]
-    impl AuthContext for RootAuthContext {
-        async fn allow_type_action(
-            &self,
-            _ty: ObjectType,
-            _action: TypeAction,
-        ) -> Result<bool, AuthError> {
-            Ok(true)
-        }
-
-        /// check if specific instance of spec can be deleted
-        async fn allow_instance_action(
-            &self,
-            _ty: ObjectType,
-            _action: InstanceAction,
-            _key: &str,
-        ) -> Result<bool, AuthError> {
-            Ok(true)
-        }
-    }
-
     /// Auth Service Context, this hold individual context that is enough enforce auth
     /// for this service context
     #[derive(Debug, Clone)]
@@ -89,4 +48,40 @@ mod common {
             Self { global_ctx, auth }
         }
     }
+
+    impl<AC> AuthServiceContext<AC>
+    where
+        AC: AuthContext,
+    {
+        /// remove objects which caller is not allowed to read.
+        /// all objects are kept if read is allowed for object type
+        pub async fn filter_readable<S>(
+            &self,
+            objects: Vec<Metadata<S>>,
+        ) -> Result<Vec<Metadata<S>>, AuthError>
+        where
+            S: SpecExt + Debug + Encoder + Decoder,
+            S::Status: Debug + Encoder + Decoder,
+        {
+            if self
+                .auth
+                .allow_type_action(S::OBJECT_TYPE, TypeAction::Read)
+                .await?
+            {
+                return Ok(objects);
+            }
+
+            let mut readable = vec![];
+            for object in objects {
+                if self
+                    .auth
+                    .allow_instance_action(S::OBJECT_TYPE, InstanceAction::Read, &object.name)
+                    .await?
+                {
+                    readable.push(object);
+                }
+            }
+            Ok(readable)
+        }
+    }
 }
@@ -18,14 +18,16 @@ use fluvio_service::{FlvService, wait_for_request};
 use fluvio_socket::{FluvioSocket, SocketError, FluvioSink};
 use fluvio_controlplane::{
     InternalScRequest, InternalScKey, RegisterSpuResponse, UpdateLrsRequest, UpdateReplicaRequest,
-    UpdateSpuRequest, ReplicaRemovedRequest,
+    UpdateSpuRequest, ReplicaRemovedRequest, UpdateSmartModuleRequest,
 };
-use fluvio_controlplane_metadata::message::{ReplicaMsg, Message, SpuMsg};
+use fluvio_controlplane_metadata::message::{ReplicaMsg, Message, SpuMsg, SmartModuleMsg};
+use fluvio_controlplane_metadata::smartmodule::SmartModule;
 
 use crate::core::SharedContext;
 use crate::stores::{K8ChangeListener};
 use crate::stores::partition::{PartitionSpec, PartitionStatus, PartitionResolution};
 use crate::stores::spu::SpuSpec;
+use crate::stores::smartmodule::SmartModuleSpec;
 use crate::controllers::spus::SpuAction;
 use crate::stores::actions::WSAction;
 
@@ -131,6 +133,7 @@ async fn dispatch_loop(
 
     let mut spu_spec_listener = context.spus().change_listener();
     let mut partition_spec_listener = context.partitions().change_listener();
+    let mut smartmodule_listener = context.smartmodules().change_listener();
 
     loop {
         use tokio::select;
@@ -146,6 +149,7 @@ async fn dispatch_loop(
 
         send_spu_spec_changes(&mut spu_spec_listener, &mut sink, spu_id).await?;
         send_replica_spec_changes(&mut partition_spec_listener, &mut sink, spu_id).await?;
+        send_smartmodule_changes(&mut smartmodule_listener, &mut sink, spu_id).await?;
 
         trace!("waiting for events");
 
@@ -203,6 +207,10 @@ async fn dispatch_loop(
 
             _ = partition_spec_listener.listen() => {
                 debug!("partition spec changed");
+            },
+
+            _ = smartmodule_listener.listen() => {
+                debug!("smartmodule changed");
             }
 
         }
@@ -394,3 +402,55 @@ async fn send_replica_spec_changes(
     sink.send_request(&message).await?;
     Ok(())
 }
+
+/// send smartmodule changes, SPUs need them to resolve SmartStreams referenced by name
+#[instrument(skip(sink))]
+async fn send_smartmodule_changes(
+    listener: &mut K8ChangeListener<SmartModuleSpec>,
+    sink: &mut FluvioSink,
+    spu_id: SpuId,
+) -> Result<(), SocketError> {
+    if !listener.has_change() {
+        debug!("changes is empty, skipping");
+        return Ok(());
+    }
+
+    let changes = listener.sync_spec_changes().await;
+    if changes.is_empty() {
+        debug!("spec changes is empty, skipping");
+        return Ok(());
+    }
+
+    let epoch = changes.epoch;
+    let is_sync_all = changes.is_sync_all();
+    let (updates, deletes) = changes.parts();
+    let request = if is_sync_all {
+        UpdateSmartModuleRequest::with_all(
+            epoch,
+            updates.into_iter().map(SmartModule::from).collect(),
+        )
+    } else {
+        let mut changes: Vec<SmartModuleMsg> = updates
+            .into_iter()
+            .map(|v| Message::update(v.into()))
+            .collect();
+        let mut deletes = deletes
+            .into_iter()
+            .map(|d| Message::delete(d.into()))
+            .collect();
+        changes.append(&mut deletes);
+        UpdateSmartModuleRequest::with_changes(epoch, changes)
+    };
+
+    let mut message = RequestMessage::new_request(request);
+    message.get_mut_header().set_client_id("sc");
+
+    debug!(
+        "sending smartmodules to spu: {}, all: {}, changes: {}",
+        spu_id,
+        message.request.all.len(),
+        message.request.changes.len()
+    );
+    sink.send_request(&message).await?;
+    Ok(())
+}
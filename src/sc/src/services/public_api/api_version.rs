@@ -4,6 +4,9 @@ use tracing::{trace, instrument};
 use dataplane::api::{RequestMessage, ResponseMessage, Request};
 use dataplane::versions::{ApiVersionKey, ApiVersionsRequest, ApiVersionsResponse, PlatformVersion};
 use fluvio_sc_schema::objects::*;
+use fluvio_sc_schema::consumer_group::{JoinGroupRequest, GroupHeartbeatRequest, LeaveGroupRequest};
+use fluvio_sc_schema::election::ElectLeadersRequest;
+use fluvio_sc_schema::audit::AuditLogRequest;
 use fluvio_sc_schema::AdminPublicApiKey;
 
 #[instrument(skip(request))]
@@ -19,7 +22,7 @@ pub async fn handle_api_versions_request(
     // topic versions
     response.api_keys.push(make_version_key(
         AdminPublicApiKey::Create,
-        CreateRequest::DEFAULT_API_VERSION,
+        0,
         CreateRequest::DEFAULT_API_VERSION,
     ));
     response.api_keys.push(make_version_key(
@@ -29,13 +32,47 @@ pub async fn handle_api_versions_request(
     ));
     response.api_keys.push(make_version_key(
         AdminPublicApiKey::List,
-        ListRequest::DEFAULT_API_VERSION,
+        0,
         ListRequest::DEFAULT_API_VERSION,
     ));
     response.api_keys.push(make_version_key(
         AdminPublicApiKey::Watch,
+        0,
         WatchRequest::DEFAULT_API_VERSION,
-        WatchRequest::DEFAULT_API_VERSION,
+    ));
+    response.api_keys.push(make_version_key(
+        AdminPublicApiKey::Update,
+        UpdateRequest::DEFAULT_API_VERSION,
+        UpdateRequest::DEFAULT_API_VERSION,
+    ));
+
+    // consumer group versions
+    response.api_keys.push(make_version_key(
+        AdminPublicApiKey::JoinGroup,
+        0,
+        JoinGroupRequest::DEFAULT_API_VERSION,
+    ));
+    response.api_keys.push(make_version_key(
+        AdminPublicApiKey::GroupHeartbeat,
+        0,
+        GroupHeartbeatRequest::DEFAULT_API_VERSION,
+    ));
+    response.api_keys.push(make_version_key(
+        AdminPublicApiKey::LeaveGroup,
+        LeaveGroupRequest::DEFAULT_API_VERSION,
+        LeaveGroupRequest::DEFAULT_API_VERSION,
+    ));
+
+    // leader election versions
+    response.api_keys.push(make_version_key(
+        AdminPublicApiKey::ElectLeaders,
+        ElectLeadersRequest::DEFAULT_API_VERSION,
+        ElectLeadersRequest::DEFAULT_API_VERSION,
+    ));
+    response.api_keys.push(make_version_key(
+        AdminPublicApiKey::AuditLog,
+        AuditLogRequest::DEFAULT_API_VERSION,
+        AuditLogRequest::DEFAULT_API_VERSION,
     ));
 
     trace!("flv api versions response: {:#?}", response);
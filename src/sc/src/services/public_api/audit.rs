@@ -0,0 +1,130 @@
+//!
+//! # Audit Log Request
+//!
+//! Records admin actions and returns recorded actions which caller is allowed to read.
+//!
+use std::io::Error as IoError;
+
+use tracing::{trace, instrument};
+
+use dataplane::now_timestamp;
+use dataplane::api::{RequestMessage, ResponseMessage};
+use fluvio_sc_schema::Status;
+use fluvio_sc_schema::audit::{AuditLogRequest, AuditLogResponse, AuditRecord};
+use fluvio_controlplane_metadata::extended::ObjectType;
+use fluvio_auth::{AuthContext, TypeAction, InstanceAction};
+
+use crate::core::AuditLog;
+use crate::services::auth::AuthServiceContext;
+
+/// record result of admin action on object
+pub fn audit_status<AC: AuthContext>(
+    auth_ctx: &AuthServiceContext<AC>,
+    ty: ObjectType,
+    action: &str,
+    status: &Status,
+) {
+    audit_action(
+        auth_ctx,
+        ty,
+        action,
+        &status.name,
+        status.error_code.is_ok(),
+        status.error_message.clone(),
+    );
+}
+
+/// record admin action on object
+pub fn audit_action<AC: AuthContext>(
+    auth_ctx: &AuthServiceContext<AC>,
+    ty: ObjectType,
+    action: &str,
+    key: &str,
+    success: bool,
+    message: Option<String>,
+) {
+    let audit = auth_ctx.global_ctx.audit();
+    if !audit.is_enabled() {
+        return;
+    }
+
+    audit.record(AuditRecord {
+        timestamp: now_timestamp(),
+        principal: auth_ctx.auth.principal().unwrap_or_default().to_owned(),
+        object_type: format!("{:?}", ty),
+        key: key.to_owned(),
+        action: action.to_owned(),
+        success,
+        message,
+    });
+}
+
+fn object_type(name: &str) -> Option<ObjectType> {
+    match name {
+        "Spu" => Some(ObjectType::Spu),
+        "CustomSpu" => Some(ObjectType::CustomSpu),
+        "SpuGroup" => Some(ObjectType::SpuGroup),
+        "Topic" => Some(ObjectType::Topic),
+        "Partition" => Some(ObjectType::Partition),
+        "SmartModule" => Some(ObjectType::SmartModule),
+        _ => None,
+    }
+}
+
+/// check if caller can read object referred by record
+async fn is_readable<AC: AuthContext>(
+    auth_ctx: &AuthServiceContext<AC>,
+    record: &AuditRecord,
+) -> Result<bool, IoError> {
+    let ty = match object_type(&record.object_type) {
+        Some(ty) => ty,
+        None => return Ok(false),
+    };
+
+    if auth_ctx
+        .auth
+        .allow_type_action(ty.clone(), TypeAction::Read)
+        .await?
+    {
+        return Ok(true);
+    }
+
+    Ok(auth_ctx
+        .auth
+        .allow_instance_action(ty, InstanceAction::Read, &record.key)
+        .await?)
+}
+
+/// Handler for audit log request
+#[instrument(skip(request, auth_ctx))]
+pub async fn handle_audit_log_request<AC: AuthContext>(
+    request: RequestMessage<AuditLogRequest>,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<ResponseMessage<AuditLogResponse>, IoError> {
+    let (header, req) = request.get_header_request();
+
+    let mut records = vec![];
+    for record in auth_ctx
+        .global_ctx
+        .audit()
+        .records(|record| req.matches(record))
+        .await
+    {
+        if is_readable(auth_ctx, &record).await? {
+            records.push(record);
+        }
+    }
+
+    // keep newest records
+    let max_records = req.max_records as usize;
+    if max_records > 0 && records.len() > max_records {
+        records.drain(0..records.len() - max_records);
+    }
+
+    trace!("audit log resp: {} records", records.len());
+
+    Ok(ResponseMessage::from_header(
+        &header,
+        AuditLogResponse { records },
+    ))
+}
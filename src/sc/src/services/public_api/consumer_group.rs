@@ -0,0 +1,202 @@
+use std::io::{Error as IoError, ErrorKind};
+use std::time::Duration;
+
+use tracing::{debug, instrument};
+
+use dataplane::ErrorCode;
+use dataplane::api::{RequestMessage, ResponseMessage};
+use fluvio_types::defaults::{
+    CONSUMER_OFFSETS_TOPIC, CONSUMER_OFFSETS_PARTITIONS, CONSUMER_OFFSETS_REPLICATION,
+};
+use fluvio_sc_schema::Status;
+use fluvio_sc_schema::consumer_group::{
+    JoinGroupRequest, JoinGroupResponse, GroupHeartbeatRequest, GroupHeartbeatResponse,
+    LeaveGroupRequest, LeaveGroupResponse,
+};
+use fluvio_controlplane_metadata::topic::{TopicSpec, CleanupPolicy, CompactionPolicy};
+use fluvio_controlplane_metadata::topic::config::CLEANUP_POLICY;
+use fluvio_auth::{AuthContext, InstanceAction};
+use fluvio_controlplane_metadata::extended::SpecExt;
+
+use crate::core::Context;
+use crate::stores::spu::SpuLocalStorePolicy;
+use crate::services::auth::AuthServiceContext;
+
+use super::topic::process_topic_request;
+
+/// number of partitions of topic, none if topic doesn't exist
+async fn partition_count(ctx: &Context, topic: &str) -> Option<i32> {
+    ctx.topics()
+        .store()
+        .value(topic)
+        .await
+        .map(|topic| topic.status().replica_map_cnt())
+}
+
+/// members of group consume topic, so they must be allowed to consume it
+async fn allow_consume<AC: AuthContext>(
+    auth_ctx: &AuthServiceContext<AC>,
+    topic: &str,
+) -> Result<bool, IoError> {
+    auth_ctx
+        .auth
+        .allow_instance_action(TopicSpec::OBJECT_TYPE, InstanceAction::Consume, topic)
+        .await
+        .map_err(|_| IoError::new(ErrorKind::Interrupted, "authorization io error"))
+}
+
+/// create internal topic storing committed offsets, unless it already exists.
+/// Topic is created by SC itself with fixed config, regardless of permissions of member.
+/// It is compacted, so only latest commit of each group partition is retained
+async fn ensure_offsets_topic(ctx: &Context) -> Status {
+    let name = CONSUMER_OFFSETS_TOPIC.to_owned();
+
+    // members joining at the same time must not race to create topic
+    let _guard = ctx.consumer_groups().offsets_topic_lock().await;
+    if let Some(topic) = ctx.topics().store().value(CONSUMER_OFFSETS_TOPIC).await {
+        return validate_offsets_topic(&topic.spec);
+    }
+
+    let online_spus = ctx.spus().store().online_spu_count().await;
+    let replication = CONSUMER_OFFSETS_REPLICATION.min(online_spus).max(1);
+    let mut spec = TopicSpec::new_computed(CONSUMER_OFFSETS_PARTITIONS, replication, None);
+    let compact = CleanupPolicy::Compact(CompactionPolicy::default());
+    if let Err(reason) = spec.set_config(CLEANUP_POLICY, compact.label()) {
+        return Status::new(name, ErrorCode::TopicError, Some(reason));
+    }
+    debug!(replication, "creating consumer offsets topic");
+    process_topic_request(ctx, name, spec).await
+}
+
+/// offsets of group are kept in partition chosen by hash of group,
+/// so topic must have expected partitions and retain latest commit of each key
+fn validate_offsets_topic(spec: &TopicSpec) -> Status {
+    let name = CONSUMER_OFFSETS_TOPIC.to_owned();
+    if spec.partitions() != CONSUMER_OFFSETS_PARTITIONS {
+        return Status::new(
+            name,
+            ErrorCode::TopicInvalidConfiguration,
+            Some(format!(
+                "consumer offsets topic must have {} partitions",
+                CONSUMER_OFFSETS_PARTITIONS
+            )),
+        );
+    }
+    if !matches!(
+        spec.config().cleanup_policy(),
+        Some(CleanupPolicy::Compact(_))
+    ) {
+        return Status::new(
+            name,
+            ErrorCode::TopicInvalidConfiguration,
+            Some("consumer offsets topic must be compacted".to_owned()),
+        );
+    }
+    Status::new_ok(name)
+}
+
+#[instrument(skip(request, auth_ctx))]
+pub async fn handle_join_group_request<AC: AuthContext>(
+    request: RequestMessage<JoinGroupRequest>,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<ResponseMessage<JoinGroupResponse>, IoError> {
+    let (header, req) = request.get_header_request();
+    let ctx = &auth_ctx.global_ctx;
+
+    let mut response = JoinGroupResponse::default();
+    if !allow_consume(auth_ctx, &req.topic).await? {
+        debug!(topic = %req.topic, "join consumer group is not authorized");
+        response.error_code = ErrorCode::PermissionDenied;
+        return Ok(ResponseMessage::from_header(&header, response));
+    }
+
+    let offsets_topic = ensure_offsets_topic(ctx).await;
+    if offsets_topic.is_error() {
+        debug!(?offsets_topic, "consumer offsets topic not provisioned");
+        response.error_code = offsets_topic.error_code;
+        return Ok(ResponseMessage::from_header(&header, response));
+    }
+
+    match partition_count(ctx, &req.topic).await {
+        Some(count) => {
+            let (member_id, assignment) = ctx.consumer_groups().join(
+                &req.group,
+                &req.topic,
+                count,
+                Duration::from_millis(req.session_timeout_ms as u64),
+                req.min_generation,
+            );
+            response.member_id = member_id;
+            response.assignment = assignment;
+        }
+        None => {
+            debug!(topic = %req.topic, "consumer group topic not found");
+            response.error_code = ErrorCode::TopicNotFound;
+        }
+    }
+
+    Ok(ResponseMessage::from_header(&header, response))
+}
+
+#[instrument(skip(request, auth_ctx))]
+pub async fn handle_group_heartbeat_request<AC: AuthContext>(
+    request: RequestMessage<GroupHeartbeatRequest>,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<ResponseMessage<GroupHeartbeatResponse>, IoError> {
+    let (header, req) = request.get_header_request();
+    let ctx = &auth_ctx.global_ctx;
+
+    // members before version 1 don't report generation
+    let generation = if header.api_version() >= 1 {
+        Some(req.generation)
+    } else {
+        None
+    };
+
+    let mut response = GroupHeartbeatResponse::default();
+    if !allow_consume(auth_ctx, &req.topic).await? {
+        debug!(topic = %req.topic, "consumer group heartbeat is not authorized");
+        response.error_code = ErrorCode::PermissionDenied;
+        return Ok(ResponseMessage::from_header(&header, response));
+    }
+
+    match partition_count(ctx, &req.topic).await {
+        Some(count) => {
+            match ctx.consumer_groups().heartbeat(
+                &req.group,
+                &req.topic,
+                &req.member_id,
+                generation,
+                count,
+            ) {
+                Some(assignment) => response.assignment = assignment,
+                None => response.error_code = ErrorCode::UnknownMemberId,
+            }
+        }
+        None => response.error_code = ErrorCode::TopicNotFound,
+    }
+
+    Ok(ResponseMessage::from_header(&header, response))
+}
+
+#[instrument(skip(request, auth_ctx))]
+pub async fn handle_leave_group_request<AC: AuthContext>(
+    request: RequestMessage<LeaveGroupRequest>,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<ResponseMessage<LeaveGroupResponse>, IoError> {
+    let (header, req) = request.get_header_request();
+
+    let mut response = LeaveGroupResponse::default();
+    if !allow_consume(auth_ctx, &req.topic).await? {
+        debug!(topic = %req.topic, "leave consumer group is not authorized");
+        response.error_code = ErrorCode::PermissionDenied;
+    } else if !auth_ctx
+        .global_ctx
+        .consumer_groups()
+        .leave(&req.group, &req.topic, &req.member_id)
+    {
+        response.error_code = ErrorCode::UnknownMemberId;
+    }
+
+    Ok(ResponseMessage::from_header(&header, response))
+}
@@ -5,9 +5,11 @@ use tracing::instrument;
 use dataplane::api::{RequestMessage, ResponseMessage};
 use fluvio_sc_schema::Status;
 use fluvio_sc_schema::objects::{CreateRequest, AllCreatableSpec};
+use fluvio_controlplane_metadata::extended::ObjectType;
 use fluvio_auth::AuthContext;
 
 use crate::services::auth::AuthServiceContext;
+use super::audit::audit_status;
 
 /// Handler for create topic request
 #[instrument(skip(request, auth_context))]
@@ -20,23 +22,40 @@ pub async fn handle_create_request<AC: AuthContext>(
     let dry_run = req.dry_run;
     let name = req.name;
 
-    let status = match req.spec {
-        AllCreatableSpec::Topic(topic) => {
-            super::topic::handle_create_topics_request(name, dry_run, topic, auth_context).await?
-        }
-        AllCreatableSpec::SpuGroup(group) => {
-            super::spg::handle_create_spu_group_request(name, group, dry_run, auth_context).await?
-        }
-        AllCreatableSpec::CustomSpu(custom) => {
+    let (ty, status) = match req.spec {
+        AllCreatableSpec::Topic(topic) => (
+            ObjectType::Topic,
+            super::topic::handle_create_topics_request(name, dry_run, topic, auth_context).await?,
+        ),
+        AllCreatableSpec::SpuGroup(group) => (
+            ObjectType::SpuGroup,
+            super::spg::handle_create_spu_group_request(name, group, dry_run, auth_context).await?,
+        ),
+        AllCreatableSpec::CustomSpu(custom) => (
+            ObjectType::CustomSpu,
             super::spu::RegisterCustomSpu::handle_register_custom_spu_request(
                 name,
                 custom,
                 dry_run,
                 auth_context,
             )
-            .await
-        }
+            .await,
+        ),
+        AllCreatableSpec::SmartModule(smartmodule) => (
+            ObjectType::SmartModule,
+            super::smartmodule::handle_create_smartmodule_request(
+                name,
+                smartmodule,
+                dry_run,
+                auth_context,
+            )
+            .await?,
+        ),
     };
 
+    if !dry_run {
+        audit_status(auth_context, ty, "Create", &status);
+    }
+
     Ok(ResponseMessage::from_header(&header, status))
 }
@@ -10,9 +10,11 @@ use std::io::Error;
 use dataplane::api::{RequestMessage, ResponseMessage};
 use fluvio_sc_schema::Status;
 use fluvio_sc_schema::objects::{DeleteRequest};
+use fluvio_controlplane_metadata::extended::ObjectType;
 use fluvio_auth::{AuthContext};
 
 use crate::services::auth::AuthServiceContext;
+use super::audit::audit_status;
 
 /// Handler for delete topic request
 #[instrument(skip(request, auth_ctx))]
@@ -22,16 +24,27 @@ pub async fn handle_delete_request<AC: AuthContext>(
 ) -> Result<ResponseMessage<Status>, Error> {
     let (header, req) = request.get_header_request();
 
-    let status = match req {
-        DeleteRequest::Topic(name) => super::topic::handle_delete_topic(name, auth_ctx).await?,
-        DeleteRequest::CustomSpu(key) => {
-            super::spu::handle_un_register_custom_spu_request(key, auth_ctx).await?
-        }
-        DeleteRequest::SpuGroup(name) => {
-            super::spg::handle_delete_spu_group(name, auth_ctx).await?
-        }
+    let (ty, status) = match req {
+        DeleteRequest::Topic(name) => (
+            ObjectType::Topic,
+            super::topic::handle_delete_topic(name, auth_ctx).await?,
+        ),
+        DeleteRequest::CustomSpu(key) => (
+            ObjectType::CustomSpu,
+            super::spu::handle_un_register_custom_spu_request(key, auth_ctx).await?,
+        ),
+        DeleteRequest::SpuGroup(name) => (
+            ObjectType::SpuGroup,
+            super::spg::handle_delete_spu_group(name, auth_ctx).await?,
+        ),
+        DeleteRequest::SmartModule(name) => (
+            ObjectType::SmartModule,
+            super::smartmodule::handle_delete_smartmodule(name, auth_ctx).await?,
+        ),
     };
 
+    audit_status(auth_ctx, ty, "Delete", &status);
+
     trace!("flv delete topics resp {:#?}", status);
 
     Ok(ResponseMessage::from_header(&header, status))
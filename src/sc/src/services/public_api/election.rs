@@ -0,0 +1,104 @@
+//!
+//! # Elect Leaders Request
+//!
+//! Moves leadership of partitions back to their preferred replica.
+//!
+use std::io::Error as IoError;
+
+use tracing::{debug, trace, instrument};
+
+use dataplane::api::{RequestMessage, ResponseMessage};
+use fluvio_sc_schema::election::{ElectLeadersRequest, ElectLeadersResponse, ElectionResult};
+use fluvio_controlplane_metadata::partition::PartitionSpec;
+use fluvio_controlplane_metadata::extended::SpecExt;
+use fluvio_auth::{AuthContext, InstanceAction};
+
+use crate::controllers::partitions::preferred_leader_candidate;
+use crate::services::auth::AuthServiceContext;
+use crate::stores::spu::SpuLocalStorePolicy;
+use super::audit::audit_action;
+
+/// Handler for elect leaders request
+#[instrument(skip(request, auth_ctx))]
+pub async fn handle_elect_leaders_request<AC: AuthContext>(
+    request: RequestMessage<ElectLeadersRequest>,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<ResponseMessage<ElectLeadersResponse>, IoError> {
+    let (header, req) = request.get_header_request();
+    let ctx = &auth_ctx.global_ctx;
+
+    let online = ctx.spus().store().online_status().await;
+    let partitions: Vec<_> = ctx
+        .partitions()
+        .store()
+        .read()
+        .await
+        .values()
+        .map(|partition| partition.inner().clone())
+        .filter(|partition| req.topics.is_empty() || req.topics.contains(&partition.key.topic))
+        .collect();
+
+    let mut response = ElectLeadersResponse::default();
+    for partition in partitions {
+        let name = partition.key.to_string();
+        let preferred = match partition.spec.preferred_leader() {
+            Some(preferred) if preferred != partition.spec.leader => preferred,
+            _ => continue,
+        };
+
+        match auth_ctx
+            .auth
+            .allow_instance_action(PartitionSpec::OBJECT_TYPE, InstanceAction::Update, &name)
+            .await
+        {
+            Ok(true) => {}
+            Ok(false) => {
+                trace!(%name, "authorization failed");
+                audit_action(
+                    auth_ctx,
+                    PartitionSpec::OBJECT_TYPE,
+                    "ElectLeader",
+                    &name,
+                    false,
+                    Some("permission denied".to_owned()),
+                );
+                response.results.push(ElectionResult::failed(
+                    name,
+                    preferred,
+                    "permission denied".to_owned(),
+                ));
+                continue;
+            }
+            Err(err) => return Err(err.into()),
+        }
+
+        let result = match preferred_leader_candidate(&partition, &online) {
+            Ok(Some(leader)) => {
+                let mut spec = partition.spec.clone();
+                spec.leader = leader;
+                match ctx
+                    .partitions()
+                    .create_spec(partition.key.clone(), spec)
+                    .await
+                {
+                    Ok(_) => ElectionResult::elected(name, leader),
+                    Err(err) => ElectionResult::failed(name, leader, err.to_string()),
+                }
+            }
+            Ok(None) => continue,
+            Err(reason) => ElectionResult::failed(name, preferred, reason),
+        };
+        debug!(?result, "preferred leader election");
+        audit_action(
+            auth_ctx,
+            PartitionSpec::OBJECT_TYPE,
+            "ElectLeader",
+            &result.partition,
+            result.error_code.is_ok(),
+            result.error_message.clone(),
+        );
+        response.results.push(result);
+    }
+
+    Ok(ResponseMessage::from_header(&header, response))
+}
@@ -29,6 +29,9 @@ pub async fn handle_list_request<AC: AuthContext>(
         ListRequest::Partition(filter) => {
             super::partition::handle_fetch_request(filter, auth_ctx).await?
         }
+        ListRequest::SmartModule(filter) => {
+            super::smartmodule::handle_fetch_smartmodules_request(filter, auth_ctx).await?
+        }
     };
 
     Ok(ResponseMessage::from_header(&header, response))
@@ -1,13 +1,18 @@
 mod public_server;
 mod spg;
+mod smartmodule;
 mod spu;
 mod topic;
 mod partition;
 mod api_version;
 mod create;
 mod delete;
+mod update;
 mod list;
 mod watch;
+mod consumer_group;
+mod election;
+mod audit;
 
 pub use server::start_public_server;
 
@@ -28,7 +33,7 @@ mod server {
     where
         A: Authorization + Sync + Send + Debug + 'static,
         AuthGlobalContext<A>: Clone + Debug,
-        <A as Authorization>::Context: Send + Sync,
+        <A as Authorization>::Context: Send + Sync + 'static,
     {
         let addr = ctx.global_ctx.config().public_endpoint.clone();
         debug!("starting public api service");
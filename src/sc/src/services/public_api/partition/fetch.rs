@@ -0,0 +1,37 @@
+use std::io::{Error, ErrorKind};
+
+use tracing::{trace, debug, instrument};
+
+use fluvio_sc_schema::objects::{ListResponse, Metadata};
+use fluvio_sc_schema::partition::{PartitionSpec};
+use fluvio_auth::AuthContext;
+
+use crate::services::auth::AuthServiceContext;
+
+#[instrument(skip(_filters, auth_ctx))]
+pub async fn handle_fetch_request<AC: AuthContext>(
+    _filters: Vec<String>,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<ListResponse, Error> {
+    debug!("fetching custom spu list");
+
+    let partitions: Vec<Metadata<PartitionSpec>> = auth_ctx
+        .global_ctx
+        .partitions()
+        .store()
+        .read()
+        .await
+        .values()
+        .map(|value| value.inner().clone().into())
+        .collect();
+
+    let partitions = auth_ctx
+        .filter_readable(partitions)
+        .await
+        .map_err(|_| Error::new(ErrorKind::Interrupted, "authorization io error"))?;
+
+    debug!("flv fetch partitions resp: {} items", partitions.len());
+    trace!("flv fetch partitions resp {:#?}", partitions);
+
+    Ok(ListResponse::Partition(partitions))
+}
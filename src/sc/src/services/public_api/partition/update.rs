@@ -0,0 +1,131 @@
+//!
+//! # Update Partition Request
+//!
+//! Starts reassignment of partition to new replicas.
+//! Partition controller moves replicas once new followers have caught up with leader.
+//!
+use std::convert::TryFrom;
+use std::collections::HashSet;
+use std::io::{Error, ErrorKind};
+
+use tracing::{debug, trace, instrument};
+
+use dataplane::ErrorCode;
+use fluvio_sc_schema::Status;
+use fluvio_sc_schema::objects::PartitionUpdate;
+use fluvio_controlplane_metadata::partition::{PartitionSpec, ReplicaKey};
+use fluvio_controlplane_metadata::extended::SpecExt;
+use fluvio_auth::{AuthContext, InstanceAction};
+use fluvio_types::SpuId;
+
+use crate::services::auth::AuthServiceContext;
+use crate::stores::spu::SpuLocalStorePolicy;
+
+/// Handler for update partition request
+#[instrument(skip(name, update, auth_ctx))]
+pub async fn handle_update_partition<AC: AuthContext>(
+    name: String,
+    update: PartitionUpdate,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<Status, Error> {
+    debug!("api request: update partition '{}': {:?}", name, update);
+
+    if let Ok(authorized) = auth_ctx
+        .auth
+        .allow_instance_action(PartitionSpec::OBJECT_TYPE, InstanceAction::Update, &name)
+        .await
+    {
+        if !authorized {
+            trace!("authorization failed");
+            return Ok(Status::new(
+                name,
+                ErrorCode::PermissionDenied,
+                Some(String::from("permission denied")),
+            ));
+        }
+    } else {
+        return Err(Error::new(ErrorKind::Interrupted, "authorization io error"));
+    }
+
+    let key = match ReplicaKey::try_from(name.clone()) {
+        Ok(key) => key,
+        Err(err) => {
+            return Ok(Status::new(
+                name,
+                ErrorCode::PartitionError,
+                Some(err.to_string()),
+            ))
+        }
+    };
+
+    let partition = match auth_ctx.global_ctx.partitions().store().value(&key).await {
+        Some(partition) => partition,
+        None => {
+            return Ok(Status::new(
+                name,
+                ErrorCode::PartitionError,
+                Some("not found".to_owned()),
+            ))
+        }
+    };
+
+    if let Err(reason) = validate_replicas(&update.replicas, &partition.spec, auth_ctx).await {
+        debug!(%reason, "invalid partition update");
+        return Ok(Status::new(name, ErrorCode::PartitionError, Some(reason)));
+    }
+
+    if update.replicas == partition.spec.replicas {
+        debug!("partition already has replicas, nothing to do");
+        return Ok(Status::new_ok(name));
+    }
+
+    let spec = partition.spec.clone().with_target_replicas(update.replicas);
+    let status = if let Err(err) = auth_ctx
+        .global_ctx
+        .partitions()
+        .create_spec(key, spec)
+        .await
+    {
+        Status::new(
+            name.clone(),
+            ErrorCode::PartitionError,
+            Some(err.to_string()),
+        )
+    } else {
+        Status::new_ok(name.clone())
+    };
+
+    trace!("flv update partition resp {:#?}", status);
+
+    Ok(status)
+}
+
+/// replicas must be unique and registered SPU
+async fn validate_replicas<AC: AuthContext>(
+    replicas: &[SpuId],
+    current: &PartitionSpec,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<(), String> {
+    if current.is_reassigning() {
+        return Err(format!(
+            "reassignment to {:?} is in progress",
+            current.target_replicas
+        ));
+    }
+
+    if replicas.is_empty() {
+        return Err("replicas can't be empty".to_owned());
+    }
+
+    let unique: HashSet<&SpuId> = replicas.iter().collect();
+    if unique.len() != replicas.len() {
+        return Err(format!("duplicate SPU in replicas: {:?}", replicas));
+    }
+
+    let spu_ids = auth_ctx.global_ctx.spus().store().spu_ids().await;
+    if let Some(missing) = replicas.iter().find(|spu| !spu_ids.contains(spu)) {
+        return Err(format!("invalid spu id: {}", missing));
+    }
+
+    Ok(())
+}
@@ -42,7 +42,7 @@ impl<A> PublicService<A> {
 impl<A> FlvService for PublicService<A>
 where
     A: Authorization + Sync + Send,
-    <A as Authorization>::Context: Send + Sync,
+    <A as Authorization>::Context: Send + Sync + 'static,
 {
     type Context = AuthGlobalContext<A>;
     type Request = AdminPublicRequest;
@@ -96,6 +96,13 @@ where
                 "delete  handler"
             ),
 
+            AdminPublicRequest::UpdateRequest(request) => call_service!(
+                request,
+                super::update::handle_update_request(request, &service_context),
+                shared_sink,
+                "update handler"
+            ),
+
             AdminPublicRequest::ListRequest(request) => call_service!(
                 request,
                 super::list::handle_list_request(request, &service_context),
@@ -106,10 +113,41 @@ where
 
                 super::watch::handle_watch_request(
                     request,
-                    &service_context,
+                    service_context.clone(),
                     shared_sink.clone(),
                     end_event.clone(),
-                )
+                ),
+
+            AdminPublicRequest::JoinGroupRequest(request) => call_service!(
+                request,
+                super::consumer_group::handle_join_group_request(request, &service_context),
+                shared_sink,
+                "join group handler"
+            ),
+            AdminPublicRequest::GroupHeartbeatRequest(request) => call_service!(
+                request,
+                super::consumer_group::handle_group_heartbeat_request(request, &service_context),
+                shared_sink,
+                "group heartbeat handler"
+            ),
+            AdminPublicRequest::LeaveGroupRequest(request) => call_service!(
+                request,
+                super::consumer_group::handle_leave_group_request(request, &service_context),
+                shared_sink,
+                "leave group handler"
+            ),
+            AdminPublicRequest::ElectLeadersRequest(request) => call_service!(
+                request,
+                super::election::handle_elect_leaders_request(request, &service_context),
+                shared_sink,
+                "elect leaders handler"
+            ),
+            AdminPublicRequest::AuditLogRequest(request) => call_service!(
+                request,
+                super::audit::handle_audit_log_request(request, &service_context),
+                shared_sink,
+                "audit log handler"
+            )
 
         );
 
@@ -0,0 +1,126 @@
+//!
+//! # Create SmartModule Request
+//!
+//! Validates the WASM module, computes its checksum and stores it in the KV store.
+//!
+
+use std::io::{Error, ErrorKind};
+
+use tracing::{debug, trace, instrument};
+use sha2::{Sha256, Digest};
+
+use dataplane::ErrorCode;
+use fluvio_sc_schema::Status;
+use fluvio_controlplane_metadata::smartmodule::SmartModuleSpec;
+use fluvio_controlplane_metadata::extended::SpecExt;
+use fluvio_auth::{AuthContext, TypeAction};
+
+use crate::core::Context;
+use crate::services::auth::AuthServiceContext;
+
+/// every WASM binary starts with this preamble
+const WASM_MAGIC: &[u8] = b"\0asm";
+
+/// Handler for create smartmodule request
+#[instrument(skip(name, spec, dry_run, auth_ctx))]
+pub async fn handle_create_smartmodule_request<AC: AuthContext>(
+    name: String,
+    spec: SmartModuleSpec,
+    dry_run: bool,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<Status, Error> {
+    debug!(
+        "creating smartmodule: {}, kind: {}, size: {}",
+        name,
+        spec.kind,
+        spec.wasm.len()
+    );
+
+    if let Ok(authorized) = auth_ctx
+        .auth
+        .allow_type_action(SmartModuleSpec::OBJECT_TYPE, TypeAction::Create)
+        .await
+    {
+        if !authorized {
+            trace!("authorization failed");
+            return Ok(Status::new(
+                name.clone(),
+                ErrorCode::PermissionDenied,
+                Some(String::from("permission denied")),
+            ));
+        }
+    } else {
+        return Err(Error::new(ErrorKind::Interrupted, "authorization io error"));
+    }
+
+    let status = validate_smartmodule_request(&name, &spec, &auth_ctx.global_ctx).await;
+    if status.is_error() || dry_run {
+        return Ok(status);
+    }
+
+    let status = process_smartmodule_request(&auth_ctx.global_ctx, name, spec).await;
+    trace!("create smartmodule response {:#?}", status);
+
+    Ok(status)
+}
+
+async fn validate_smartmodule_request(name: &str, spec: &SmartModuleSpec, ctx: &Context) -> Status {
+    if ctx.smartmodules().store().contains_key(name).await {
+        debug!("smartmodule already exists");
+        return Status::new(
+            name.to_string(),
+            ErrorCode::SmartModuleAlreadyExists,
+            Some(format!("smartmodule '{}' already defined", name)),
+        );
+    }
+
+    if !spec.wasm.starts_with(WASM_MAGIC) {
+        debug!("invalid wasm module");
+        return Status::new(
+            name.to_string(),
+            ErrorCode::SmartModuleError,
+            Some("not a valid WASM module".to_owned()),
+        );
+    }
+
+    Status::new_ok(name.to_string())
+}
+
+/// Fill in checksum and send spec to KV store
+#[instrument(skip(ctx, name, spec))]
+async fn process_smartmodule_request(
+    ctx: &Context,
+    name: String,
+    mut spec: SmartModuleSpec,
+) -> Status {
+    spec.checksum = wasm_checksum(&spec.wasm);
+
+    if let Err(err) = ctx.smartmodules().create_spec(name.clone(), spec).await {
+        let error = Some(err.to_string());
+        Status::new(name, ErrorCode::SmartModuleError, error)
+    } else {
+        Status::new_ok(name)
+    }
+}
+
+/// hex encoded sha256 of WASM module
+fn wasm_checksum(wasm: &[u8]) -> String {
+    Sha256::digest(wasm)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::wasm_checksum;
+
+    #[test]
+    fn test_wasm_checksum() {
+        assert_eq!(
+            wasm_checksum(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+}
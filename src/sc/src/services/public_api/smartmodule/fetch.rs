@@ -0,0 +1,44 @@
+use std::io::{Error, ErrorKind};
+
+use tracing::{debug, trace, instrument};
+
+use fluvio_sc_schema::objects::{ListResponse, NameFilter, Metadata};
+use fluvio_sc_schema::smartmodule::SmartModuleSpec;
+use fluvio_auth::AuthContext;
+use fluvio_controlplane_metadata::store::KeyFilter;
+
+use crate::services::auth::AuthServiceContext;
+
+#[instrument(skip(filters, auth_ctx))]
+pub async fn handle_fetch_smartmodules_request<AC: AuthContext>(
+    filters: Vec<NameFilter>,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<ListResponse, Error> {
+    debug!("fetching smartmodules");
+
+    let smartmodules: Vec<Metadata<SmartModuleSpec>> = auth_ctx
+        .global_ctx
+        .smartmodules()
+        .store()
+        .read()
+        .await
+        .values()
+        .filter_map(|value| {
+            if filters.filter(value.key()) {
+                Some(value.inner().clone().into())
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    let smartmodules = auth_ctx
+        .filter_readable(smartmodules)
+        .await
+        .map_err(|_| Error::new(ErrorKind::Interrupted, "authorization io error"))?;
+
+    debug!("flv fetch smartmodules resp: {} items", smartmodules.len());
+    trace!("flv fetch smartmodules resp {:#?}", smartmodules);
+
+    Ok(ListResponse::SmartModule(smartmodules))
+}
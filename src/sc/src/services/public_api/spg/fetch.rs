@@ -4,9 +4,8 @@ use tracing::{debug, trace, instrument};
 
 use fluvio_sc_schema::objects::{ListResponse, NameFilter, Metadata};
 use fluvio_sc_schema::spg::SpuGroupSpec;
-use fluvio_auth::{AuthContext, TypeAction};
+use fluvio_auth::AuthContext;
 use fluvio_controlplane_metadata::store::KeyFilter;
-use fluvio_controlplane_metadata::extended::SpecExt;
 
 use crate::services::auth::AuthServiceContext;
 
@@ -17,20 +16,6 @@ pub async fn handle_fetch_spu_groups_request<AC: AuthContext>(
 ) -> Result<ListResponse, Error> {
     debug!("fetching spu groups");
 
-    if let Ok(authorized) = auth_ctx
-        .auth
-        .allow_type_action(SpuGroupSpec::OBJECT_TYPE, TypeAction::Read)
-        .await
-    {
-        if !authorized {
-            trace!("authorization failed");
-            // If permission denied, return empty list;
-            return Ok(ListResponse::SpuGroup(vec![]));
-        }
-    } else {
-        return Err(Error::new(ErrorKind::Interrupted, "authorization io error"));
-    }
-
     let spgs: Vec<Metadata<SpuGroupSpec>> = auth_ctx
         .global_ctx
         .spgs()
@@ -47,6 +32,11 @@ pub async fn handle_fetch_spu_groups_request<AC: AuthContext>(
         })
         .collect();
 
+    let spgs = auth_ctx
+        .filter_readable(spgs)
+        .await
+        .map_err(|_| Error::new(ErrorKind::Interrupted, "authorization io error"))?;
+
     debug!("flv fetch spgs resp: {} items", spgs.len());
     trace!("flv fetch spgs resp {:#?}", spgs);
 
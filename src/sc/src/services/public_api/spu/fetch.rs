@@ -5,9 +5,8 @@ use tracing::{trace, debug, instrument};
 use fluvio_sc_schema::objects::{ListResponse, Metadata};
 use fluvio_sc_schema::spu::SpuSpec;
 use fluvio_sc_schema::spu::CustomSpuSpec;
-use fluvio_auth::{AuthContext, TypeAction};
+use fluvio_auth::AuthContext;
 use fluvio_controlplane_metadata::store::KeyFilter;
-use fluvio_controlplane_metadata::extended::SpecExt;
 
 use crate::services::auth::AuthServiceContext;
 
@@ -18,21 +17,7 @@ pub async fn handle_fetch_custom_spu_request<AC: AuthContext>(
 ) -> Result<ListResponse, Error> {
     debug!("fetching custom spu list");
 
-    if let Ok(authorized) = auth_ctx
-        .auth
-        .allow_type_action(CustomSpuSpec::OBJECT_TYPE, TypeAction::Read)
-        .await
-    {
-        if !authorized {
-            trace!("authorization failed");
-            // If permission denied, return empty list;
-            return Ok(ListResponse::CustomSpu(vec![]));
-        }
-    } else {
-        return Err(Error::new(ErrorKind::Interrupted, "authorization io error"));
-    }
-
-    let custom_spus: Vec<_> = auth_ctx
+    let custom_spus: Vec<Metadata<CustomSpuSpec>> = auth_ctx
         .global_ctx
         .spus()
         .store()
@@ -53,6 +38,11 @@ pub async fn handle_fetch_custom_spu_request<AC: AuthContext>(
         })
         .collect();
 
+    let custom_spus = auth_ctx
+        .filter_readable(custom_spus)
+        .await
+        .map_err(|_| Error::new(ErrorKind::Interrupted, "authorization io error"))?;
+
     debug!("flv fetch custom resp: {} items", custom_spus.len());
     trace!("flv fetch custom spus resp {:#?}", custom_spus);
 
@@ -66,18 +56,6 @@ pub async fn handle_fetch_spus_request<AC: AuthContext>(
 ) -> Result<ListResponse, Error> {
     debug!("fetching spu list");
 
-    if let Ok(authorized) = auth_ctx
-        .auth
-        .allow_type_action(SpuSpec::OBJECT_TYPE, TypeAction::Read)
-        .await
-    {
-        if !authorized {
-            trace!("authorization failed");
-            // If permission denied, return empty list;
-            return Ok(ListResponse::Spu(vec![]));
-        }
-    }
-
     let spus: Vec<Metadata<SpuSpec>> = auth_ctx
         .global_ctx
         .spus()
@@ -94,6 +72,11 @@ pub async fn handle_fetch_spus_request<AC: AuthContext>(
         })
         .collect();
 
+    let spus = auth_ctx
+        .filter_readable(spus)
+        .await
+        .map_err(|_| Error::new(ErrorKind::Interrupted, "authorization io error"))?;
+
     debug!("fetched {} spu items", spus.len());
     trace!("fetch spus items detail: {:#?}", spus);
 
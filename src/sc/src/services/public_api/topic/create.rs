@@ -19,6 +19,7 @@ use fluvio_sc_schema::Status;
 use fluvio_controlplane_metadata::topic::TopicSpec;
 use fluvio_auth::{AuthContext, TypeAction};
 use fluvio_controlplane_metadata::extended::SpecExt;
+use fluvio_types::defaults::CONSUMER_OFFSETS_TOPIC;
 
 use crate::core::Context;
 use crate::controllers::topics::generate_replica_map;
@@ -57,13 +58,23 @@ pub async fn handle_create_topics_request<AC: AuthContext>(
         ));
     }
 
+    // internal topics are only created by SC
+    if name == CONSUMER_OFFSETS_TOPIC {
+        debug!("internal topic can't be created");
+        return Ok(Status::new(
+            name.clone(),
+            ErrorCode::PermissionDenied,
+            Some(format!("'{}' is an internal topic", name)),
+        ));
+    }
+
     // validate topic request
     let mut status = validate_topic_request(&name, &topic_spec, &auth_ctx.global_ctx).await;
     if status.is_error() {
         return Ok(status);
     }
     if !dry_run {
-        status = process_topic_request(auth_ctx, name, topic_spec).await;
+        status = process_topic_request(&auth_ctx.global_ctx, name, topic_spec).await;
     }
 
     trace!("create topics request response {:#?}", status);
@@ -87,6 +98,31 @@ async fn validate_topic_request(name: &str, topic_spec: &TopicSpec, metadata: &C
         );
     }
 
+    if let Err(reason) = topic_spec.config().validate() {
+        debug!(%reason, "invalid topic config");
+        return Status::new(name.to_string(), ErrorCode::TopicError, Some(reason));
+    }
+
+    if let (Some(min_isr), Some(replication)) = (
+        topic_spec.config().min_in_sync_replicas(),
+        topic_spec.replication_factor(),
+    ) {
+        if min_isr as i32 > replication {
+            debug!(
+                min_isr,
+                replication, "min in sync replicas exceeds replication"
+            );
+            return Status::new(
+                name.to_string(),
+                ErrorCode::TopicInvalidConfiguration,
+                Some(format!(
+                    "min.insync.replicas: {} can't be greater than replication factor: {}",
+                    min_isr, replication
+                )),
+            );
+        }
+    }
+
     match topic_spec {
         TopicSpec::Computed(param) => {
             let next_state = validate_computed_topic_parameters(param);
@@ -138,9 +174,10 @@ async fn validate_topic_request(name: &str, topic_spec: &TopicSpec, metadata: &C
 }
 
 /// create new topic and wait until all partitions are fully provisioned
-/// if any partitions are not provisioned in time, this will generate error
-async fn process_topic_request<AC: AuthContext>(
-    auth_ctx: &AuthServiceContext<AC>,
+/// if any partitions are not provisioned in time, this will generate error.
+/// Caller must be authorized, SC also uses it to create internal topics
+pub(crate) async fn process_topic_request(
+    ctx: &Context,
     name: String,
     topic_spec: TopicSpec,
 ) -> Status {
@@ -157,12 +194,7 @@ async fn process_topic_request<AC: AuthContext>(
         wait_time
     });
 
-    let topic_instance = match auth_ctx
-        .global_ctx
-        .topics()
-        .create_spec(name.clone(), topic_spec)
-        .await
-    {
+    let topic_instance = match ctx.topics().create_spec(name.clone(), topic_spec).await {
         Ok(instance) => instance,
         Err(err) => {
             return Status::new(
@@ -180,7 +212,7 @@ async fn process_topic_request<AC: AuthContext>(
     );
     let topic_uid = &topic_instance.ctx().item().uid;
 
-    let partition_ctx = auth_ctx.global_ctx.partitions();
+    let partition_ctx = ctx.partitions();
     let mut partition_listener = partition_ctx.change_listener();
     let mut timer = sleep(Duration::from_secs(*MAX_WAIT_TIME));
 
@@ -12,6 +12,7 @@ use fluvio_sc_schema::Status;
 use fluvio_controlplane_metadata::topic::TopicSpec;
 use fluvio_auth::{AuthContext, InstanceAction};
 use fluvio_controlplane_metadata::extended::SpecExt;
+use fluvio_types::defaults::CONSUMER_OFFSETS_TOPIC;
 
 use crate::services::auth::AuthServiceContext;
 
@@ -40,6 +41,15 @@ pub async fn handle_delete_topic<AC: AuthContext>(
         return Err(Error::new(ErrorKind::Interrupted, "authorization io error"));
     }
 
+    if topic_name == CONSUMER_OFFSETS_TOPIC {
+        debug!("internal topic can't be deleted");
+        return Ok(Status::new(
+            topic_name.clone(),
+            ErrorCode::PermissionDenied,
+            Some(format!("'{}' is an internal topic", topic_name)),
+        ));
+    }
+
     let status = if auth_ctx
         .global_ctx
         .topics()
@@ -4,8 +4,7 @@ use std::io::{Error, ErrorKind};
 use fluvio_controlplane_metadata::store::KeyFilter;
 use fluvio_sc_schema::objects::{ListResponse, Metadata};
 use fluvio_sc_schema::topic::TopicSpec;
-use fluvio_auth::{AuthContext, TypeAction};
-use fluvio_controlplane_metadata::extended::SpecExt;
+use fluvio_auth::AuthContext;
 
 use crate::services::auth::AuthServiceContext;
 
@@ -16,19 +15,6 @@ pub async fn handle_fetch_topics_request<AC: AuthContext>(
 ) -> Result<ListResponse, Error> {
     debug!("retrieving topic list: {:#?}", filters);
 
-    if let Ok(authorized) = auth_ctx
-        .auth
-        .allow_type_action(TopicSpec::OBJECT_TYPE, TypeAction::Read)
-        .await
-    {
-        if !authorized {
-            trace!("authorization failed");
-            return Ok(ListResponse::Topic(vec![]));
-        }
-    } else {
-        return Err(Error::new(ErrorKind::Interrupted, "authorization io error"));
-    }
-
     let topics: Vec<Metadata<TopicSpec>> = auth_ctx
         .global_ctx
         .topics()
@@ -45,6 +31,11 @@ pub async fn handle_fetch_topics_request<AC: AuthContext>(
         })
         .collect();
 
+    let topics = auth_ctx
+        .filter_readable(topics)
+        .await
+        .map_err(|_| Error::new(ErrorKind::Interrupted, "authorization io error"))?;
+
     debug!("flv fetch topics resp: {} items", topics.len());
     trace!("flv fetch topics resp {:#?}", topics);
 
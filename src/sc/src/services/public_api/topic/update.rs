@@ -0,0 +1,96 @@
+//!
+//! # Update Topic Request
+//!
+//! Update topic request handler. Partitions can only be added to computed topic,
+//! new partitions are allocated by topic controller while existing partitions are unchanged.
+//!
+use tracing::{debug, trace, instrument};
+use std::io::{Error, ErrorKind};
+
+use dataplane::ErrorCode;
+use fluvio_sc_schema::Status;
+use fluvio_sc_schema::objects::TopicUpdate;
+use fluvio_controlplane_metadata::topic::TopicSpec;
+use fluvio_auth::{AuthContext, InstanceAction};
+use fluvio_controlplane_metadata::extended::SpecExt;
+use fluvio_types::defaults::CONSUMER_OFFSETS_TOPIC;
+
+use crate::services::auth::AuthServiceContext;
+
+/// Handler for update topic request
+#[instrument(skip(topic_name, update, auth_ctx))]
+pub async fn handle_update_topic<AC: AuthContext>(
+    topic_name: String,
+    update: TopicUpdate,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<Status, Error> {
+    debug!("api request: update topic '{}': {:?}", topic_name, update);
+
+    if let Ok(authorized) = auth_ctx
+        .auth
+        .allow_instance_action(TopicSpec::OBJECT_TYPE, InstanceAction::Update, &topic_name)
+        .await
+    {
+        if !authorized {
+            trace!("authorization failed");
+            return Ok(Status::new(
+                topic_name.clone(),
+                ErrorCode::PermissionDenied,
+                Some(String::from("permission denied")),
+            ));
+        }
+    } else {
+        return Err(Error::new(ErrorKind::Interrupted, "authorization io error"));
+    }
+
+    if topic_name == CONSUMER_OFFSETS_TOPIC {
+        debug!("internal topic can't be updated");
+        return Ok(Status::new(
+            topic_name.clone(),
+            ErrorCode::PermissionDenied,
+            Some(format!("'{}' is an internal topic", topic_name)),
+        ));
+    }
+
+    let topic = match auth_ctx
+        .global_ctx
+        .topics()
+        .store()
+        .value(&topic_name)
+        .await
+    {
+        Some(topic) => topic,
+        None => {
+            return Ok(Status::new(
+                topic_name.clone(),
+                ErrorCode::TopicNotFound,
+                Some("not found".to_owned()),
+            ))
+        }
+    };
+
+    let mut spec = topic.spec.clone();
+    if let Err(reason) = spec.set_partitions(update.partitions) {
+        debug!(%reason, "invalid topic update");
+        return Ok(Status::new(topic_name, ErrorCode::TopicError, Some(reason)));
+    }
+
+    let status = if let Err(err) = auth_ctx
+        .global_ctx
+        .topics()
+        .create_spec(topic_name.clone(), spec)
+        .await
+    {
+        Status::new(
+            topic_name.clone(),
+            ErrorCode::TopicError,
+            Some(err.to_string()),
+        )
+    } else {
+        Status::new_ok(topic_name.clone())
+    };
+
+    trace!("flv update topic resp {:#?}", status);
+
+    Ok(status)
+}
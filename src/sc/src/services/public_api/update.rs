@@ -0,0 +1,42 @@
+//!
+//! # Update Request
+//!
+//! Update request handler, dispatches update to handler of object type.
+//!
+use tracing::{trace, instrument};
+use std::io::Error;
+
+use dataplane::api::{RequestMessage, ResponseMessage};
+use fluvio_sc_schema::Status;
+use fluvio_sc_schema::objects::{UpdateRequest, AllUpdatableSpec};
+use fluvio_controlplane_metadata::extended::ObjectType;
+use fluvio_auth::AuthContext;
+
+use crate::services::auth::AuthServiceContext;
+use super::audit::audit_status;
+
+/// Handler for update request
+#[instrument(skip(request, auth_ctx))]
+pub async fn handle_update_request<AC: AuthContext>(
+    request: RequestMessage<UpdateRequest>,
+    auth_ctx: &AuthServiceContext<AC>,
+) -> Result<ResponseMessage<Status>, Error> {
+    let (header, req) = request.get_header_request();
+
+    let (ty, status) = match req.spec {
+        AllUpdatableSpec::Topic(update) => (
+            ObjectType::Topic,
+            super::topic::handle_update_topic(req.name, update, auth_ctx).await?,
+        ),
+        AllUpdatableSpec::Partition(update) => (
+            ObjectType::Partition,
+            super::partition::handle_update_partition(req.name, update, auth_ctx).await?,
+        ),
+    };
+
+    audit_status(auth_ctx, ty, "Update", &status);
+
+    trace!("flv update resp {:#?}", status);
+
+    Ok(ResponseMessage::from_header(&header, status))
+}
@@ -9,7 +9,9 @@ use dataplane::core::{Encoder, Decoder};
 use dataplane::api::{RequestMessage, RequestHeader, ResponseMessage};
 use fluvio_sc_schema::objects::{WatchRequest, WatchResponse, Metadata, MetadataUpdate};
 
+use fluvio_auth::AuthContext;
 use fluvio_controlplane_metadata::core::Spec;
+use fluvio_controlplane_metadata::extended::SpecExt;
 use fluvio_controlplane_metadata::partition::PartitionSpec;
 use fluvio_controlplane_metadata::spu::SpuSpec;
 use fluvio_controlplane_metadata::topic::TopicSpec;
@@ -19,61 +21,70 @@ use crate::stores::{StoreContext, K8ChangeListener};
 use fluvio_controlplane_metadata::spg::SpuGroupSpec;
 
 /// handle watch request by spawning watch controller for each store
+/// objects which caller is not allowed to read are not sent
 #[instrument(skip(request, auth_ctx, sink, end_event))]
 pub fn handle_watch_request<AC>(
     request: RequestMessage<WatchRequest>,
-    auth_ctx: &AuthServiceContext<AC>,
+    auth_ctx: Arc<AuthServiceContext<AC>>,
     sink: ExclusiveFlvSink,
     end_event: Arc<SimpleEvent>,
-) {
+) where
+    AC: AuthContext + Send + Sync + 'static,
+{
     debug!("handling watch request");
     let (header, req) = request.get_header_request();
 
     match req {
-        WatchRequest::Topic(_) => WatchController::<TopicSpec>::update(
+        WatchRequest::Topic(_) => WatchController::<TopicSpec, AC>::update(
             sink,
             end_event,
             auth_ctx.global_ctx.topics().clone(),
             header,
+            auth_ctx,
         ),
-        WatchRequest::Spu(_) => WatchController::<SpuSpec>::update(
+        WatchRequest::Spu(_) => WatchController::<SpuSpec, AC>::update(
             sink,
             end_event,
             auth_ctx.global_ctx.spus().clone(),
             header,
+            auth_ctx,
         ),
-        WatchRequest::SpuGroup(_) => WatchController::<SpuGroupSpec>::update(
+        WatchRequest::SpuGroup(_) => WatchController::<SpuGroupSpec, AC>::update(
             sink,
             end_event,
             auth_ctx.global_ctx.spgs().clone(),
             header,
+            auth_ctx,
         ),
-        WatchRequest::Partition(_) => WatchController::<PartitionSpec>::update(
+        WatchRequest::Partition(_) => WatchController::<PartitionSpec, AC>::update(
             sink,
             end_event,
             auth_ctx.global_ctx.partitions().clone(),
             header,
+            auth_ctx,
         ),
     }
 }
 
-struct WatchController<S>
+struct WatchController<S, AC>
 where
-    S: Spec,
+    S: SpecExt,
 {
     response_sink: ExclusiveFlvSink,
     store: StoreContext<S>,
     header: RequestHeader,
     end_event: Arc<SimpleEvent>,
+    auth_ctx: Arc<AuthServiceContext<AC>>,
 }
 
-impl<S> WatchController<S>
+impl<S, AC> WatchController<S, AC>
 where
-    S: Spec + Debug + 'static + Send + Sync + Encoder + Decoder,
+    S: SpecExt + Debug + 'static + Send + Sync + Encoder + Decoder,
     S::IndexKey: ToString,
     <S as Spec>::Status: Sync + Send + Encoder + Decoder,
     <S as Spec>::IndexKey: Sync + Send,
     MetadataUpdate<S>: Into<WatchResponse>,
+    AC: AuthContext + Send + Sync + 'static,
 {
     /// start watch controller
     fn update(
@@ -81,6 +92,7 @@ where
         end_event: Arc<SimpleEvent>,
         store: StoreContext<S>,
         header: RequestHeader,
+        auth_ctx: Arc<AuthServiceContext<AC>>,
     ) {
         use fluvio_future::task::spawn;
 
@@ -89,6 +101,7 @@ where
             store,
             header,
             end_event,
+            auth_ctx,
         };
 
         spawn(controller.dispatch_loop());
@@ -149,19 +162,23 @@ where
             epoch
         );
 
-        let updates = if changes.is_sync_all() {
-            let (updates, _) = changes.parts();
-            MetadataUpdate::with_all(epoch, updates.into_iter().map(|u| u.into()).collect())
+        let is_sync_all = changes.is_sync_all();
+        let (updates, deletes) = changes.parts();
+        let updates = match self.readable(updates).await {
+            Some(updates) => updates,
+            None => return false,
+        };
+
+        let updates = if is_sync_all {
+            MetadataUpdate::with_all(epoch, updates)
         } else {
-            let (updates, deletes) = changes.parts();
-            let mut changes: Vec<Message<Metadata<S>>> = updates
-                .into_iter()
-                .map(|v| Message::update(v.into()))
-                .collect();
-            let mut deletes = deletes
-                .into_iter()
-                .map(|d| Message::delete(d.into()))
-                .collect();
+            let deletes = match self.readable(deletes).await {
+                Some(deletes) => deletes,
+                None => return false,
+            };
+            let mut changes: Vec<Message<Metadata<S>>> =
+                updates.into_iter().map(Message::update).collect();
+            let mut deletes = deletes.into_iter().map(Message::delete).collect();
             changes.append(&mut deletes);
             MetadataUpdate::with_changes(epoch, changes)
         };
@@ -187,4 +204,20 @@ where
 
         true
     }
+
+    /// convert store objects into metadata which caller can read
+    /// returns None if authorization fails
+    async fn readable<T>(&self, objects: Vec<T>) -> Option<Vec<Metadata<S>>>
+    where
+        T: Into<Metadata<S>>,
+    {
+        let objects = objects.into_iter().map(|o| o.into()).collect();
+        match self.auth_ctx.filter_readable(objects).await {
+            Ok(objects) => Some(objects),
+            Err(err) => {
+                error!("watch: {}, authorization error: {}", S::LABEL, err);
+                None
+            }
+        }
+    }
 }
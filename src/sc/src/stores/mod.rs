@@ -2,6 +2,7 @@ pub mod spu;
 pub mod topic;
 pub mod partition;
 pub mod spg;
+pub mod smartmodule;
 
 pub use crate::dispatcher::store::*;
 
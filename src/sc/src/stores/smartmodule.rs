@@ -0,0 +1,6 @@
+pub use fluvio_controlplane_metadata::smartmodule::*;
+pub use fluvio_controlplane_metadata::smartmodule::store::*;
+pub use fluvio_controlplane_metadata::store::k8::K8MetaItem;
+
+pub type SmartModuleAdminMd = SmartModuleMetadata<K8MetaItem>;
+pub type SmartModuleAdminStore = SmartModuleLocalStore<K8MetaItem>;
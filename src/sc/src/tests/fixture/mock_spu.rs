@@ -158,6 +158,9 @@ where
                                 InternalSpuRequest::UpdateReplicaRequest(request) => {
                                     handle_update_replica_request(request, self.ctx.clone()).await.expect("replica request");
                                 }
+                                InternalSpuRequest::UpdateSmartModuleRequest(_) => {
+                                    tracing::trace!("ignoring smartmodule update");
+                                }
                             }
                             
                         } else {
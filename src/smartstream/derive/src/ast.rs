@@ -23,6 +23,8 @@ pub enum SmartStreamKind {
     Aggregate,
     Filter,
     Map,
+    ArrayMap,
+    FilterMap,
 }
 
 impl SmartStreamKind {
@@ -37,6 +39,8 @@ impl SmartStreamKind {
                                 "aggregate" => Some(Self::Aggregate),
                                 "filter" => Some(Self::Filter),
                                 "map" => Some(Self::Map),
+                                "array_map" => Some(Self::ArrayMap),
+                                "filter_map" => Some(Self::FilterMap),
                                 _ => None,
                             }
                         })
@@ -50,14 +54,43 @@ impl SmartStreamKind {
     }
 }
 
+impl SmartStreamKind {
+    /// number of arguments of the user function, not counting the extra params
+    fn base_args(&self) -> usize {
+        match self {
+            Self::Aggregate => 2,
+            _ => 1,
+        }
+    }
+}
+
 pub struct SmartStreamFn<'a> {
     pub name: &'a Ident,
     pub func: &'a ItemFn,
+    /// the user function takes `&SmartStreamExtraParams` as the last argument
+    pub has_params: bool,
 }
 
 impl<'a> SmartStreamFn<'a> {
-    pub fn from_ast(func: &'a ItemFn) -> SynResult<Self> {
+    pub fn from_ast(func: &'a ItemFn, kind: &SmartStreamKind) -> SynResult<Self> {
         let name = &func.sig.ident;
-        Ok(Self { name, func })
+        let args = func.sig.inputs.len();
+        let base_args = kind.base_args();
+        if args < base_args || args > base_args + 1 {
+            return Err(SynError::new(
+                func.sig.inputs.span(),
+                format!(
+                    "SmartStream function must take {} or {} arguments",
+                    base_args,
+                    base_args + 1
+                ),
+            ));
+        }
+
+        Ok(Self {
+            name,
+            func,
+            has_params: args > base_args,
+        })
     }
 }
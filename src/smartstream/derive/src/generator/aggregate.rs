@@ -6,6 +6,12 @@ pub fn generate_aggregate_smartstream(func: &SmartStreamFn) -> TokenStream {
     let user_code = &func.func;
     let user_fn = &func.name;
 
+    let call = if func.has_params {
+        quote! { super:: #user_fn(acc_data, &record, &smartstream_input.base.params) }
+    } else {
+        quote! { super:: #user_fn(acc_data, &record) }
+    };
+
     quote! {
         #user_code
 
@@ -45,7 +51,7 @@ pub fn generate_aggregate_smartstream(func: &SmartStreamFn) -> TokenStream {
 
                 for mut record in records.into_iter() {
                     let acc_data = RecordData::from(accumulator);
-                    let result = super:: #user_fn(acc_data, &record);
+                    let result = #call;
 
                     match result {
                         Ok(value) => {
@@ -6,6 +6,12 @@ pub fn generate_filter_smartstream(func: &SmartStreamFn) -> TokenStream {
     let user_fn = &func.name;
     let user_code = func.func;
 
+    let call = if func.has_params {
+        quote! { super:: #user_fn(&record, &smartstream_input.params) }
+    } else {
+        quote! { super:: #user_fn(&record) }
+    };
+
     quote! {
         #user_code
 
@@ -44,7 +50,7 @@ pub fn generate_filter_smartstream(func: &SmartStreamFn) -> TokenStream {
                 };
 
                 for mut record in records.into_iter() {
-                    let result = super:: #user_fn(&record);
+                    let result = #call;
                     match result {
                         Ok(value) => {
                             if value {
@@ -1,18 +1,73 @@
-use quote::quote;
+use quote::{quote, format_ident};
 use proc_macro2::TokenStream;
-use crate::SmartStreamFn;
+use crate::{SmartStreamFn, SmartStreamKind};
 
-pub fn generate_map_smartstream(func: &SmartStreamFn) -> TokenStream {
+/// Generate map, array map or filter map.
+/// They only differ in name of exported function and how output of user function becomes records.
+pub fn generate_map_smartstream(func: &SmartStreamFn, kind: &SmartStreamKind) -> TokenStream {
     let user_code = &func.func;
     let user_fn = &func.name;
 
+    let (export_fn, smartstream_type, handle_output) = match kind {
+        SmartStreamKind::Map => (
+            "map",
+            "Map",
+            quote! {
+                Ok((maybe_key, value)) => {
+                    let mut record = record;
+                    record.key = maybe_key;
+                    record.value = value;
+                    output.successes.push(record);
+                }
+            },
+        ),
+        // every output is copy of input record, so outputs keep offset of input record.
+        // this way offset of consumer always refers to record of source topic
+        SmartStreamKind::ArrayMap => (
+            "array_map",
+            "ArrayMap",
+            quote! {
+                Ok(outputs) => {
+                    for (maybe_key, value) in outputs {
+                        let mut output_record = record.clone();
+                        output_record.key = maybe_key;
+                        output_record.value = value;
+                        output.successes.push(output_record);
+                    }
+                }
+            },
+        ),
+        SmartStreamKind::FilterMap => (
+            "filter_map",
+            "FilterMap",
+            quote! {
+                Ok(Some((maybe_key, value))) => {
+                    let mut record = record;
+                    record.key = maybe_key;
+                    record.value = value;
+                    output.successes.push(record);
+                }
+                Ok(None) => {}
+            },
+        ),
+        _ => unreachable!("not map smartstream"),
+    };
+    let export_fn = format_ident!("{}", export_fn);
+    let smartstream_type = format_ident!("{}", smartstream_type);
+
+    let call = if func.has_params {
+        quote! { super:: #user_fn(&record, &smartstream_input.params) }
+    } else {
+        quote! { super:: #user_fn(&record) }
+    };
+
     quote! {
         #user_code
 
         mod __system {
             #[no_mangle]
             #[allow(clippy::missing_safety_doc)]
-            pub unsafe fn map(ptr: *mut u8, len: usize) -> i32 {
+            pub unsafe fn #export_fn(ptr: *mut u8, len: usize) -> i32 {
                 use fluvio_smartstream::dataplane::smartstream::{
                     SmartStreamInput, SmartStreamInternalError,
                     SmartStreamRuntimeError, SmartStreamType, SmartStreamOutput,
@@ -43,19 +98,15 @@ pub fn generate_map_smartstream(func: &SmartStreamFn) -> TokenStream {
                     error: None,
                 };
 
-                for mut record in records.into_iter() {
-                    let result = super:: #user_fn(&record);
+                for record in records.into_iter() {
+                    let result = #call;
                     match result {
-                        Ok((maybe_key, value)) => {
-                            record.key = maybe_key;
-                            record.value = value;
-                            output.successes.push(record);
-                        }
+                        #handle_output
                         Err(err) => {
                             let error = SmartStreamRuntimeError::new(
                                 &record,
                                 smartstream_input.base_offset,
-                                SmartStreamType::Map,
+                                SmartStreamType::#smartstream_type,
                                 err,
                             );
                             output.error = Some(error);
@@ -8,7 +8,9 @@ mod aggregate;
 pub fn generate_smartstream(config: &SmartStreamConfig, func: &SmartStreamFn) -> TokenStream {
     match config.kind {
         SmartStreamKind::Filter => self::filter::generate_filter_smartstream(func),
-        SmartStreamKind::Map => self::map::generate_map_smartstream(func),
+        SmartStreamKind::Map | SmartStreamKind::ArrayMap | SmartStreamKind::FilterMap => {
+            self::map::generate_map_smartstream(func, &config.kind)
+        }
         SmartStreamKind::Aggregate => self::aggregate::generate_aggregate_smartstream(func),
     }
 }
@@ -16,7 +16,7 @@ pub fn smartstream(args: TokenStream, input: TokenStream) -> TokenStream {
         Ok(config) => config,
         Err(e) => return e.into_compile_error().into(),
     };
-    let func = match SmartStreamFn::from_ast(&func) {
+    let func = match SmartStreamFn::from_ast(&func, &config.kind) {
         Ok(func) => func,
         Err(e) => return e.into_compile_error().into(),
     };
@@ -0,0 +1,8 @@
+use fluvio_smartstream::{smartstream, Record, RecordData, Result};
+
+#[smartstream(filter_map)]
+pub fn my_filter_map(_record: &Record) -> Result<Option<(Option<RecordData>, RecordData)>> {
+    unimplemented!()
+}
+
+fn main() {}
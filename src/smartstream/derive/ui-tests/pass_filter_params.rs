@@ -0,0 +1,8 @@
+use fluvio_smartstream::{smartstream, Record, Result, SmartStreamExtraParams};
+
+#[smartstream(filter)]
+pub fn my_filter(_record: &Record, _params: &SmartStreamExtraParams) -> Result<bool> {
+    unimplemented!()
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use fluvio_smartstream::{smartstream, Record, RecordData, Result};
+
+#[smartstream(array_map)]
+pub fn array_map(record: &Record) -> Result<Vec<(Option<RecordData>, RecordData)>> {
+    // Read the input record as a JSON array
+    let array = serde_json::from_slice::<Vec<serde_json::Value>>(record.value.as_ref())?;
+
+    // Convert each JSON value from the array back into a JSON string
+    let strings: Vec<String> = array
+        .into_iter()
+        .map(|value| serde_json::to_string(&value))
+        .collect::<core::result::Result<_, _>>()?;
+
+    // Create one record from each JSON string to send
+    let records: Vec<(Option<RecordData>, RecordData)> = strings
+        .into_iter()
+        .map(|s| (None, RecordData::from(s)))
+        .collect();
+    Ok(records)
+}
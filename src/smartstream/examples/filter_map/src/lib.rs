@@ -0,0 +1,16 @@
+use fluvio_smartstream::{smartstream, Record, RecordData, Result};
+
+#[smartstream(filter_map)]
+pub fn filter_map(record: &Record) -> Result<Option<(Option<RecordData>, RecordData)>> {
+    let key = record.key.clone();
+    let string = std::str::from_utf8(record.value.as_ref())?;
+    let int: i32 = string.parse()?;
+
+    // keep only even numbers, and divide them by two
+    if int % 2 == 0 {
+        let output = int / 2;
+        Ok(Some((key, RecordData::from(output.to_string()))))
+    } else {
+        Ok(None)
+    }
+}
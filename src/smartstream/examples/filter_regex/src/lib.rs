@@ -1,14 +1,21 @@
-use fluvio_smartstream::{smartstream, Record, Result};
+use fluvio_smartstream::{smartstream, Record, Result, SmartStreamExtraParams};
 use regex::Regex;
 
+/// Matches Social Security numbers, used if no "regex" parameter is given
+const DEFAULT_REGEX: &str = r"\d{3}-\d{2}-\d{4}";
+
 #[smartstream(filter)]
-pub fn filter(record: &Record) -> Result<bool> {
+pub fn filter(record: &Record, params: &SmartStreamExtraParams) -> Result<bool> {
     let string = std::str::from_utf8(record.value.as_ref())?;
 
-    // Check whether the Record contains a Social Security number
-    let social_security_regex = Regex::new(r"\d{3}-\d{2}-\d{4}").unwrap();
-    let has_ss = social_security_regex.is_match(string);
+    // Check whether the Record matches the pattern, ex: `-e regex=[0-9]+`
+    let pattern = params
+        .get("regex")
+        .map(|it| it.as_str())
+        .unwrap_or(DEFAULT_REGEX);
+    let regex = Regex::new(pattern)?;
+    let is_match = regex.is_match(string);
 
-    // Only accept records that _do not_ have social security numbers in them
-    Ok(!has_ss)
+    // Only accept records that _do not_ match the pattern
+    Ok(!is_match)
 }
@@ -1,7 +1,8 @@
 #![doc = include_str!("../README.md")]
 
 pub use fluvio_dataplane_protocol as dataplane;
-pub use dataplane::record::{Record, RecordData};
+pub use dataplane::record::{Record, RecordData, Header};
+pub use dataplane::smartstream::SmartStreamExtraParams;
 
 #[cfg(feature = "derive")]
 pub use fluvio_smartstream_derive::smartstream;
@@ -106,6 +106,25 @@ impl MultiplexerSocket {
         correlation_id(self.correlation_id_counter.clone()).await
     }
 
+    /// send request without waiting for response.
+    /// Only for requests which server doesn't respond to.
+    #[instrument(skip(self, req_msg))]
+    pub async fn send_only<R>(&self, mut req_msg: RequestMessage<R>) -> Result<(), SocketError>
+    where
+        R: Request,
+    {
+        let correlation_id = self.next_correlation_id().await;
+        req_msg.header.set_correlation_id(correlation_id);
+
+        debug!(
+            "serial multiplexing: sending request without response: {} id: {}",
+            R::API_KEY,
+            correlation_id
+        );
+        self.sink.send_request(&req_msg).await?;
+        Ok(())
+    }
+
     /// create socket to perform request and response
     #[instrument(skip(self, req_msg))]
     pub async fn send_and_receive<R>(
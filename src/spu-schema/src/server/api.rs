@@ -21,6 +21,7 @@ use super::SpuServerApiKey;
 use super::fetch_offset::FetchOffsetsRequest;
 use super::stream_fetch::FileStreamFetchRequest;
 use super::update_offset::UpdateOffsetsRequest;
+use super::consumer_offset::{CommitConsumerOffsetRequest, FetchConsumerOffsetRequest};
 
 /// Request to Spu Server
 #[derive(Debug, Encoder)]
@@ -34,6 +35,8 @@ pub enum SpuServerRequest {
     FetchOffsetsRequest(RequestMessage<FetchOffsetsRequest>),
     FileStreamFetchRequest(RequestMessage<FileStreamFetchRequest>),
     UpdateOffsetsRequest(RequestMessage<UpdateOffsetsRequest>),
+    CommitConsumerOffsetRequest(RequestMessage<CommitConsumerOffsetRequest>),
+    FetchConsumerOffsetRequest(RequestMessage<FetchConsumerOffsetRequest>),
 }
 
 impl Default for SpuServerRequest {
@@ -64,6 +67,12 @@ impl ApiMessage for SpuServerRequest {
             SpuServerApiKey::FetchOffsets => api_decode!(Self, FetchOffsetsRequest, src, header),
             SpuServerApiKey::StreamFetch => api_decode!(Self, FileStreamFetchRequest, src, header),
             SpuServerApiKey::UpdateOffsets => api_decode!(Self, UpdateOffsetsRequest, src, header),
+            SpuServerApiKey::CommitConsumerOffset => {
+                api_decode!(Self, CommitConsumerOffsetRequest, src, header)
+            }
+            SpuServerApiKey::FetchConsumerOffset => {
+                api_decode!(Self, FetchConsumerOffsetRequest, src, header)
+            }
         }
     }
 }
@@ -20,6 +20,8 @@ pub enum SpuServerApiKey {
     FetchOffsets = 1002,
     StreamFetch = 1003,
     UpdateOffsets = 1005,
+    CommitConsumerOffset = 1006,
+    FetchConsumerOffset = 1007,
 }
 
 impl Default for SpuServerApiKey {
@@ -0,0 +1,93 @@
+//!
+//! # Consumer Group Offsets
+//!
+//! API to commit and fetch offsets of consumer groups.
+//! Offsets are stored as records of internal topic, so they are replicated like any other records.
+//! Offsets of a group are kept in single partition of that topic, requests are sent to its leader.
+//!
+
+use dataplane::api::Request;
+use dataplane::core::{Encoder, Decoder};
+use dataplane::Offset;
+
+use crate::errors::ErrorCode;
+use super::SpuServerApiKey;
+
+pub use fluvio_types::defaults::{CONSUMER_OFFSETS_TOPIC, CONSUMER_OFFSETS_PARTITIONS};
+
+/// partition of offsets topic which stores offsets of group.
+/// Clients and SPUs must agree on it, so hash is fixed (FNV-1a) rather than std hasher
+pub fn consumer_offsets_partition(group: &str) -> i32 {
+    let hash = group.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x0100_0193)
+    });
+    (hash % CONSUMER_OFFSETS_PARTITIONS as u32) as i32
+}
+
+/// Commit next offset to be consumed by group
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct CommitConsumerOffsetRequest {
+    pub group: String,
+    pub topic: String,
+    pub partition: i32,
+    pub offset: Offset,
+    /// generation of group assignment the member consumed partition in.
+    /// commits older than generation of last commit are rejected with `IllegalGeneration`
+    #[fluvio(min_version = 1)]
+    pub generation: i32,
+}
+
+impl Request for CommitConsumerOffsetRequest {
+    const API_KEY: u16 = SpuServerApiKey::CommitConsumerOffset as u16;
+    const DEFAULT_API_VERSION: i16 = 1;
+    type Response = CommitConsumerOffsetResponse;
+}
+
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct CommitConsumerOffsetResponse {
+    pub error_code: ErrorCode,
+}
+
+/// Fetch offset committed by group
+#[derive(Decoder, Encoder, Default, Debug)]
+pub struct FetchConsumerOffsetRequest {
+    pub group: String,
+    pub topic: String,
+    pub partition: i32,
+}
+
+impl Request for FetchConsumerOffsetRequest {
+    const API_KEY: u16 = SpuServerApiKey::FetchConsumerOffset as u16;
+    const DEFAULT_API_VERSION: i16 = 1;
+    type Response = FetchConsumerOffsetResponse;
+}
+
+#[derive(Encoder, Decoder, Default, Debug)]
+pub struct FetchConsumerOffsetResponse {
+    pub error_code: ErrorCode,
+    /// None if group has not committed any offset
+    pub offset: Option<Offset>,
+    /// highest generation group has committed in for any partition of topic, 0 if none.
+    /// Group starts above it when it is created again, so its new commits are not fenced
+    #[fluvio(min_version = 1)]
+    pub generation: i32,
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::{consumer_offsets_partition, CONSUMER_OFFSETS_PARTITIONS};
+
+    #[test]
+    fn test_consumer_offsets_partition() {
+        // must not change between releases
+        assert_eq!(consumer_offsets_partition(""), 5);
+        assert_eq!(consumer_offsets_partition("a"), 4);
+        assert_eq!(consumer_offsets_partition("group1"), 7);
+        assert_eq!(consumer_offsets_partition("group2"), 2);
+        for group in &["my-group", "another-group", "g"] {
+            let partition = consumer_offsets_partition(group);
+            assert!((0..CONSUMER_OFFSETS_PARTITIONS).contains(&partition));
+        }
+    }
+}
@@ -6,7 +6,7 @@ use std::fmt;
 
 use dataplane::api::Request;
 use dataplane::core::{Encoder, Decoder};
-use dataplane::PartitionOffset;
+use dataplane::{Offset, PartitionOffset, Timestamp};
 use dataplane::ReplicaKey;
 
 use crate::errors::ErrorCode;
@@ -25,7 +25,7 @@ pub struct FetchOffsetsRequest {
 
 impl Request for FetchOffsetsRequest {
     const API_KEY: u16 = SpuServerApiKey::FetchOffsets as u16;
-    const DEFAULT_API_VERSION: i16 = 0;
+    const DEFAULT_API_VERSION: i16 = 1;
     type Response = FetchOffsetsResponse;
 }
 
@@ -37,6 +37,21 @@ impl FetchOffsetsRequest {
                 name: topic,
                 partitions: vec![FetchOffsetPartition {
                     partition_index: partition,
+                    ..Default::default()
+                }],
+            }],
+        }
+    }
+
+    /// create request with a single topic and partition which also
+    /// looks up offset of first record at or after timestamp
+    pub fn new_with_timestamp(topic: String, partition: i32, timestamp: Timestamp) -> Self {
+        Self {
+            topics: vec![FetchOffsetTopic {
+                name: topic,
+                partitions: vec![FetchOffsetPartition {
+                    partition_index: partition,
+                    timestamp: Some(timestamp),
                 }],
             }],
         }
@@ -56,6 +71,10 @@ pub struct FetchOffsetTopic {
 pub struct FetchOffsetPartition {
     /// The partition index.
     pub partition_index: i32,
+
+    /// Timestamp to look up offset for, in milliseconds since epoch
+    #[fluvio(min_version = 1)]
+    pub timestamp: Option<Timestamp>,
 }
 
 // -----------------------------------
@@ -106,6 +125,11 @@ pub struct FetchOffsetPartitionResponse {
 
     /// Last readable offset
     pub last_stable_offset: i64,
+
+    /// Offset of first record at or after requested timestamp.
+    /// None if timestamp was not requested or all records are older
+    #[fluvio(min_version = 1)]
+    pub timestamp_offset: Option<Offset>,
 }
 
 impl fmt::Display for FetchOffsetPartitionResponse {
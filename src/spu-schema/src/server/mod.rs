@@ -2,6 +2,7 @@ mod api_key;
 #[cfg(feature = "file")]
 mod api;
 pub mod fetch_offset;
+pub mod consumer_offset;
 pub mod stream_fetch;
 pub mod update_offset;
 
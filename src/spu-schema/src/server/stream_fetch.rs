@@ -11,6 +11,7 @@ use dataplane::api::Request;
 use dataplane::fetch::FetchablePartitionResponse;
 use dataplane::record::RecordSet;
 use dataplane::Isolation;
+use dataplane::smartstream::SmartStreamExtraParams;
 
 pub type DefaultStreamFetchResponse = StreamFetchResponse<RecordSet>;
 
@@ -25,6 +26,18 @@ pub const WASM_MODULE_V2_API: i16 = 12;
 // version for aggregator smartstream
 pub const AGGREGATOR_API: i16 = 13;
 
+// version for array map and filter map smartstreams
+pub const ARRAY_MAP_WASM_API: i16 = 14;
+
+// version for smartstream parameters
+pub const SMART_STREAM_PARAMS_API: i16 = 15;
+
+// version for chained smartstreams
+pub const CHAIN_SMART_STREAM_API: i16 = 16;
+
+// version for smartstreams referring to SmartModules stored in the cluster
+pub const NAMED_SMART_MODULE_API: i16 = 17;
+
 /// Fetch records continuously
 /// Output will be send back as stream
 #[derive(Decoder, Encoder, Default, Debug)]
@@ -41,6 +54,9 @@ where
     pub wasm_module: Vec<u8>,
     #[fluvio(min_version = 12)]
     pub wasm_payload: Option<SmartStreamPayload>,
+    /// SmartStreams executed in order, takes precedence over `wasm_payload`
+    #[fluvio(min_version = 16)]
+    pub wasm_payloads: Vec<SmartStreamPayload>,
     pub data: PhantomData<R>,
 }
 
@@ -49,7 +65,7 @@ where
     R: Debug + Decoder + Encoder,
 {
     const API_KEY: u16 = SpuServerApiKey::StreamFetch as u16;
-    const DEFAULT_API_VERSION: i16 = AGGREGATOR_API;
+    const DEFAULT_API_VERSION: i16 = NAMED_SMART_MODULE_API;
     type Response = StreamFetchResponse<R>;
 }
 
@@ -61,6 +77,8 @@ where
 pub struct SmartStreamPayload {
     pub wasm: SmartStreamWasm,
     pub kind: SmartStreamKind,
+    #[fluvio(min_version = 15)]
+    pub params: SmartStreamExtraParams,
 }
 
 /// Indicates the type of SmartStream as well as any special data required
@@ -69,6 +87,8 @@ pub enum SmartStreamKind {
     Filter,
     Map,
     Aggregate { accumulator: Vec<u8> },
+    ArrayMap,
+    FilterMap,
 }
 
 impl Default for SmartStreamKind {
@@ -80,14 +100,12 @@ impl Default for SmartStreamKind {
 /// Different possible representations of WASM modules.
 ///
 /// In a fetch request, a WASM module may be given directly in the request
-/// as raw bytes.
-///
-// TODO ... or, it may be named and selected from the WASM store.
+/// as raw bytes, or it may be named and selected from the SmartModules
+/// stored in the cluster.
 #[derive(Debug, Clone, Encoder, Decoder)]
 pub enum SmartStreamWasm {
     Raw(Vec<u8>),
-    // TODO implement named WASM modules once we have a WASM store
-    // Url(String),
+    Named(String),
 }
 
 impl Default for SmartStreamWasm {
@@ -184,6 +202,20 @@ mod tests {
         assert_eq!(dest[8], 0xef);
     }
 
+    #[test]
+    fn test_encode_smartstreamwasm_named() {
+        let mut dest = Vec::new();
+        let value = SmartStreamWasm::Named("ab".to_owned());
+        value.encode(&mut dest, 0).expect("should encode");
+        assert_eq!(dest, vec![0x01, 0x00, 0x02, 0x61, 0x62]);
+
+        let mut decoded: SmartStreamWasm = Default::default();
+        decoded
+            .decode(&mut std::io::Cursor::new(dest), 0)
+            .expect("should decode");
+        assert!(matches!(decoded, SmartStreamWasm::Named(name) if name == "ab"));
+    }
+
     #[test]
     fn test_decode_smartstreamwasm() {
         let bytes = vec![0x00, 0x00, 0x00, 0x00, 0x04, 0xde, 0xad, 0xbe, 0xef];
@@ -193,7 +225,6 @@ mod tests {
             .expect("should decode");
         let inner = match value {
             SmartStreamWasm::Raw(inner) => inner,
-            #[allow(unreachable_patterns)]
             _ => panic!("should decode to SmartStreamWasm::Raw"),
         };
         assert_eq!(inner.len(), 4);
@@ -212,6 +243,7 @@ mod tests {
             wasm_payload: Some(SmartStreamPayload {
                 kind: SmartStreamKind::Filter,
                 wasm: SmartStreamWasm::Raw(vec![0xde, 0xad, 0xbe, 0xef]),
+                ..Default::default()
             }),
             ..Default::default()
         };
@@ -224,6 +256,84 @@ mod tests {
         assert_eq!(dest, expected);
     }
 
+    #[test]
+    fn test_encode_stream_fetch_request_params() {
+        let mut params = SmartStreamExtraParams::default();
+        params.insert("k".to_owned(), "v".to_owned());
+        let value = DefaultStreamFetchRequest {
+            topic: "one".to_string(),
+            partition: 3,
+            wasm_payload: Some(SmartStreamPayload {
+                kind: SmartStreamKind::Filter,
+                wasm: SmartStreamWasm::Raw(vec![0xde, 0xad, 0xbe, 0xef]),
+                params,
+            }),
+            ..Default::default()
+        };
+
+        // params are not sent to older SPU
+        let mut dest = Vec::new();
+        value.encode(&mut dest, 14).expect("should encode");
+        assert_eq!(dest.len(), 37);
+
+        let mut dest = Vec::new();
+        value
+            .encode(&mut dest, SMART_STREAM_PARAMS_API)
+            .expect("should encode");
+        assert_eq!(
+            &dest[37..],
+            &[0x00, 0x01, 0x00, 0x01, 0x6b, 0x00, 0x01, 0x76]
+        );
+
+        let mut decoded = DefaultStreamFetchRequest::default();
+        decoded
+            .decode(&mut std::io::Cursor::new(dest), SMART_STREAM_PARAMS_API)
+            .expect("should decode");
+        let payload = decoded.wasm_payload.expect("payload");
+        assert_eq!(payload.params.get("k").map(|it| it.as_str()), Some("v"));
+    }
+
+    #[test]
+    fn test_encode_stream_fetch_request_chain() {
+        let value = DefaultStreamFetchRequest {
+            topic: "one".to_string(),
+            partition: 3,
+            wasm_payloads: vec![
+                SmartStreamPayload {
+                    kind: SmartStreamKind::Filter,
+                    wasm: SmartStreamWasm::Raw(vec![0xde, 0xad]),
+                    ..Default::default()
+                },
+                SmartStreamPayload {
+                    kind: SmartStreamKind::Map,
+                    wasm: SmartStreamWasm::Raw(vec![0xbe, 0xef]),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+
+        let mut dest = Vec::new();
+        value
+            .encode(&mut dest, CHAIN_SMART_STREAM_API)
+            .expect("should encode");
+
+        let mut decoded = DefaultStreamFetchRequest::default();
+        decoded
+            .decode(&mut std::io::Cursor::new(dest), CHAIN_SMART_STREAM_API)
+            .expect("should decode");
+        assert!(decoded.wasm_payload.is_none());
+        assert_eq!(decoded.wasm_payloads.len(), 2);
+        assert!(matches!(
+            decoded.wasm_payloads[0].kind,
+            SmartStreamKind::Filter
+        ));
+        assert!(matches!(
+            decoded.wasm_payloads[1].kind,
+            SmartStreamKind::Map
+        ));
+    }
+
     #[test]
     fn test_decode_stream_fetch_request() {
         let bytes = vec![
@@ -241,7 +351,6 @@ mod tests {
         };
         let wasm = match smartstream.wasm {
             SmartStreamWasm::Raw(wasm) => wasm,
-            #[allow(unreachable_patterns)]
             _ => panic!("should be SmartStreamWasm::Raw"),
         };
         assert_eq!(wasm, vec![0xde, 0xad, 0xbe, 0xef]);
@@ -7,6 +7,8 @@
 use std::io::Error as IoError;
 use std::process;
 use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::convert::TryFrom;
 
 use tracing::debug;
 use tracing::info;
@@ -15,6 +17,8 @@ use structopt::StructOpt;
 use fluvio_types::print_cli_err;
 use fluvio_types::SpuId;
 use fluvio_future::openssl::TlsAcceptor;
+use fluvio_auth::basic::BasicRbacPolicy;
+use fluvio_auth::token::CredentialStore;
 
 use super::SpuConfig;
 
@@ -50,6 +54,14 @@ pub struct SpuOpt {
     #[structopt(long, value_name = "integer", env = "FLV_LOG_INDEX_MAX_INTERVAL_BYTES")]
     pub index_max_interval_bytes: Option<u32>,
 
+    /// how long records are kept before they are removed, by default records are kept forever
+    #[structopt(long, value_name = "seconds", env = "FLV_LOG_RETENTION_SECONDS")]
+    pub retention_seconds: Option<u32>,
+
+    /// max bytes of partition log, oldest segments are removed when exceeded. No limit by default
+    #[structopt(long, value_name = "integer", env = "FLV_PARTITION_MAX_BYTES")]
+    pub partition_max_bytes: Option<u64>,
+
     /// max bytes to transfer between leader and follower
     #[structopt(
         long,
@@ -61,6 +73,24 @@ pub struct SpuOpt {
 
     #[structopt(flatten)]
     tls: TlsConfig,
+
+    #[structopt(
+        long = "authorization-scopes",
+        value_name = "authorization scopes path",
+        env
+    )]
+    x509_auth_scopes: Option<PathBuf>,
+
+    #[structopt(
+        long = "authorization-policy",
+        value_name = "authorization policy path",
+        env
+    )]
+    auth_policy: Option<PathBuf>,
+
+    /// credentials file with hashed passwords and tokens
+    #[structopt(long = "credentials", value_name = "credentials path", env)]
+    credentials: Option<PathBuf>,
 }
 
 impl SpuOpt {
@@ -74,8 +104,6 @@ impl SpuOpt {
 
     #[allow(clippy::wrong_self_convention)]
     fn as_spu_config(self) -> Result<(SpuConfig, Option<String>), IoError> {
-        use std::path::PathBuf;
-
         let mut config = SpuConfig {
             id: match self.id {
                 Some(id) => id,
@@ -112,6 +140,16 @@ impl SpuOpt {
             config.log.index_max_interval_bytes = index_max_interval_bytes;
         }
 
+        if let Some(retention_seconds) = self.retention_seconds {
+            info!("overriding retention seconds: {}", retention_seconds);
+            config.log.retention_seconds = Some(retention_seconds);
+        }
+
+        if let Some(partition_max_bytes) = self.partition_max_bytes {
+            info!("overriding partition max bytes: {}", partition_max_bytes);
+            config.log.max_partition_size = Some(partition_max_bytes);
+        }
+
         if let Some(public_addr) = self.bind_public {
             info!("overriding public addr: {}", public_addr);
             config.public_endpoint = public_addr;
@@ -137,6 +175,30 @@ impl SpuOpt {
         }
 
         config.peer_max_bytes = self.peer_max_bytes;
+        config.x509_auth_scopes = self.x509_auth_scopes;
+
+        // without policy, all produce and consume requests are allowed
+        if let Some(policy_path) = self.auth_policy {
+            info!("using authorization policy: {:?}", policy_path);
+            config.auth_policy = Some(BasicRbacPolicy::try_from(policy_path)?);
+        }
+
+        if let Some(credentials_path) = self.credentials {
+            info!("using credentials: {:?}", credentials_path);
+            config.credentials = Some(CredentialStore::try_from(credentials_path)?);
+        }
+
+        // policy is checked against identity of client, which comes from credentials
+        // or from certificate authenticated by tls proxy
+        if config.auth_policy.is_some()
+            && config.credentials.is_none()
+            && (config.x509_auth_scopes.is_none() || tls_port.is_none())
+        {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                "authorization policy requires credentials, or tls with authorization scopes",
+            ));
+        }
 
         Ok((config, tls_port))
     }
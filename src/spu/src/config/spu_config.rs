@@ -10,6 +10,7 @@
 
 use std::env;
 use std::path::PathBuf;
+use std::time::Duration;
 
 // defaults values
 use fluvio_types::defaults::SPU_PUBLIC_PORT;
@@ -25,22 +26,29 @@ use fluvio_types::defaults::SPU_RETRY_SC_TIMEOUT_MS;
 // environment variables
 
 use fluvio_types::defaults::SPU_MIN_IN_SYNC_REPLICAS;
+use fluvio_types::defaults::SPU_REPLICA_LAG_TIME_MAX_MS;
 use fluvio_types::defaults::FLV_LOG_BASE_DIR;
 use fluvio_types::defaults::FLV_LOG_SIZE;
 use fluvio_types::SpuId;
+use fluvio_auth::basic::BasicRbacPolicy;
+use fluvio_auth::token::CredentialStore;
 use fluvio_storage::config::{
     ConfigOption, DEFAULT_FLUSH_WRITE_COUNT, DEFAULT_FLUSH_IDLE_MSEC, DEFAULT_MAX_BATCH_SIZE,
 };
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct ReplicationConfig {
+    /// used by topics which don't set min.insync.replicas
     pub min_in_sync_replicas: u16,
+    /// follower is out of sync if it hasn't caught up with leader within this time
+    pub replica_lag_time_max: Duration,
 }
 
 impl Default for ReplicationConfig {
     fn default() -> Self {
         Self {
             min_in_sync_replicas: SPU_MIN_IN_SYNC_REPLICAS,
+            replica_lag_time_max: Duration::from_millis(SPU_REPLICA_LAG_TIME_MAX_MS),
         }
     }
 }
@@ -55,6 +63,8 @@ pub struct Log {
     pub flush_write_count: u32,
     pub flush_idle_msec: u32,
     pub max_batch_size: u32,
+    pub retention_seconds: Option<u32>,
+    pub max_partition_size: Option<u64>,
 }
 
 impl Default for Log {
@@ -70,6 +80,8 @@ impl Default for Log {
             flush_write_count: DEFAULT_FLUSH_WRITE_COUNT,
             flush_idle_msec: DEFAULT_FLUSH_IDLE_MSEC,
             max_batch_size: DEFAULT_MAX_BATCH_SIZE,
+            retention_seconds: None,
+            max_partition_size: None,
         }
     }
 }
@@ -94,6 +106,11 @@ pub struct SpuConfig {
     pub log: Log,
 
     pub peer_max_bytes: u32,
+
+    // authorization
+    pub x509_auth_scopes: Option<PathBuf>,
+    pub auth_policy: Option<BasicRbacPolicy>,
+    pub credentials: Option<CredentialStore>,
 }
 
 impl Default for SpuConfig {
@@ -108,6 +125,9 @@ impl Default for SpuConfig {
             sc_retry_ms: SPU_RETRY_SC_TIMEOUT_MS,
             log: Log::default(),
             peer_max_bytes: fluvio_storage::FileReplica::PREFER_MAX_LEN,
+            x509_auth_scopes: None,
+            auth_policy: None,
+            credentials: None,
         }
     }
 }
@@ -156,6 +176,8 @@ impl From<&SpuConfig> for ConfigOption {
             .flush_write_count(log.flush_write_count)
             .flush_idle_msec(log.flush_idle_msec)
             .max_batch_size(log.max_batch_size)
+            .retention_seconds(log.retention_seconds)
+            .max_partition_size(log.max_partition_size)
             .build()
     }
 }
@@ -14,6 +14,7 @@ use fluvio_controlplane::InternalSpuRequest;
 use fluvio_controlplane::RegisterSpuRequest;
 use fluvio_controlplane::{UpdateSpuRequest, UpdateLrsRequest};
 use fluvio_controlplane::UpdateReplicaRequest;
+use fluvio_controlplane::UpdateSmartModuleRequest;
 use dataplane::api::RequestMessage;
 use fluvio_socket::{FluvioSocket, SocketError, FluvioSink};
 use fluvio_storage::FileReplica;
@@ -25,9 +26,10 @@ use super::message_sink::{SharedStatusUpdate};
 // keep track of various internal state of dispatcher
 #[derive(Default)]
 struct DispatcherCounter {
-    pub replica_changes: u64, // replica changes received from sc
-    pub spu_changes: u64,     // spu changes received from sc
-    pub reconnect: u64,       // number of reconnect to sc
+    pub replica_changes: u64,     // replica changes received from sc
+    pub spu_changes: u64,         // spu changes received from sc
+    pub smartmodule_changes: u64, // smartmodule changes received from sc
+    pub reconnect: u64,           // number of reconnect to sc
 }
 
 /// Controller for handling connection to SC
@@ -152,6 +154,10 @@ impl ScDispatcher<FileReplica> {
                                 break;
                             }
                         },
+                        Some(Ok(InternalSpuRequest::UpdateSmartModuleRequest(request))) => {
+                            self.counter.smartmodule_changes += 1;
+                            self.handle_update_smartmodule_request(request);
+                        },
                         Some(_) => {
                             debug!("no more sc msg content, end");
                             break;
@@ -328,4 +334,25 @@ impl ScDispatcher<FileReplica> {
 
         Ok(())
     }
+
+    /// SmartModules are only looked up when a stream fetch starts,
+    /// so there is nothing to notify beyond updating the local store
+    #[instrument(skip(self, req_msg))]
+    fn handle_update_smartmodule_request(
+        &mut self,
+        req_msg: RequestMessage<UpdateSmartModuleRequest>,
+    ) {
+        let (_, request) = req_msg.get_header_request();
+
+        debug!(
+            epoch = request.epoch,
+            all = request.all.len(),
+            changes = request.changes.len(),
+            "received smartmodule request"
+        );
+
+        self.ctx
+            .smartmodule_localstore()
+            .apply(request.all, request.changes);
+    }
 }
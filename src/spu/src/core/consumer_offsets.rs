@@ -0,0 +1,260 @@
+//!
+//! # Consumer Offsets
+//!
+//! Offsets committed by consumer groups are records of internal offsets topic,
+//! keyed by group and partition, so they are replicated like any other records.
+//! Leader of offsets partition keeps index of latest commit of each key.
+//! Index is caught up with committed records before it is used, so leader which
+//! took over partition also sees commits it received as follower.
+//!
+use std::collections::HashMap;
+use std::io::{Cursor, Error as IoError};
+use std::sync::{Arc, RwLock};
+
+use async_lock::Mutex;
+use tracing::{debug, error};
+
+use dataplane::{Isolation, Offset};
+use dataplane::batch::Batch;
+use dataplane::core::{Encoder, Decoder};
+use dataplane::fetch::FilePartitionResponse;
+use dataplane::record::{Record, RecordSet};
+use fluvio_controlplane_metadata::partition::ReplicaKey;
+
+use crate::replication::leader::SharedFileLeaderState;
+use crate::smart_stream::file_batch::FileBatchIterator;
+
+/// max bytes of offsets topic read at once
+const MAX_READ_BYTES: u32 = 1024 * 1024;
+
+/// key of commit record
+#[derive(Encoder, Decoder, Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConsumerOffsetKey {
+    pub group: String,
+    pub topic: String,
+    pub partition: i32,
+}
+
+/// value of commit record
+#[derive(Encoder, Decoder, Default, Debug, Clone, PartialEq)]
+pub struct ConsumerOffsetValue {
+    pub offset: Offset,
+    pub generation: i32,
+}
+
+/// create record which commits value for key
+pub fn commit_record(
+    key: &ConsumerOffsetKey,
+    value: &ConsumerOffsetValue,
+) -> Result<RecordSet, IoError> {
+    let mut key_bytes = vec![];
+    key.encode(&mut key_bytes, 0)?;
+    let mut value_bytes = vec![];
+    value.encode(&mut value_bytes, 0)?;
+
+    let mut batch = Batch::default();
+    batch.add_record(Record::new_key_value(key_bytes, value_bytes));
+    Ok(RecordSet::default().add(batch))
+}
+
+/// latest commits of single partition of offsets topic
+#[derive(Debug, Default)]
+pub struct PartitionOffsets {
+    /// next offset of offsets topic to be indexed
+    next_offset: Offset,
+    commits: HashMap<ConsumerOffsetKey, ConsumerOffsetValue>,
+    /// highest generation committed by group for topic
+    generations: HashMap<(String, String), i32>,
+}
+
+impl PartitionOffsets {
+    pub fn get(&self, key: &ConsumerOffsetKey) -> Option<&ConsumerOffsetValue> {
+        self.commits.get(key)
+    }
+
+    /// highest generation group has committed in for topic, 0 if group has not committed
+    pub fn group_generation(&self, group: &str, topic: &str) -> i32 {
+        self.generations
+            .get(&(group.to_owned(), topic.to_owned()))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// commit is stale if partition has been committed by newer generation
+    pub fn is_stale(&self, key: &ConsumerOffsetKey, generation: i32) -> bool {
+        self.get(key)
+            .map(|committed| committed.generation > generation)
+            .unwrap_or(false)
+    }
+
+    /// index committed records which have not been indexed yet
+    pub async fn catch_up(&mut self, leader: &SharedFileLeaderState) -> Result<(), IoError> {
+        // replica has been recreated with fewer records, index again from start
+        if self.next_offset > leader.leo() {
+            debug!(replica = %leader.id(), "offsets topic truncated, rebuilding index");
+            *self = Self::default();
+        }
+
+        while self.next_offset < leader.hw() {
+            let mut response = FilePartitionResponse::default();
+            leader
+                .read_records(
+                    self.next_offset,
+                    MAX_READ_BYTES,
+                    Isolation::ReadCommitted,
+                    &mut response,
+                )
+                .await;
+
+            // records before log start have been superseded by compaction
+            if response.log_start_offset > self.next_offset {
+                self.next_offset = response.log_start_offset;
+                continue;
+            }
+            if response.records.len() == 0 {
+                break;
+            }
+
+            let start = self.next_offset;
+            for file_batch in FileBatchIterator::from_raw_slice(response.records.raw_slice()) {
+                let file_batch = file_batch?;
+                let records: Vec<Record> =
+                    Decoder::decode_from(&mut Cursor::new(&file_batch.records), 0)?;
+                for record in records {
+                    // read may start in middle of batch
+                    if file_batch.base_offset() + record.get_offset_delta() >= self.next_offset {
+                        self.index(&record);
+                    }
+                }
+                self.next_offset =
+                    file_batch.base_offset() + file_batch.offset_delta() as Offset + 1;
+            }
+            if self.next_offset == start {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// records which are not commits are skipped
+    fn index(&mut self, record: &Record) {
+        let key = match record.key() {
+            Some(key) => key,
+            None => return,
+        };
+        let decoded =
+            ConsumerOffsetKey::decode_from(&mut Cursor::new(key.as_ref()), 0).and_then(|key| {
+                ConsumerOffsetValue::decode_from(&mut Cursor::new(record.value().as_ref()), 0)
+                    .map(|value| (key, value))
+            });
+        match decoded {
+            Ok((key, value)) => {
+                let generation = self
+                    .generations
+                    .entry((key.group.clone(), key.topic.clone()))
+                    .or_default();
+                *generation = (*generation).max(value.generation);
+                self.commits.insert(key, value);
+            }
+            Err(err) => error!("invalid consumer offset record: {}", err),
+        }
+    }
+}
+
+pub type SharedPartitionOffsets = Arc<Mutex<PartitionOffsets>>;
+
+/// Index of each partition of offsets topic led by this SPU
+#[derive(Debug, Default)]
+pub struct ConsumerOffsets(RwLock<HashMap<ReplicaKey, SharedPartitionOffsets>>);
+
+impl ConsumerOffsets {
+    pub fn new_shared() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// index of offsets partition, lock it to serialize commits of partition
+    pub fn partition(&self, replica: &ReplicaKey) -> SharedPartitionOffsets {
+        if let Some(offsets) = self.0.read().unwrap().get(replica) {
+            return offsets.clone();
+        }
+        self.0
+            .write()
+            .unwrap()
+            .entry(replica.clone())
+            .or_default()
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use dataplane::record::Record;
+
+    use super::{ConsumerOffsetKey, ConsumerOffsetValue, PartitionOffsets, commit_record};
+
+    fn index_commit(offsets: &mut PartitionOffsets, key: &ConsumerOffsetKey, generation: i32) {
+        let value = ConsumerOffsetValue {
+            offset: 10,
+            generation,
+        };
+        let records = commit_record(key, &value).expect("record");
+        for batch in &records.batches {
+            for record in batch.records() {
+                offsets.index(record);
+            }
+        }
+    }
+
+    #[test]
+    fn test_index_commit_record() {
+        let key = ConsumerOffsetKey {
+            group: "group1".to_owned(),
+            topic: "topic1".to_owned(),
+            partition: 2,
+        };
+        let value = ConsumerOffsetValue {
+            offset: 100,
+            generation: 3,
+        };
+        let records = commit_record(&key, &value).expect("record");
+
+        let mut offsets = PartitionOffsets::default();
+        for batch in &records.batches {
+            for record in batch.records() {
+                offsets.index(record);
+            }
+        }
+        assert_eq!(offsets.get(&key), Some(&value));
+
+        // records which are not commits are skipped
+        offsets.index(&Record::new("value"));
+        offsets.index(&Record::new_key_value("key", "value"));
+        assert_eq!(offsets.commits.len(), 1);
+    }
+
+    #[test]
+    fn test_commit_after_group_restart() {
+        let key = ConsumerOffsetKey {
+            group: "group1".to_owned(),
+            topic: "topic1".to_owned(),
+            partition: 0,
+        };
+        let mut offsets = PartitionOffsets::default();
+        assert_eq!(offsets.group_generation("group1", "topic1"), 0);
+        assert!(!offsets.is_stale(&key, 1));
+
+        index_commit(&mut offsets, &key, 5);
+        assert_eq!(offsets.group_generation("group1", "topic1"), 5);
+        assert_eq!(offsets.group_generation("group1", "topic2"), 0);
+        assert!(offsets.is_stale(&key, 4));
+        assert!(!offsets.is_stale(&key, 5));
+
+        // group created again starts above committed generation, so its commits are accepted
+        let restarted = offsets.group_generation("group1", "topic1") + 1;
+        assert!(!offsets.is_stale(&key, restarted));
+        index_commit(&mut offsets, &key, restarted);
+        assert_eq!(offsets.group_generation("group1", "topic1"), restarted);
+        assert!(offsets.is_stale(&key, 5));
+    }
+}
@@ -24,6 +24,8 @@ use super::spus::SharedSpuLocalStore;
 use super::SharedReplicaLocalStore;
 use super::spus::SpuLocalStore;
 use super::replica::ReplicaStore;
+use super::smartmodule::{SmartModuleLocalStore, SharedSmartModuleLocalStore};
+use super::consumer_offsets::ConsumerOffsets;
 use super::SharedSpuConfig;
 
 pub use file_replica::ReplicaChange;
@@ -33,11 +35,13 @@ pub struct GlobalContext<S> {
     config: SharedSpuConfig,
     spu_localstore: SharedSpuLocalStore,
     replica_localstore: SharedReplicaLocalStore,
+    smartmodule_localstore: SharedSmartModuleLocalStore,
     leaders_state: SharedReplicaLeadersState<S>,
     followers_state: SharedFollowersState<S>,
     stream_publishers: StreamPublishers,
     spu_followers: SharedSpuUpdates,
     status_update: SharedStatusUpdate,
+    consumer_offsets: Arc<ConsumerOffsets>,
 }
 
 // -----------------------------------
@@ -56,12 +60,14 @@ where
         GlobalContext {
             spu_localstore: SpuLocalStore::new_shared(),
             replica_localstore: ReplicaStore::new_shared(),
+            smartmodule_localstore: SmartModuleLocalStore::new_shared(),
             config: Arc::new(spu_config),
             leaders_state: ReplicaLeadersState::new_shared(),
             followers_state: FollowersState::new_shared(),
             stream_publishers: StreamPublishers::new(),
             spu_followers: FollowerNotifier::shared(),
             status_update: StatusMessageSink::shared(),
+            consumer_offsets: ConsumerOffsets::new_shared(),
         }
     }
 
@@ -82,6 +88,10 @@ where
         &self.replica_localstore
     }
 
+    pub fn smartmodule_localstore(&self) -> &SmartModuleLocalStore {
+        &self.smartmodule_localstore
+    }
+
     pub fn leaders_state(&self) -> &ReplicaLeadersState<S> {
         &self.leaders_state
     }
@@ -119,6 +129,11 @@ where
         self.status_update.clone()
     }
 
+    /// index of offsets committed by consumer groups
+    pub fn consumer_offsets(&self) -> &ConsumerOffsets {
+        &self.consumer_offsets
+    }
+
     /// notify all follower handlers with SPU changes
     pub async fn sync_follower_update(&self) {
         self.spu_followers
@@ -278,10 +293,29 @@ mod file_replica {
                                     }
                                 }
                             } else if new_replica.leader == local_id {
-                                if self.leaders_state().get(&new_replica.id).is_some() {
+                                if let Some(leader) = self.leaders_state().get(&new_replica.id) {
+                                    if new_replica.replicas != old_replica.replicas {
+                                        leader.update_replicas(&new_replica.replicas).await;
+                                    }
                                 } else {
                                     error!("leader controller was not found: {}", new_replica.id);
                                 }
+                            } else if old_replica.replicas.contains(&local_id)
+                                && !new_replica.replicas.contains(&local_id)
+                            {
+                                // replica has been reassigned to other spu
+                                self.remove_follower_replica(new_replica).await;
+                            } else if !old_replica.replicas.contains(&local_id)
+                                && new_replica.replicas.contains(&local_id)
+                            {
+                                // replica has been reassigned to us
+                                if let Err(err) = self
+                                    .followers_state_owned()
+                                    .add_replica(self, new_replica)
+                                    .await
+                                {
+                                    outputs.push(ReplicaChange::StorageError(err));
+                                }
                             } else {
                                 self.followers_state().update_replica(new_replica).await;
                             }
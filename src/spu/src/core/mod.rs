@@ -3,6 +3,8 @@ mod store;
 
 pub mod spus;
 pub mod replica;
+pub mod smartmodule;
+pub mod consumer_offsets;
 
 pub use self::global_context::{GlobalContext, ReplicaChange};
 pub use self::store::Spec;
@@ -11,6 +13,8 @@ pub use self::store::SpecChange;
 
 pub use self::spus::SpuLocalStore;
 pub use self::replica::SharedReplicaLocalStore;
+pub use self::smartmodule::SmartModuleLocalStore;
+pub use self::consumer_offsets::ConsumerOffsets;
 
 use std::sync::Arc;
 use ::fluvio_storage::FileReplica;
@@ -0,0 +1,24 @@
+//
+//  SmartModules (WASM modules stored in the cluster, received from Sc)
+//
+
+use fluvio_controlplane_metadata::smartmodule::SmartModule;
+
+use crate::core::Spec;
+use crate::core::LocalStore;
+
+impl Spec for SmartModule {
+    const LABEL: &'static str = "SmartModule";
+
+    type Key = String;
+
+    fn key(&self) -> &Self::Key {
+        &self.name
+    }
+
+    fn key_owned(&self) -> Self::Key {
+        self.name.clone()
+    }
+}
+
+pub type SmartModuleLocalStore = LocalStore<SmartModule>;
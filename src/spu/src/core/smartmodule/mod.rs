@@ -0,0 +1,7 @@
+mod metadata;
+
+pub use self::metadata::SmartModuleLocalStore;
+
+use std::sync::Arc;
+
+pub type SharedSmartModuleLocalStore = Arc<SmartModuleLocalStore>;
@@ -10,10 +10,11 @@ use fluvio_controlplane_metadata::partition::{Replica, ReplicaKey};
 use dataplane::record::RecordSet;
 use dataplane::Offset;
 use fluvio_storage::{FileReplica, StorageError, ReplicaStorage};
+use fluvio_storage::config::ConfigOption;
 use fluvio_types::SpuId;
 use crate::replication::leader::ReplicaOffsetRequest;
 use crate::core::{FileGlobalContext};
-use crate::storage::SharableReplicaStorage;
+use crate::storage::{SharableReplicaStorage, apply_topic_config};
 
 use super::controller::FollowerGroups;
 
@@ -90,8 +91,10 @@ impl FollowersState<FileReplica> {
                     "creating new follower state"
                 );
 
+                let mut storage_config: ConfigOption = ctx.config().into();
+                apply_topic_config(&mut storage_config, &replica.config);
                 let replica_state =
-                    FollowerReplicaState::create(leader, replica.id, ctx.config().into()).await?;
+                    FollowerReplicaState::create(leader, replica.id, storage_config).await?;
 
                 entry.insert(replica_state.clone());
                 self.groups.check_new(ctx, leader).await;
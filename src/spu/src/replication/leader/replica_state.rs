@@ -3,6 +3,7 @@ use std::{
     collections::{BTreeMap, HashSet},
     ops::{Deref, DerefMut},
     sync::Arc,
+    time::{Duration, Instant},
 };
 use std::iter::FromIterator;
 use std::fmt;
@@ -10,10 +11,14 @@ use std::fmt;
 use tracing::{debug, error, warn};
 use tracing::instrument;
 use async_rwlock::{RwLock};
+use tokio::select;
+
+use fluvio_future::timer::sleep;
 
 use dataplane::{record::RecordSet};
-use dataplane::{Offset, Isolation, ReplicaKey};
+use dataplane::{Offset, Isolation, ReplicaKey, now_timestamp};
 use fluvio_controlplane_metadata::partition::{Replica};
+use fluvio_controlplane_metadata::topic::TimestampType;
 use fluvio_controlplane::LrsRequest;
 use fluvio_storage::{FileReplica, StorageError, ReplicaStorage, OffsetInfo};
 use fluvio_types::{SpuId};
@@ -23,7 +28,7 @@ use crate::{
     control_plane::SharedStatusUpdate,
 };
 use crate::replication::follower::sync::{PeerFileTopicResponse, PeerFilePartitionResponse};
-use crate::storage::SharableReplicaStorage;
+use crate::storage::{SharableReplicaStorage, apply_topic_config};
 
 use super::{FollowerNotifier};
 
@@ -37,6 +42,8 @@ pub struct LeaderReplicaState<S> {
     storage: SharableReplicaStorage<S>,
     config: ReplicationConfig,
     followers: Arc<RwLock<BTreeMap<SpuId, OffsetInfo>>>,
+    /// last time follower has caught up with leader's end offset
+    caught_up: Arc<RwLock<BTreeMap<SpuId, Instant>>>,
     status_update: SharedStatusUpdate,
 }
 
@@ -47,6 +54,7 @@ impl<S> Clone for LeaderReplicaState<S> {
             storage: self.storage.clone(),
             config: self.config.clone(),
             followers: self.followers.clone(),
+            caught_up: self.caught_up.clone(),
             in_sync_replica: self.in_sync_replica,
             status_update: self.status_update.clone(),
         }
@@ -115,6 +123,7 @@ where
             storage: inner,
             config,
             followers: Arc::new(RwLock::new(followers)),
+            caught_up: Arc::new(RwLock::new(BTreeMap::new())),
             in_sync_replica,
             status_update,
         }
@@ -130,7 +139,9 @@ where
         ReplicationConfig: From<&'a C>,
         S::Config: From<&'a C>,
     {
-        let inner = SharableReplicaStorage::create(replica.id.clone(), config.into()).await?;
+        let mut storage_config: S::Config = config.into();
+        apply_topic_config(&mut storage_config, &replica.config);
+        let inner = SharableReplicaStorage::create(replica.id.clone(), storage_config).await?;
 
         let leader_replica = Self::new(replica, config.into(), status_update, inner);
         leader_replica.update_status().await;
@@ -153,6 +164,51 @@ where
         self.in_sync_replica = replica_count;
     }
 
+    /// minimum in sync replicas required for writes with acks = all.
+    /// topic config overrides SPU config
+    pub fn min_in_sync_replicas(&self) -> u16 {
+        self.replica
+            .config
+            .min_in_sync_replicas()
+            .unwrap_or(self.config.min_in_sync_replicas)
+    }
+
+    /// leader and followers which have caught up with leader's end offset within max lag time
+    pub async fn in_sync_replica_count(&self) -> u16 {
+        self.mark_caught_up_followers().await;
+        let caught_up = self.caught_up.read().await;
+        let in_sync_followers = caught_up
+            .values()
+            .filter(|time| time.elapsed() <= self.config.replica_lag_time_max)
+            .count();
+        1 + in_sync_followers as u16
+    }
+
+    /// true if enough replicas are in sync to accept writes with acks = all
+    pub async fn has_min_in_sync_replicas(&self) -> bool {
+        let in_sync = self.in_sync_replica_count().await;
+        let min_in_sync = self.min_in_sync_replicas();
+        if in_sync < min_in_sync {
+            debug!(in_sync, min_in_sync, replica = %self.id(), "not enough in sync replicas");
+            false
+        } else {
+            true
+        }
+    }
+
+    /// followers which are at leader's end offset are caught up as of now
+    async fn mark_caught_up_followers(&self) {
+        let leo = self.leo();
+        let now = Instant::now();
+        let followers = self.followers.read().await;
+        let mut caught_up = self.caught_up.write().await;
+        for (follower_id, follower_info) in followers.iter() {
+            if follower_info.leo >= leo {
+                caught_up.insert(*follower_id, now);
+            }
+        }
+    }
+
     /// update leader's state from follower's offset states
     /// if follower's state has been updated may result in leader's hw update
     /// return true if update has been updated, in this case, updates can be computed to followers
@@ -202,6 +258,9 @@ where
 
         drop(followers);
 
+        if update {
+            self.mark_caught_up_followers().await;
+        }
         self.notify_followers(notifier).await;
         if update {
             self.update_status().await;
@@ -295,21 +354,53 @@ where
     }
 
     /// write records to storage
-    /// then update our follower's leo
+    /// then update our follower's leo.
+    /// return base offset and last offset of records written
     #[instrument(skip(self, records, notifiers))]
     pub async fn write_record_set(
         &self,
         records: &mut RecordSet,
         notifiers: &FollowerNotifier,
-    ) -> Result<(), StorageError> {
-        self.storage
+    ) -> Result<(Offset, Offset), StorageError> {
+        if self.replica.config.timestamp_type() == TimestampType::LogAppendTime {
+            let now = now_timestamp();
+            for batch in records.batches.iter_mut() {
+                batch.set_log_append_time(now);
+            }
+        }
+
+        // followers were caught up until these records are written
+        self.mark_caught_up_followers().await;
+
+        let offsets = self
+            .storage
             .write_record_set(records, self.in_sync_replica == 1)
             .await?;
 
         self.notify_followers(notifiers).await;
         self.update_status().await;
 
-        Ok(())
+        Ok(offsets)
+    }
+
+    /// wait until high watermark reaches offset, so records before offset are replicated
+    /// to all in-sync followers.
+    /// return false if it doesn't happen before timeout
+    pub async fn wait_for_hw(&self, offset: Offset, timeout: Duration) -> bool {
+        let mut listener = self.offset_listener(&Isolation::ReadCommitted);
+        let committed = async {
+            while self.hw() < offset {
+                listener.listen().await;
+            }
+        };
+
+        select! {
+            _ = committed => true,
+            _ = sleep(timeout) => {
+                debug!(offset, hw = self.hw(), "timed out waiting for high watermark");
+                false
+            }
+        }
     }
 
     async fn notify_followers(&self, notifier: &FollowerNotifier) {
@@ -329,6 +420,32 @@ where
         }
     }
 
+    /// update followers when replicas of partition are reassigned
+    /// offsets of new followers are unknown until they send update
+    pub async fn update_replicas(&self, replicas: &[SpuId]) {
+        let mut followers = self.followers.write().await;
+        let removed: Vec<SpuId> = followers
+            .keys()
+            .filter(|follower| !replicas.contains(follower))
+            .cloned()
+            .collect();
+        let mut caught_up = self.caught_up.write().await;
+        for follower in removed {
+            followers.remove(&follower);
+            caught_up.remove(&follower);
+        }
+        drop(caught_up);
+        for replica in replicas.iter().filter(|id| **id != self.leader()) {
+            followers
+                .entry(*replica)
+                .or_insert_with(OffsetInfo::default);
+        }
+        debug!(?followers, "updated followers");
+        drop(followers);
+
+        self.update_status().await;
+    }
+
     #[allow(dead_code)]
     pub async fn live_replicas(&self) -> Vec<SpuId> {
         self.followers.read().await.keys().cloned().collect()
@@ -672,7 +789,8 @@ mod test_leader {
     use fluvio_future::test_async;
     use fluvio_controlplane_metadata::partition::{ReplicaKey, Replica};
     use fluvio_storage::{ReplicaStorage, ReplicaStorageConfig, OffsetInfo};
-    use dataplane::Offset;
+    use fluvio_storage::config::{CleanupPolicy, ReplicaOverrides};
+    use dataplane::{Offset, Size, Timestamp};
     use dataplane::fixture::{create_recordset};
 
     use crate::{
@@ -687,7 +805,11 @@ mod test_leader {
     #[derive(Default)]
     struct MockConfig {}
 
-    impl ReplicaStorageConfig for MockConfig {}
+    impl ReplicaStorageConfig for MockConfig {
+        fn set_cleanup_policy(&mut self, _policy: CleanupPolicy, _tombstone_retention: Size) {}
+
+        fn apply_overrides(&mut self, _overrides: &ReplicaOverrides) {}
+    }
 
     #[derive(Default)]
     struct MockStorage {
@@ -753,6 +875,21 @@ mod test_leader {
             Ok(true)
         }
 
+        async fn enforce_retention(&mut self) -> Result<bool, fluvio_storage::StorageError> {
+            Ok(false)
+        }
+
+        fn compaction_job(&self) -> Option<fluvio_storage::compaction::CompactionJob> {
+            None
+        }
+
+        async fn apply_compaction(
+            &mut self,
+            _compacted: fluvio_storage::compaction::CompactedSegments,
+        ) -> Result<bool, fluvio_storage::StorageError> {
+            Ok(false)
+        }
+
         type Config = MockConfig;
 
         fn get_log_start_offset(&self) -> Offset {
@@ -762,6 +899,13 @@ mod test_leader {
         async fn remove(&self) -> Result<(), fluvio_storage::StorageError> {
             todo!()
         }
+
+        async fn find_offset_by_timestamp(
+            &self,
+            _timestamp: Timestamp,
+        ) -> Result<Option<Offset>, fluvio_storage::StorageError> {
+            Ok(None)
+        }
     }
 
     #[test_async]
@@ -786,6 +930,29 @@ mod test_leader {
         Ok(())
     }
 
+    #[test_async]
+    async fn test_update_replicas() -> Result<(), ()> {
+        let leader_config = SpuConfig {
+            id: 5000,
+            ..Default::default()
+        };
+
+        let replica: ReplicaKey = ("test", 1).into();
+        let state: LeaderReplicaState<MockStorage> = LeaderReplicaState::create(
+            Replica::new(replica, 5000, vec![5000, 5001, 5002]),
+            &leader_config,
+            StatusMessageSink::shared(),
+        )
+        .await
+        .expect("state");
+
+        // 5002 is replaced by 5003
+        state.update_replicas(&[5000, 5001, 5003]).await;
+        assert_eq!(state.live_replicas().await, vec![5001, 5003]);
+
+        Ok(())
+    }
+
     #[test_async]
     async fn test_follower_update() -> Result<(), ()> {
         let leader_config = SpuConfig {
@@ -868,6 +1035,98 @@ mod test_leader {
         Ok(())
     }
 
+    #[test_async]
+    async fn test_in_sync_replicas() -> Result<(), ()> {
+        use fluvio_controlplane_metadata::topic::config::MIN_INSYNC_REPLICAS;
+
+        let mut leader_config = SpuConfig {
+            id: 5000,
+            ..Default::default()
+        };
+        leader_config.replication.replica_lag_time_max = Duration::from_millis(50);
+
+        let notifier = FollowerNotifier::shared();
+
+        let mut replica = Replica::new(("test", 1), 5000, vec![5000, 5001, 5002]);
+        replica
+            .config
+            .set(MIN_INSYNC_REPLICAS, "2")
+            .expect("config");
+        let state: LeaderReplicaState<MockStorage> =
+            LeaderReplicaState::create(replica, &leader_config, StatusMessageSink::shared())
+                .await
+                .expect("state");
+        assert_eq!(state.min_in_sync_replicas(), 2);
+
+        // followers haven't reported offsets yet
+        assert_eq!(state.in_sync_replica_count().await, 1);
+        assert!(!state.has_min_in_sync_replicas().await);
+
+        // 5001 has caught up with leader
+        assert!(
+            state
+                .update_states_from_followers(5001, OffsetInfo { leo: 0, hw: 0 }, &notifier)
+                .await
+        );
+        assert_eq!(state.in_sync_replica_count().await, 2);
+        assert!(state.has_min_in_sync_replicas().await);
+
+        // 5001 is still in sync until lag time is exceeded
+        state
+            .write_record_set(&mut create_recordset(10), &notifier)
+            .await
+            .expect("write");
+        assert_eq!(state.in_sync_replica_count().await, 2);
+
+        sleep(Duration::from_millis(100)).await;
+        assert_eq!(state.in_sync_replica_count().await, 1);
+        assert!(!state.has_min_in_sync_replicas().await);
+
+        Ok(())
+    }
+
+    #[test_async]
+    async fn test_wait_for_hw() -> Result<(), ()> {
+        let leader_config = SpuConfig {
+            id: 5000,
+            ..Default::default()
+        };
+
+        let notifier = FollowerNotifier::shared();
+
+        let replica: ReplicaKey = ("test", 1).into();
+        let state: LeaderReplicaState<MockStorage> = LeaderReplicaState::create(
+            Replica::new(replica, 5000, vec![5000, 5001]),
+            &leader_config,
+            StatusMessageSink::shared(),
+        )
+        .await
+        .expect("state");
+
+        state
+            .write_record_set(&mut create_recordset(10), &notifier)
+            .await
+            .expect("write");
+        assert_eq!(state.hw(), 0);
+
+        // records are not committed
+        assert!(!state.wait_for_hw(10, Duration::from_millis(50)).await);
+
+        // records are committed while waiting
+        let (committed, _) =
+            futures_util::future::join(state.wait_for_hw(10, Duration::from_secs(5)), async {
+                sleep(Duration::from_millis(10)).await;
+                state.update_hw(10).await.expect("hw");
+            })
+            .await;
+        assert!(committed);
+
+        // already committed
+        assert!(state.wait_for_hw(5, Duration::from_millis(50)).await);
+
+        Ok(())
+    }
+
     #[test_async]
     async fn test_update_leader_from_followers() -> Result<(), ()> {
         use crate::core::{GlobalContext};
@@ -0,0 +1,18 @@
+use std::sync::Arc;
+use std::fmt::Debug;
+
+use crate::core::DefaultSharedGlobalContext;
+
+/// SPU global context with authorization
+/// auth is trait object which contains global auth policy
+#[derive(Clone, Debug)]
+pub struct AuthGlobalContext<A> {
+    pub global_ctx: DefaultSharedGlobalContext,
+    pub auth: Arc<A>,
+}
+
+impl<A> AuthGlobalContext<A> {
+    pub fn new(global_ctx: DefaultSharedGlobalContext, auth: Arc<A>) -> Self {
+        Self { global_ctx, auth }
+    }
+}
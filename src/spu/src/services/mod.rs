@@ -1,6 +1,7 @@
 pub(crate) mod public;
 
 pub mod internal;
+pub mod auth;
 
 pub use self::internal::create_internal_server;
-pub use self::public::create_public_server;
+pub use self::public::{create_public_server, create_public_server_with_auth};
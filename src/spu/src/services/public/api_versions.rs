@@ -9,6 +9,9 @@ use fluvio_spu_schema::server::SpuServerApiKey;
 use fluvio_spu_schema::server::fetch_offset::FetchOffsetsRequest;
 use fluvio_spu_schema::server::stream_fetch::DefaultStreamFetchRequest;
 use fluvio_spu_schema::server::update_offset::UpdateOffsetsRequest;
+use fluvio_spu_schema::server::consumer_offset::{
+    CommitConsumerOffsetRequest, FetchConsumerOffsetRequest,
+};
 use fluvio_spu_schema::{ApiVersionsRequest, ApiVersionsResponse};
 
 #[instrument(skip(request))]
@@ -31,7 +34,7 @@ pub async fn handle_kf_lookup_version_request(
     ));
     response.api_keys.push(make_version_key(
         SpuServerApiKey::FetchOffsets,
-        FetchOffsetsRequest::DEFAULT_API_VERSION,
+        0,
         FetchOffsetsRequest::DEFAULT_API_VERSION,
     ));
     response.api_keys.push(make_version_key(
@@ -44,6 +47,16 @@ pub async fn handle_kf_lookup_version_request(
         0,
         UpdateOffsetsRequest::DEFAULT_API_VERSION,
     ));
+    response.api_keys.push(make_version_key(
+        SpuServerApiKey::CommitConsumerOffset,
+        0,
+        CommitConsumerOffsetRequest::DEFAULT_API_VERSION,
+    ));
+    response.api_keys.push(make_version_key(
+        SpuServerApiKey::FetchConsumerOffset,
+        0,
+        FetchConsumerOffsetRequest::DEFAULT_API_VERSION,
+    ));
 
     Ok(request.new_response(response))
 }
@@ -0,0 +1,152 @@
+use std::io::Error as IoError;
+use std::time::Duration;
+
+use tracing::{debug, error, warn, instrument};
+
+use dataplane::ErrorCode;
+use dataplane::api::{RequestMessage, ResponseMessage};
+use fluvio_controlplane_metadata::partition::ReplicaKey;
+use fluvio_controlplane_metadata::extended::ObjectType;
+use fluvio_auth::{AuthContext, InstanceAction};
+use fluvio_spu_schema::server::consumer_offset::{
+    CommitConsumerOffsetRequest, CommitConsumerOffsetResponse, FetchConsumerOffsetRequest,
+    FetchConsumerOffsetResponse, CONSUMER_OFFSETS_TOPIC, consumer_offsets_partition,
+};
+use fluvio_storage::StorageError;
+
+use crate::core::DefaultSharedGlobalContext;
+use crate::replication::leader::SharedFileLeaderState;
+use crate::core::consumer_offsets::{ConsumerOffsetKey, ConsumerOffsetValue, commit_record};
+
+/// time for commit to be replicated to in-sync followers
+const COMMIT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// partition of offsets topic storing offsets of group
+fn offsets_replica(group: &str) -> ReplicaKey {
+    ReplicaKey::new(CONSUMER_OFFSETS_TOPIC, consumer_offsets_partition(group))
+}
+
+#[instrument(skip(req_msg, ctx, auth_ctx))]
+pub async fn handle_commit_consumer_offset<AC: AuthContext>(
+    req_msg: RequestMessage<CommitConsumerOffsetRequest>,
+    ctx: DefaultSharedGlobalContext,
+    auth_ctx: &AC,
+) -> Result<ResponseMessage<CommitConsumerOffsetResponse>, IoError> {
+    let request = req_msg.request();
+    let rep_id = offsets_replica(&request.group);
+    let mut response = CommitConsumerOffsetResponse::default();
+
+    if !auth_ctx
+        .allow_instance_action(ObjectType::Topic, InstanceAction::Consume, &request.topic)
+        .await?
+    {
+        debug!(topic = %request.topic, "commit consumer offset is not authorized");
+        response.error_code = ErrorCode::PermissionDenied;
+    } else if request.offset < 0 {
+        debug!(offset = request.offset, "consumer offset out of range");
+        response.error_code = ErrorCode::OffsetOutOfRange;
+    } else if let Some(leader_state) = ctx.leaders_state().get(&rep_id) {
+        let key = ConsumerOffsetKey {
+            group: request.group.clone(),
+            topic: request.topic.clone(),
+            partition: request.partition,
+        };
+        let value = ConsumerOffsetValue {
+            offset: request.offset,
+            generation: request.generation,
+        };
+        response.error_code = match commit_offset(&ctx, &leader_state, &key, &value).await {
+            Ok(error_code) => error_code,
+            Err(err) => {
+                error!(%rep_id, ?key, "error committing consumer offset: {}", err);
+                ErrorCode::StorageError
+            }
+        };
+    } else {
+        debug!(%rep_id, "not leader of consumer offsets partition");
+        response.error_code = ErrorCode::NotLeaderForPartition;
+    }
+
+    Ok(req_msg.new_response(response))
+}
+
+/// write commit to offsets partition and wait until it is replicated.
+/// Commits of partition are written one at a time, so generation check and write don't interleave
+async fn commit_offset(
+    ctx: &DefaultSharedGlobalContext,
+    leader_state: &SharedFileLeaderState,
+    key: &ConsumerOffsetKey,
+    value: &ConsumerOffsetValue,
+) -> Result<ErrorCode, StorageError> {
+    let partition_offsets = ctx.consumer_offsets().partition(leader_state.id());
+    let mut partition_offsets = partition_offsets.lock().await;
+    partition_offsets.catch_up(leader_state).await?;
+
+    if partition_offsets.is_stale(key, value.generation) {
+        debug!(
+            ?key,
+            generation = value.generation,
+            "commit from stale generation"
+        );
+        return Ok(ErrorCode::IllegalGeneration);
+    }
+
+    let mut records = commit_record(key, value)?;
+    let (_, last_offset) = leader_state
+        .write_record_set(&mut records, ctx.follower_notifier())
+        .await?;
+    if !leader_state
+        .wait_for_hw(last_offset + 1, COMMIT_TIMEOUT)
+        .await
+    {
+        warn!(replica = %leader_state.id(), ?key, "consumer offset not replicated in time");
+        return Ok(ErrorCode::RequestTimedOut);
+    }
+    partition_offsets.catch_up(leader_state).await?;
+    Ok(ErrorCode::None)
+}
+
+#[instrument(skip(req_msg, ctx, auth_ctx))]
+pub async fn handle_fetch_consumer_offset<AC: AuthContext>(
+    req_msg: RequestMessage<FetchConsumerOffsetRequest>,
+    ctx: DefaultSharedGlobalContext,
+    auth_ctx: &AC,
+) -> Result<ResponseMessage<FetchConsumerOffsetResponse>, IoError> {
+    let request = req_msg.request();
+    let rep_id = offsets_replica(&request.group);
+    let mut response = FetchConsumerOffsetResponse::default();
+
+    if !auth_ctx
+        .allow_instance_action(ObjectType::Topic, InstanceAction::Consume, &request.topic)
+        .await?
+    {
+        debug!(topic = %request.topic, "fetch consumer offset is not authorized");
+        response.error_code = ErrorCode::PermissionDenied;
+    } else if let Some(leader_state) = ctx.leaders_state().get(&rep_id) {
+        let key = ConsumerOffsetKey {
+            group: request.group.clone(),
+            topic: request.topic.clone(),
+            partition: request.partition,
+        };
+        let partition_offsets = ctx.consumer_offsets().partition(&rep_id);
+        let mut partition_offsets = partition_offsets.lock().await;
+        match partition_offsets.catch_up(&leader_state).await {
+            Ok(_) => {
+                response.offset = partition_offsets
+                    .get(&key)
+                    .map(|committed| committed.offset);
+                response.generation =
+                    partition_offsets.group_generation(&request.group, &request.topic);
+            }
+            Err(err) => {
+                error!(%rep_id, ?key, "error reading consumer offsets: {}", err);
+                response.error_code = ErrorCode::StorageError;
+            }
+        }
+    } else {
+        debug!(%rep_id, "not leader of consumer offsets partition");
+        response.error_code = ErrorCode::NotLeaderForPartition;
+    }
+
+    Ok(req_msg.new_response(response))
+}
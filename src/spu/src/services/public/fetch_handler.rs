@@ -1,3 +1,5 @@
+use std::io::Error as IoError;
+
 use tracing::{debug, trace, instrument};
 
 use fluvio_socket::ExclusiveFlvSink;
@@ -5,14 +7,17 @@ use fluvio_socket::SocketError;
 use dataplane::{ErrorCode, api::RequestMessage};
 use dataplane::fetch::{FileFetchResponse, FileFetchRequest, FilePartitionResponse, FileTopicResponse};
 use fluvio_controlplane_metadata::partition::ReplicaKey;
+use fluvio_controlplane_metadata::extended::ObjectType;
+use fluvio_auth::{AuthContext, InstanceAction};
 
 use crate::core::DefaultSharedGlobalContext;
 
 /// perform log fetch request using zero copy write
-#[instrument(skip(request, ctx, sink))]
-pub async fn handle_fetch_request(
+#[instrument(skip(request, ctx, auth_ctx, sink))]
+pub async fn handle_fetch_request<AC: AuthContext>(
     request: RequestMessage<FileFetchRequest>,
     ctx: DefaultSharedGlobalContext,
+    auth_ctx: &AC,
     sink: ExclusiveFlvSink,
 ) -> Result<(), SocketError> {
     let (header, fetch_request) = request.get_header_request();
@@ -20,6 +25,10 @@ pub async fn handle_fetch_request(
 
     for topic_request in &fetch_request.topics {
         let topic = &topic_request.name;
+        let allowed = auth_ctx
+            .allow_instance_action(ObjectType::Topic, InstanceAction::Consume, topic)
+            .await
+            .map_err(IoError::from)?;
 
         let mut topic_response = FileTopicResponse {
             name: topic.clone(),
@@ -39,7 +48,10 @@ pub async fn handle_fetch_request(
                 ..Default::default()
             };
 
-            if let Some(leader) = ctx.leaders_state().get(&rep_id) {
+            if !allowed {
+                debug!(%rep_id, "fetch is not authorized");
+                partition_response.error_code = ErrorCode::PermissionDenied;
+            } else if let Some(leader) = ctx.leaders_state().get(&rep_id) {
                 leader
                     .read_records(
                         fetch_offset,
@@ -3,28 +3,49 @@ mod service_impl;
 mod produce_handler;
 mod fetch_handler;
 mod offset_request;
+mod consumer_offset;
 mod stream_fetch;
 
+use std::sync::Arc;
+
 use tracing::info;
 
+use fluvio_auth::RootAuthorization;
 use fluvio_service::FlvApiServer;
 use service_impl::PublicService;
 use fluvio_spu_schema::server::SpuServerRequest;
 use fluvio_spu_schema::server::SpuServerApiKey;
 
 use crate::core::DefaultSharedGlobalContext;
+use crate::services::auth::AuthGlobalContext;
 pub use stream_fetch::publishers::StreamPublishers;
 
-pub(crate) type PublicApiServer =
-    FlvApiServer<SpuServerRequest, SpuServerApiKey, DefaultSharedGlobalContext, PublicService>;
+pub(crate) type PublicApiServer<A> =
+    FlvApiServer<SpuServerRequest, SpuServerApiKey, AuthGlobalContext<A>, PublicService<A>>;
+
+// start server without any authorization
+pub fn create_public_server(
+    addr: String,
+    ctx: DefaultSharedGlobalContext,
+) -> PublicApiServer<RootAuthorization> {
+    create_public_server_with_auth(addr, ctx, Arc::new(RootAuthorization::new()))
+}
 
-// start server
-pub fn create_public_server(addr: String, ctx: DefaultSharedGlobalContext) -> PublicApiServer {
+// start server which enforces authorization on produce and consume requests
+pub fn create_public_server_with_auth<A>(
+    addr: String,
+    ctx: DefaultSharedGlobalContext,
+    auth: Arc<A>,
+) -> PublicApiServer<A> {
     info!(
         "starting SPU: {} at public service at: {}",
         ctx.local_spu_id(),
         addr
     );
 
-    FlvApiServer::new(addr, ctx, PublicService::new())
+    FlvApiServer::new(
+        addr,
+        AuthGlobalContext::new(ctx, auth),
+        PublicService::new(),
+    )
 }
@@ -1,6 +1,6 @@
 use std::io::Error as IoError;
 
-use tracing::{trace, instrument};
+use tracing::{trace, error, instrument};
 
 use dataplane::api::{RequestMessage, ResponseMessage};
 use fluvio_spu_schema::server::fetch_offset::FetchOffsetsRequest;
@@ -8,14 +8,17 @@ use fluvio_spu_schema::server::fetch_offset::FetchOffsetTopicResponse;
 use fluvio_spu_schema::server::fetch_offset::FetchOffsetsResponse;
 use fluvio_spu_schema::server::fetch_offset::FetchOffsetPartitionResponse;
 use fluvio_controlplane_metadata::partition::ReplicaKey;
+use fluvio_controlplane_metadata::extended::ObjectType;
+use fluvio_auth::{AuthContext, InstanceAction};
 use dataplane::ErrorCode;
 
 use crate::core::DefaultSharedGlobalContext;
 
-#[instrument(skip(req_msg, ctx))]
-pub async fn handle_offset_request(
+#[instrument(skip(req_msg, ctx, auth_ctx))]
+pub async fn handle_offset_request<AC: AuthContext>(
     req_msg: RequestMessage<FetchOffsetsRequest>,
     ctx: DefaultSharedGlobalContext,
+    auth_ctx: &AC,
 ) -> Result<ResponseMessage<FetchOffsetsResponse>, IoError> {
     let request = req_msg.request();
     trace!("handling flv fetch request: {:#?}", request);
@@ -24,6 +27,9 @@ pub async fn handle_offset_request(
 
     for topic_request in &request.topics {
         let topic = &topic_request.name;
+        let allowed = auth_ctx
+            .allow_instance_action(ObjectType::Topic, InstanceAction::Consume, topic)
+            .await?;
 
         let mut topic_response = FetchOffsetTopicResponse {
             name: topic.clone(),
@@ -37,12 +43,24 @@ pub async fn handle_offset_request(
                 ..Default::default()
             };
             let rep_id = ReplicaKey::new(topic.clone(), *partition);
-            if let Some(ref replica) = ctx.leaders_state().get(&rep_id) {
+            if !allowed {
+                trace!("offset fetch request is not authorized: {}", rep_id);
+                partition_response.error_code = ErrorCode::PermissionDenied;
+            } else if let Some(ref replica) = ctx.leaders_state().get(&rep_id) {
                 trace!("offset fetch request for replica found: {}", rep_id);
                 let (start_offset, hw) = replica.start_offset_info().await;
                 partition_response.error_code = ErrorCode::None;
                 partition_response.start_offset = start_offset;
                 partition_response.last_stable_offset = hw;
+                if let Some(timestamp) = partition_req.timestamp {
+                    match replica.find_offset_by_timestamp(timestamp).await {
+                        Ok(offset) => partition_response.timestamp_offset = offset,
+                        Err(err) => {
+                            error!(%rep_id, timestamp, "error finding offset: {}", err);
+                            partition_response.error_code = ErrorCode::StorageError;
+                        }
+                    }
+                }
             } else {
                 trace!("offset fetch request is not found: {}", rep_id);
                 partition_response.error_code = ErrorCode::PartitionNotLeader;
@@ -1,41 +1,64 @@
 use std::io::Error;
+use std::time::{Duration, Instant};
 
 use fluvio_storage::StorageError;
-use tracing::{debug, trace, error};
+use tracing::{debug, trace, error, warn};
 use tracing::instrument;
 
-use dataplane::ErrorCode;
+use dataplane::{ErrorCode, Offset};
 use dataplane::produce::{
-    DefaultProduceRequest, ProduceResponse, TopicProduceResponse, PartitionProduceResponse,
+    Acks, DefaultProduceRequest, ProduceResponse, TopicProduceResponse, PartitionProduceResponse,
 };
 use dataplane::api::RequestMessage;
 use dataplane::api::ResponseMessage;
 use fluvio_controlplane_metadata::partition::ReplicaKey;
+use fluvio_controlplane_metadata::extended::ObjectType;
+use fluvio_auth::{AuthContext, InstanceAction};
+use fluvio_types::defaults::CONSUMER_OFFSETS_TOPIC;
 
 use crate::core::DefaultSharedGlobalContext;
+use crate::replication::leader::SharedFileLeaderState;
+
+/// partition written by leader, which has to be committed before response is sent
+struct PendingCommit {
+    topic_index: usize,
+    partition_index: usize,
+    leader_state: SharedFileLeaderState,
+    leo: Offset,
+}
 
 #[instrument(
-    skip(request,ctx),
+    skip(request,ctx,auth_ctx),
     fields(
         id = request.header.correlation_id(),
         client = %request.header.client_id()
     )
 )]
-pub async fn handle_produce_request(
+pub async fn handle_produce_request<AC: AuthContext>(
     request: RequestMessage<DefaultProduceRequest>,
     ctx: DefaultSharedGlobalContext,
+    auth_ctx: &AC,
 ) -> Result<ResponseMessage<ProduceResponse>, Error> {
     let (header, produce_request) = request.get_header_request();
     trace!("handling produce request: {:#?}", produce_request);
 
     let mut response = ProduceResponse::default();
 
-    //let ack = produce_request.acks;
+    let acks = produce_request.required_acks();
+    let timeout = Duration::from_millis(produce_request.timeout_ms.max(0) as u64);
+    let deadline = Instant::now() + timeout;
+    let mut pending_commits = vec![];
 
     for topic_request in produce_request.topics.into_iter() {
         let topic = &topic_request.name;
         trace!("handling produce request for topic{}", topic);
 
+        // internal offsets topic is only written by SPU when offsets are committed
+        let allowed = topic != CONSUMER_OFFSETS_TOPIC
+            && auth_ctx
+                .allow_instance_action(ObjectType::Topic, InstanceAction::Produce, topic)
+                .await?;
+
         let mut topic_response = TopicProduceResponse {
             name: topic.to_owned(),
             ..Default::default()
@@ -51,13 +74,47 @@ pub async fn handle_produce_request(
                 ..Default::default()
             };
 
+            if !allowed {
+                debug!(%rep_id, "produce is not authorized");
+                partition_response.error_code = ErrorCode::PermissionDenied;
+                topic_response.partitions.push(partition_response);
+                continue;
+            }
+
+            let acks = match acks {
+                Ok(acks) => acks,
+                Err(invalid) => {
+                    warn!(%rep_id, acks = invalid, "invalid acks");
+                    partition_response.error_code = ErrorCode::InvalidRequiredAcks;
+                    topic_response.partitions.push(partition_response);
+                    continue;
+                }
+            };
+
             if let Some(leader_state) = ctx.leaders_state().get(&rep_id) {
+                // with acks = all, records must not be written if they can't be committed by min in sync replicas
+                if acks == Acks::All && !leader_state.has_min_in_sync_replicas().await {
+                    warn!(%rep_id, "not enough in sync replicas");
+                    partition_response.error_code = ErrorCode::NotEnoughReplicas;
+                    topic_response.partitions.push(partition_response);
+                    continue;
+                }
+
                 match leader_state
                     .write_record_set(&mut partition_request.records, ctx.follower_notifier())
                     .await
                 {
-                    Ok(_) => {
+                    Ok((base_offset, last_offset)) => {
                         partition_response.error_code = ErrorCode::None;
+                        partition_response.base_offset = base_offset;
+                        if acks == Acks::All {
+                            pending_commits.push(PendingCommit {
+                                topic_index: response.responses.len(),
+                                partition_index: topic_response.partitions.len(),
+                                leo: last_offset + 1,
+                                leader_state,
+                            });
+                        }
                     }
                     Err(err) => {
                         error!("error: {:#?} writing to replica: {}", err, rep_id);
@@ -82,6 +139,25 @@ pub async fn handle_produce_request(
         response.responses.push(topic_response);
     }
 
+    // with acks = all, records must be committed by in-sync replicas within request timeout
+    for pending in pending_commits {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if !pending
+            .leader_state
+            .wait_for_hw(pending.leo, remaining)
+            .await
+        {
+            warn!(
+                replica = %pending.leader_state.id(),
+                leo = pending.leo,
+                hw = pending.leader_state.hw(),
+                "records not committed before timeout"
+            );
+            response.responses[pending.topic_index].partitions[pending.partition_index]
+                .error_code = ErrorCode::RequestTimedOut;
+        }
+    }
+
     trace!("produce request completed");
 
     Ok(RequestMessage::<DefaultProduceRequest>::response_with_header(&header, response))
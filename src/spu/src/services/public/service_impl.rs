@@ -1,4 +1,6 @@
 use std::sync::Arc;
+use std::marker::PhantomData;
+use std::io::Error as IoError;
 
 use tracing::{debug, trace, instrument};
 use async_trait::async_trait;
@@ -6,39 +8,59 @@ use futures_util::stream::StreamExt;
 use tokio::select;
 
 use fluvio_types::event::SimpleEvent;
+use fluvio_auth::Authorization;
 use fluvio_socket::FluvioSocket;
 use fluvio_socket::SocketError;
 use fluvio_service::{call_service, FlvService};
 use fluvio_spu_schema::server::{SpuServerApiKey, SpuServerRequest};
 use dataplane::{ErrorCode, api::RequestMessage};
+use dataplane::produce::Acks;
 
 use crate::core::DefaultSharedGlobalContext;
+use crate::services::auth::AuthGlobalContext;
 use super::api_versions::handle_kf_lookup_version_request;
 use super::produce_handler::handle_produce_request;
 use super::fetch_handler::handle_fetch_request;
 use super::offset_request::handle_offset_request;
+use super::consumer_offset::{handle_commit_consumer_offset, handle_fetch_consumer_offset};
 use super::stream_fetch::StreamFetchHandler;
 
 #[derive(Debug)]
-pub struct PublicService {}
+pub struct PublicService<A> {
+    data: PhantomData<A>,
+}
 
-impl PublicService {
+impl<A> PublicService<A> {
     pub fn new() -> Self {
-        PublicService {}
+        PublicService { data: PhantomData }
     }
 }
 
 #[async_trait]
-impl FlvService for PublicService {
-    type Context = DefaultSharedGlobalContext;
+impl<A> FlvService for PublicService<A>
+where
+    A: Authorization + Sync + Send,
+    <A as Authorization>::Context: Send + Sync,
+{
+    type Context = AuthGlobalContext<A>;
     type Request = SpuServerRequest;
 
-    #[instrument(skip(self, context, socket))]
+    #[instrument(skip(self, ctx, socket))]
     async fn respond(
         self: Arc<Self>,
-        context: DefaultSharedGlobalContext,
-        socket: FluvioSocket,
+        ctx: Self::Context,
+        mut socket: FluvioSocket,
     ) -> Result<(), SocketError> {
+        let auth_ctx = ctx
+            .auth
+            .create_auth_context(&mut socket)
+            .await
+            .map_err(|err| {
+                let io_error: IoError = err.into();
+                io_error
+            })?;
+        let context = ctx.global_ctx;
+
         let (sink, mut stream) = socket.split();
 
         let mut s_sink = sink.as_shared();
@@ -70,17 +92,22 @@ impl FlvService for PublicService {
                                 ),
 
 
-                                SpuServerRequest::ProduceRequest(request) => call_service!(
-                                    request,
-                                    handle_produce_request(request,context.clone()),
-                                    s_sink,
-                                    "roduce request handler"
-                                ),
-                                SpuServerRequest::FileFetchRequest(request) => handle_fetch_request(request,context.clone(),s_sink.clone()).await?,
+                                SpuServerRequest::ProduceRequest(request) => {
+                                    let version = request.header.api_version();
+                                    // with acks = 0, producer doesn't wait for response, so none is sent
+                                    let no_ack = matches!(request.request.required_acks(), Ok(Acks::None));
+                                    let response = handle_produce_request(request,context.clone(),&auth_ctx).await?;
+                                    if no_ack {
+                                        trace!("acks = 0, skipping produce response");
+                                    } else {
+                                        s_sink.send_response(&response, version).await?;
+                                    }
+                                },
+                                SpuServerRequest::FileFetchRequest(request) => handle_fetch_request(request,context.clone(),&auth_ctx,s_sink.clone()).await?,
 
                                 SpuServerRequest::FetchOffsetsRequest(request) => call_service!(
                                     request,
-                                    handle_offset_request(request,context.clone()),
+                                    handle_offset_request(request,context.clone(),&auth_ctx),
                                     s_sink,
                                     "handling offset fetch request"
                                 ),
@@ -90,6 +117,7 @@ impl FlvService for PublicService {
                                         StreamFetchHandler::start(
                                             request,
                                             context.clone(),
+                                            &auth_ctx,
                                             s_sink.clone(),
                                             end_event.clone(),
                                         ).await?;
@@ -103,6 +131,18 @@ impl FlvService for PublicService {
                                         s_sink,
                                         "roduce request handler"
                                     ),
+                                SpuServerRequest::CommitConsumerOffsetRequest(request) => call_service!(
+                                    request,
+                                    handle_commit_consumer_offset(request,context.clone(),&auth_ctx),
+                                    s_sink,
+                                    "commit consumer offset handler"
+                                ),
+                                SpuServerRequest::FetchConsumerOffsetRequest(request) => call_service!(
+                                    request,
+                                    handle_fetch_consumer_offset(request,context.clone(),&auth_ctx),
+                                    s_sink,
+                                    "fetch consumer offset handler"
+                                ),
 
                             }
                         } else {
@@ -20,17 +20,21 @@ use dataplane::{Offset, Isolation, ReplicaKey};
 use dataplane::fetch::FilePartitionResponse;
 use fluvio_spu_schema::server::stream_fetch::{
     FileStreamFetchRequest, DefaultStreamFetchRequest, StreamFetchResponse, SmartStreamWasm,
-    SmartStreamKind,
+    SmartStreamKind, SmartStreamPayload,
 };
 use fluvio_types::event::offsets::OffsetChangeListener;
+use fluvio_controlplane_metadata::extended::ObjectType;
+use fluvio_auth::{AuthContext, InstanceAction};
 
 use crate::core::DefaultSharedGlobalContext;
 use crate::replication::leader::SharedFileLeaderState;
 use publishers::INIT_OFFSET;
-use crate::smart_stream::{SmartStreamEngine, SmartStream};
+use crate::smart_stream::{SmartStreamEngine, SmartStream, SmartStreamModule};
+use crate::smart_stream::chain::SmartStreamChain;
+use crate::smart_stream::map::MapKind;
 use crate::smart_stream::file_batch::FileBatchIterator;
 use dataplane::batch::Batch;
-use dataplane::smartstream::SmartStreamRuntimeError;
+use dataplane::smartstream::{SmartStreamExtraParams, SmartStreamRuntimeError};
 
 /// Fetch records as stream
 pub struct StreamFetchHandler {
@@ -49,10 +53,11 @@ pub struct StreamFetchHandler {
 
 impl StreamFetchHandler {
     /// handle fluvio continuous fetch request
-    #[instrument(skip(request, ctx, sink, end_event))]
-    pub async fn start(
+    #[instrument(skip(request, ctx, auth_ctx, sink, end_event))]
+    pub async fn start<AC: AuthContext>(
         request: RequestMessage<FileStreamFetchRequest>,
         ctx: DefaultSharedGlobalContext,
+        auth_ctx: &AC,
         sink: ExclusiveFlvSink,
         end_event: Arc<SimpleEvent>,
     ) -> Result<(), SocketError> {
@@ -64,7 +69,41 @@ impl StreamFetchHandler {
         let replica = ReplicaKey::new(msg.topic, msg.partition);
         let max_bytes = msg.max_bytes as u32;
 
-        if let Some(leader_state) = ctx.leaders_state().get(&replica) {
+        if !auth_ctx
+            .allow_instance_action(ObjectType::Topic, InstanceAction::Consume, &replica.topic)
+            .await
+            .map_err(IoError::from)?
+        {
+            debug!(%replica, "stream fetch is not authorized, returning");
+            Self::send_error_response(replica, ErrorCode::PermissionDenied, &header, &sink).await?;
+        } else if let Some(leader_state) = ctx.leaders_state().get(&replica) {
+            let mut payloads = msg.wasm_payloads;
+            if payloads.is_empty() {
+                payloads.extend(msg.wasm_payload);
+            }
+            debug!("Has WASM payloads: {}", payloads.len());
+
+            // replace SmartModule names with modules received from SC
+            for payload in payloads.iter_mut() {
+                if let SmartStreamWasm::Named(name) = &payload.wasm {
+                    match ctx.smartmodule_localstore().spec(name) {
+                        Some(smartmodule) => {
+                            payload.wasm = SmartStreamWasm::Raw(smartmodule.spec.wasm);
+                        }
+                        None => {
+                            debug!(%name, "smartmodule not found, returning");
+                            return Self::send_error_response(
+                                replica,
+                                ErrorCode::SmartModuleNotFound,
+                                &header,
+                                &sink,
+                            )
+                            .await;
+                        }
+                    }
+                }
+            }
+
             let (stream_id, offset_publisher) =
                 ctx.stream_publishers().create_new_publisher().await;
             let offset_listener = offset_publisher.change_listner();
@@ -78,55 +117,16 @@ impl StreamFetchHandler {
             );
 
             let sm_engine = SmartStreamEngine::default();
-            debug!("Has WASM payload: {}", msg.wasm_payload.is_some());
-
-            let smartstream = if let Some(payload) = msg.wasm_payload {
-                let SmartStreamWasm::Raw(wasm) = &payload.wasm;
-                let module = sm_engine.create_module_from_binary(wasm).map_err(|err| {
-                    SocketError::Io(IoError::new(
-                        ErrorKind::Other,
-                        format!("module loading error {}", err),
-                    ))
-                })?;
-
-                let smartstream = match payload.kind {
-                    SmartStreamKind::Filter => {
-                        debug!("Instantiating SmartStreamFilter");
-                        let filter = module.create_filter(&sm_engine).map_err(|err| {
-                            SocketError::Io(IoError::new(
-                                ErrorKind::Other,
-                                format!("Failed to instantiate SmartStreamFilter {}", err),
-                            ))
-                        })?;
-                        SmartStream::Filter(filter)
-                    }
-                    SmartStreamKind::Map => {
-                        debug!("Instantiating SmartStreamMap");
-                        let map = module.create_map(&sm_engine).map_err(|err| {
-                            SocketError::Io(IoError::new(
-                                ErrorKind::Other,
-                                format!("Failed to instantiate SmartStreamMap {}", err),
-                            ))
-                        })?;
-                        SmartStream::Map(map)
-                    }
-                    SmartStreamKind::Aggregate { accumulator } => {
-                        let aggregator =
-                            module
-                                .create_aggregate(&sm_engine, accumulator)
-                                .map_err(|err| {
-                                    SocketError::Io(IoError::new(
-                                        ErrorKind::Other,
-                                        format!("Failed to instantiate SmartStreamMap {}", err),
-                                    ))
-                                })?;
-                        SmartStream::Aggregate(aggregator)
-                    }
-                };
-
-                Some(smartstream)
+            let mut stages = payloads
+                .into_iter()
+                .map(|payload| Self::create_smartstream(&sm_engine, payload))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let smartstream = if stages.len() > 1 {
+                debug!("Instantiating SmartStreamChain");
+                Some(SmartStream::Chain(SmartStreamChain::new(stages)))
             } else {
-                None
+                stages.pop()
             };
 
             // if we are filtered we should scan all batches instead of just limit to max bytes
@@ -154,27 +154,112 @@ impl StreamFetchHandler {
             debug!("spawned stream fetch controller");
         } else {
             debug!(topic = %replica.topic," no leader founded, returning");
-            let response = StreamFetchResponse {
-                topic: replica.topic,
-                stream_id: 0,
-                partition: FilePartitionResponse {
-                    partition_index: replica.partition,
-                    error_code: ErrorCode::NotLeaderForPartition,
-                    ..Default::default()
-                },
-            };
+            Self::send_error_response(replica, ErrorCode::NotLeaderForPartition, &header, &sink)
+                .await?;
+        }
 
-            let response_msg =
-                RequestMessage::<FileStreamFetchRequest>::response_with_header(&header, response);
+        Ok(())
+    }
 
-            trace!("sending back file fetch response msg: {:#?}", response_msg);
+    /// create SmartStream instance from payload of request
+    fn create_smartstream(
+        sm_engine: &SmartStreamEngine,
+        payload: SmartStreamPayload,
+    ) -> Result<SmartStream, SocketError> {
+        let wasm = match &payload.wasm {
+            SmartStreamWasm::Raw(wasm) => wasm,
+            SmartStreamWasm::Named(name) => {
+                return Err(SocketError::Io(IoError::new(
+                    ErrorKind::NotFound,
+                    format!("smartmodule {} is not resolved", name),
+                )))
+            }
+        };
+        let module = sm_engine.create_module_from_binary(wasm).map_err(|err| {
+            SocketError::Io(IoError::new(
+                ErrorKind::Other,
+                format!("module loading error {}", err),
+            ))
+        })?;
+
+        let params = payload.params;
+        let smartstream = match payload.kind {
+            SmartStreamKind::Filter => {
+                debug!("Instantiating SmartStreamFilter");
+                let filter = module.create_filter(sm_engine, params).map_err(|err| {
+                    SocketError::Io(IoError::new(
+                        ErrorKind::Other,
+                        format!("Failed to instantiate SmartStreamFilter {}", err),
+                    ))
+                })?;
+                SmartStream::Filter(filter)
+            }
+            SmartStreamKind::Map => Self::create_map(sm_engine, &module, params, MapKind::Map)?,
+            SmartStreamKind::ArrayMap => {
+                Self::create_map(sm_engine, &module, params, MapKind::ArrayMap)?
+            }
+            SmartStreamKind::FilterMap => {
+                Self::create_map(sm_engine, &module, params, MapKind::FilterMap)?
+            }
+            SmartStreamKind::Aggregate { accumulator } => {
+                let aggregator = module
+                    .create_aggregate(sm_engine, params, accumulator)
+                    .map_err(|err| {
+                        SocketError::Io(IoError::new(
+                            ErrorKind::Other,
+                            format!("Failed to instantiate SmartStreamMap {}", err),
+                        ))
+                    })?;
+                SmartStream::Aggregate(aggregator)
+            }
+        };
 
-            let mut inner_sink = sink.lock().await;
-            inner_sink
-                .send_response(&response_msg, header.api_version())
-                .await?;
-        }
+        Ok(smartstream)
+    }
+
+    /// map, array map and filter map only differ in function exported by module
+    fn create_map(
+        sm_engine: &SmartStreamEngine,
+        module: &SmartStreamModule,
+        params: SmartStreamExtraParams,
+        kind: MapKind,
+    ) -> Result<SmartStream, SocketError> {
+        debug!(?kind, "Instantiating SmartStreamMap");
+        let map = module.create_map(sm_engine, params, kind).map_err(|err| {
+            SocketError::Io(IoError::new(
+                ErrorKind::Other,
+                format!("Failed to instantiate SmartStreamMap {:?} {}", kind, err),
+            ))
+        })?;
+        Ok(SmartStream::Map(map))
+    }
+
+    /// reject stream fetch request with error code
+    async fn send_error_response(
+        replica: ReplicaKey,
+        error_code: ErrorCode,
+        header: &RequestHeader,
+        sink: &ExclusiveFlvSink,
+    ) -> Result<(), SocketError> {
+        let response = StreamFetchResponse {
+            topic: replica.topic,
+            stream_id: 0,
+            partition: FilePartitionResponse {
+                partition_index: replica.partition,
+                error_code,
+                ..Default::default()
+            },
+        };
 
+        let response_msg =
+            RequestMessage::<FileStreamFetchRequest>::response_with_header(header, response);
+
+        trace!("sending back file fetch response msg: {:#?}", response_msg);
+
+        let mut inner_sink = sink.lock().await;
+        inner_sink
+            .send_response(&response_msg, header.api_version())
+            .await?;
         Ok(())
     }
 
@@ -359,8 +444,19 @@ impl StreamFetchHandler {
         // We were unable to read any records from this starting offset,
         // therefore the next offset we should try to read is the same starting offset
         if file_partition_response.records.len() == 0 {
-            debug!("empty records, skipping");
-            return Ok((starting_offset, false));
+            if file_partition_response.error_code == ErrorCode::None {
+                debug!("empty records, skipping");
+                return Ok((starting_offset, false));
+            }
+
+            // offset could have been removed by retention, let consumer know about it
+            debug!(
+                error_code = ?file_partition_response.error_code,
+                log_start_offset = file_partition_response.log_start_offset,
+                "unable to read records, sending back error"
+            );
+            self.send_file_response(file_partition_response).await?;
+            return Ok((starting_offset, true));
         }
 
         // If a smartstream module is provided, we need to read records from file to memory
@@ -391,14 +487,14 @@ impl StreamFetchHandler {
                 .await
             }
             Some(SmartStream::Map(map)) => {
-                debug!("Handling SmartStreamMap logic");
+                debug!(kind = ?map.kind(), "Handling SmartStreamMap logic");
 
                 let (batch, smartstream_error) = {
                     let records = &file_partition_response.records;
                     let mut file_batch_iterator =
                         FileBatchIterator::from_raw_slice(records.raw_slice());
 
-                    // Input: FileBatch, Output: MemoryBatch post-filter
+                    // Input: FileBatch, Output: MemoryBatch post-map
                     map.map(&mut file_batch_iterator, self.max_bytes as usize)
                         .map_err(|err| IoError::new(ErrorKind::Other, format!("map err {}", err)))?
                 };
@@ -411,6 +507,30 @@ impl StreamFetchHandler {
                 )
                 .await
             }
+            Some(SmartStream::Chain(chain)) => {
+                debug!("Handling SmartStreamChain logic");
+
+                let (batch, smartstream_error) = {
+                    let records = &file_partition_response.records;
+                    let mut file_batch_iterator =
+                        FileBatchIterator::from_raw_slice(records.raw_slice());
+
+                    // Input: FileBatch, Output: MemoryBatch post-chain
+                    chain
+                        .chain(&mut file_batch_iterator, self.max_bytes as usize)
+                        .map_err(|err| {
+                            IoError::new(ErrorKind::Other, format!("chain err {}", err))
+                        })?
+                };
+
+                self.send_processed_response(
+                    file_partition_response,
+                    next_offset,
+                    batch,
+                    smartstream_error,
+                )
+                .await
+            }
             Some(SmartStream::Aggregate(aggregator)) => {
                 info!("Creating Smart Aggregator");
 
@@ -436,25 +556,7 @@ impl StreamFetchHandler {
                 // If no smartstream is provided, respond using raw file records
                 debug!("No SmartStream, sending back entire log");
 
-                let response = StreamFetchResponse {
-                    topic: self.replica.topic.clone(),
-                    stream_id: self.stream_id,
-                    partition: file_partition_response,
-                };
-
-                let response_msg = RequestMessage::<FileStreamFetchRequest>::response_with_header(
-                    &self.header,
-                    response,
-                );
-
-                trace!("sending back file fetch response msg: {:#?}", response_msg);
-
-                let mut inner_sink = self.sink.lock().await;
-                inner_sink
-                    .encode_file_slices(&response_msg, self.header.api_version())
-                    .await?;
-
-                drop(inner_sink);
+                self.send_file_response(file_partition_response).await?;
 
                 debug!(read_time_ms = %now.elapsed().as_millis(),"finish sending back records");
 
@@ -463,6 +565,30 @@ impl StreamFetchHandler {
         }
     }
 
+    /// send back file records as they are stored
+    async fn send_file_response(
+        &self,
+        file_partition_response: FilePartitionResponse,
+    ) -> Result<(), SocketError> {
+        let response = StreamFetchResponse {
+            topic: self.replica.topic.clone(),
+            stream_id: self.stream_id,
+            partition: file_partition_response,
+        };
+
+        let response_msg =
+            RequestMessage::<FileStreamFetchRequest>::response_with_header(&self.header, response);
+
+        trace!("sending back file fetch response msg: {:#?}", response_msg);
+
+        let mut inner_sink = self.sink.lock().await;
+        inner_sink
+            .encode_file_slices(&response_msg, self.header.api_version())
+            .await?;
+
+        Ok(())
+    }
+
     #[instrument(skip(self, file_partition_response, batch, smartstream_error))]
     async fn send_processed_response(
         &self,
@@ -827,6 +953,7 @@ mod test {
         let wasm_payload = SmartStreamPayload {
             wasm: SmartStreamWasm::Raw(wasm),
             kind: SmartStreamKind::Filter,
+            ..Default::default()
         };
 
         let stream_request = DefaultStreamFetchRequest {
@@ -888,17 +1015,23 @@ mod test {
 
         // another 1 of 3, here base offset should be = 4
         let mut records = create_filter_records(3);
-        replica
-            .write_record_set(&mut records, ctx.follower_notifier())
-            .await
-            .expect("write");
+        assert_eq!(
+            replica
+                .write_record_set(&mut records, ctx.follower_notifier())
+                .await
+                .expect("write"),
+            (4, 6)
+        );
 
         // create another 4, base should be 4 + 3 = 7 and total 10 records
         let mut records = create_filter_records(3);
-        replica
-            .write_record_set(&mut records, ctx.follower_notifier())
-            .await
-            .expect("write");
+        assert_eq!(
+            replica
+                .write_record_set(&mut records, ctx.follower_notifier())
+                .await
+                .expect("write"),
+            (7, 9)
+        );
         assert_eq!(replica.hw(), 10);
 
         debug!("2nd filter batch, hw=10");
@@ -970,6 +1103,7 @@ mod test {
         let wasm_payload = SmartStreamPayload {
             wasm: SmartStreamWasm::Raw(wasm),
             kind: SmartStreamKind::Filter,
+            ..Default::default()
         };
 
         let stream_request = DefaultStreamFetchRequest {
@@ -1065,6 +1199,7 @@ mod test {
         let wasm_payload = SmartStreamPayload {
             wasm: SmartStreamWasm::Raw(wasm),
             kind: SmartStreamKind::Filter,
+            ..Default::default()
         };
 
         let stream_request = DefaultStreamFetchRequest {
@@ -1124,7 +1259,7 @@ mod test {
                 assert_eq!(error.record_value.as_ref(), "ten".as_bytes());
                 assert_eq!(error.kind, SmartStreamType::Filter);
                 let rendered = format!("{}", error);
-                assert_eq!(rendered, "Oops something went wrong\n\nCaused by:\n   0: Failed to parse int\n   1: invalid digit found in string\n\nSmartStream Info: \n    Type: Filter\n    Offset: 10\n    Key: NULL\n    Value: ten");
+                assert_eq!(rendered, "Oops something went wrong\n\nCaused by:\n   0: Failed to parse int\n   1: invalid digit found in string\n\nSmartStream Info: \n    Type: Filter\n    Stage: 0\n    Offset: 10\n    Key: NULL\n    Value: ten");
             }
             _ => panic!("should have gotten error code"),
         }
@@ -1205,6 +1340,7 @@ mod test {
         let wasm_payload = SmartStreamPayload {
             wasm: SmartStreamWasm::Raw(wasm),
             kind: SmartStreamKind::Filter,
+            ..Default::default()
         };
 
         let stream_request = DefaultStreamFetchRequest {
@@ -1318,6 +1454,7 @@ mod test {
         let wasm_payload = SmartStreamPayload {
             wasm: SmartStreamWasm::Raw(wasm),
             kind: SmartStreamKind::Map,
+            ..Default::default()
         };
 
         let stream_request = DefaultStreamFetchRequest {
@@ -1385,6 +1522,173 @@ mod test {
         debug!("terminated controller");
     }
 
+    #[fluvio_future::test(ignore)]
+    async fn test_stream_fetch_filter_map() {
+        let test_path = temp_dir().join("test_stream_fetch_filter_map");
+        ensure_clean_dir(&test_path);
+
+        let addr = "127.0.0.1:12008";
+        let mut spu_config = SpuConfig::default();
+        spu_config.log.base_dir = test_path;
+        let ctx = GlobalContext::new_shared_context(spu_config);
+
+        let server_end_event = create_public_server(addr.to_owned(), ctx.clone()).run();
+
+        // wait for stream controller async to start
+        sleep(Duration::from_millis(100)).await;
+
+        let client_socket =
+            MultiplexerSocket::new(FluvioSocket::connect(addr).await.expect("connect"));
+
+        let topic = "test_filter_map";
+        let test = Replica::new((topic.to_owned(), 0), 5001, vec![5001]);
+        let test_id = test.id.clone();
+        let replica = LeaderReplicaState::create(test, ctx.config(), ctx.status_update_owned())
+            .await
+            .expect("replica");
+        ctx.leaders_state().insert(test_id, replica.clone());
+
+        let wasm = load_wasm_module("fluvio_wasm_filter_map");
+        let wasm_payload = SmartStreamPayload {
+            wasm: SmartStreamWasm::Raw(wasm),
+            kind: SmartStreamKind::FilterMap,
+            ..Default::default()
+        };
+
+        let stream_request = DefaultStreamFetchRequest {
+            topic: topic.to_owned(),
+            partition: 0,
+            fetch_offset: 0,
+            isolation: Isolation::ReadUncommitted,
+            max_bytes: 10000,
+            wasm_module: Vec::new(),
+            wasm_payload: Some(wasm_payload),
+            ..Default::default()
+        };
+
+        let mut stream = client_socket
+            .create_stream(RequestMessage::new_request(stream_request), 11)
+            .await
+            .expect("create stream");
+
+        let mut records: RecordSet = BatchProducer::builder()
+            .records(10u16)
+            .record_generator(Arc::new(|i, _| Record::new(i.to_string())))
+            .build()
+            .expect("batch")
+            .records();
+
+        replica
+            .write_record_set(&mut records, ctx.follower_notifier())
+            .await
+            .expect("write");
+
+        debug!("first filter map fetch");
+        let response = stream.next().await.expect("first").expect("response");
+
+        assert_eq!(response.partition.error_code, ErrorCode::None);
+        assert_eq!(response.partition.records.batches.len(), 1);
+        let records = response.partition.records.batches[0].records();
+        assert_eq!(records.len(), 5);
+        assert_eq!(records[0].value.as_ref(), "0".as_bytes());
+        assert_eq!(records[1].value.as_ref(), "1".as_bytes());
+        assert_eq!(records[2].value.as_ref(), "2".as_bytes());
+        assert_eq!(records[3].value.as_ref(), "3".as_bytes());
+        assert_eq!(records[4].value.as_ref(), "4".as_bytes());
+
+        drop(response);
+
+        server_end_event.notify();
+        debug!("terminated controller");
+    }
+
+    #[fluvio_future::test(ignore)]
+    async fn test_stream_fetch_chain() {
+        let test_path = temp_dir().join("test_stream_fetch_chain");
+        ensure_clean_dir(&test_path);
+
+        let addr = "127.0.0.1:12009";
+        let mut spu_config = SpuConfig::default();
+        spu_config.log.base_dir = test_path;
+        let ctx = GlobalContext::new_shared_context(spu_config);
+
+        let server_end_event = create_public_server(addr.to_owned(), ctx.clone()).run();
+
+        // wait for stream controller async to start
+        sleep(Duration::from_millis(100)).await;
+
+        let client_socket =
+            MultiplexerSocket::new(FluvioSocket::connect(addr).await.expect("connect"));
+
+        let topic = "test_chain";
+        let test = Replica::new((topic.to_owned(), 0), 5001, vec![5001]);
+        let test_id = test.id.clone();
+        let replica = LeaderReplicaState::create(test, ctx.config(), ctx.status_update_owned())
+            .await
+            .expect("replica");
+        ctx.leaders_state().insert(test_id, replica.clone());
+
+        // keep even numbers and divide them by two, then double them
+        let wasm_payloads = vec![
+            SmartStreamPayload {
+                wasm: SmartStreamWasm::Raw(load_wasm_module("fluvio_wasm_filter_map")),
+                kind: SmartStreamKind::FilterMap,
+                ..Default::default()
+            },
+            SmartStreamPayload {
+                wasm: SmartStreamWasm::Raw(load_wasm_module("fluvio_wasm_map_double")),
+                kind: SmartStreamKind::Map,
+                ..Default::default()
+            },
+        ];
+
+        let stream_request = DefaultStreamFetchRequest {
+            topic: topic.to_owned(),
+            partition: 0,
+            fetch_offset: 0,
+            isolation: Isolation::ReadUncommitted,
+            max_bytes: 10000,
+            wasm_module: Vec::new(),
+            wasm_payloads,
+            ..Default::default()
+        };
+
+        let mut stream = client_socket
+            .create_stream(RequestMessage::new_request(stream_request), 16)
+            .await
+            .expect("create stream");
+
+        let mut records: RecordSet = BatchProducer::builder()
+            .records(10u16)
+            .record_generator(Arc::new(|i, _| Record::new(i.to_string())))
+            .build()
+            .expect("batch")
+            .records();
+
+        replica
+            .write_record_set(&mut records, ctx.follower_notifier())
+            .await
+            .expect("write");
+
+        debug!("first chain fetch");
+        let response = stream.next().await.expect("first").expect("response");
+
+        assert_eq!(response.partition.error_code, ErrorCode::None);
+        assert_eq!(response.partition.records.batches.len(), 1);
+        let records = response.partition.records.batches[0].records();
+        assert_eq!(records.len(), 5);
+        assert_eq!(records[0].value.as_ref(), "0".as_bytes());
+        assert_eq!(records[1].value.as_ref(), "2".as_bytes());
+        assert_eq!(records[2].value.as_ref(), "4".as_bytes());
+        assert_eq!(records[3].value.as_ref(), "6".as_bytes());
+        assert_eq!(records[4].value.as_ref(), "8".as_bytes());
+
+        drop(response);
+
+        server_end_event.notify();
+        debug!("terminated controller");
+    }
+
     #[fluvio_future::test(ignore)]
     async fn test_stream_aggregate_fetch() {
         let test_path = temp_dir().join("aggregate_stream_fetch");
@@ -1417,6 +1721,7 @@ mod test {
             kind: SmartStreamKind::Aggregate {
                 accumulator: Vec::from("789".repeat(100)),
             },
+            ..Default::default()
         };
 
         let stream_request = DefaultStreamFetchRequest {
@@ -1494,4 +1799,116 @@ mod test {
 
         server_end_event.notify();
     }
+
+    #[fluvio_future::test(ignore)]
+    async fn test_stream_fetch_named_smartmodule() {
+        use fluvio_controlplane_metadata::smartmodule::{SmartModule, SmartModuleSpec, SmartModuleKind};
+
+        let test_path = temp_dir().join("test_stream_fetch_named_smartmodule");
+        ensure_clean_dir(&test_path);
+
+        let addr = "127.0.0.1:12010";
+        let mut spu_config = SpuConfig::default();
+        spu_config.log.base_dir = test_path;
+        let ctx = GlobalContext::new_shared_context(spu_config);
+
+        let server_end_event = create_public_server(addr.to_owned(), ctx.clone()).run();
+
+        // wait for stream controller async to start
+        sleep(Duration::from_millis(100)).await;
+
+        let client_socket =
+            MultiplexerSocket::new(FluvioSocket::connect(addr).await.expect("connect"));
+
+        let topic = "test_named_smartmodule";
+        let test = Replica::new((topic.to_owned(), 0), 5001, vec![5001]);
+        let test_id = test.id.clone();
+        let replica = LeaderReplicaState::create(test, ctx.config(), ctx.status_update_owned())
+            .await
+            .expect("replica");
+        ctx.leaders_state().insert(test_id, replica.clone());
+
+        // same as received from SC
+        ctx.smartmodule_localstore().insert(SmartModule {
+            name: "filter-map".to_owned(),
+            spec: SmartModuleSpec::new(
+                SmartModuleKind::FilterMap,
+                load_wasm_module("fluvio_wasm_filter_map"),
+            ),
+        });
+
+        // unknown smartmodule is rejected
+        let stream_request = DefaultStreamFetchRequest {
+            topic: topic.to_owned(),
+            partition: 0,
+            fetch_offset: 0,
+            isolation: Isolation::ReadUncommitted,
+            max_bytes: 10000,
+            wasm_payload: Some(SmartStreamPayload {
+                wasm: SmartStreamWasm::Named("unknown".to_owned()),
+                kind: SmartStreamKind::FilterMap,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let mut stream = client_socket
+            .create_stream(RequestMessage::new_request(stream_request), 11)
+            .await
+            .expect("create stream");
+
+        let response = stream.next().await.expect("first").expect("response");
+        assert_eq!(
+            response.partition.error_code,
+            ErrorCode::SmartModuleNotFound
+        );
+        drop(response);
+        drop(stream);
+
+        let stream_request = DefaultStreamFetchRequest {
+            topic: topic.to_owned(),
+            partition: 0,
+            fetch_offset: 0,
+            isolation: Isolation::ReadUncommitted,
+            max_bytes: 10000,
+            wasm_payload: Some(SmartStreamPayload {
+                wasm: SmartStreamWasm::Named("filter-map".to_owned()),
+                kind: SmartStreamKind::FilterMap,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let mut stream = client_socket
+            .create_stream(RequestMessage::new_request(stream_request), 11)
+            .await
+            .expect("create stream");
+
+        let mut records: RecordSet = BatchProducer::builder()
+            .records(10u16)
+            .record_generator(Arc::new(|i, _| Record::new(i.to_string())))
+            .build()
+            .expect("batch")
+            .records();
+
+        replica
+            .write_record_set(&mut records, ctx.follower_notifier())
+            .await
+            .expect("write");
+
+        debug!("first named smartmodule fetch");
+        let response = stream.next().await.expect("first").expect("response");
+
+        assert_eq!(response.partition.error_code, ErrorCode::None);
+        assert_eq!(response.partition.records.batches.len(), 1);
+        let records = response.partition.records.batches[0].records();
+        assert_eq!(records.len(), 5);
+        assert_eq!(records[0].value.as_ref(), "0".as_bytes());
+        assert_eq!(records[4].value.as_ref(), "4".as_bytes());
+
+        drop(response);
+
+        server_end_event.notify();
+        debug!("terminated controller");
+    }
 }
@@ -9,13 +9,14 @@ use tracing::debug;
 use wasmtime::{Caller, Extern, Func, Instance, Trap, TypedFunc, Store};
 
 use dataplane::core::{Decoder, Encoder};
+use dataplane::Offset;
 use dataplane::batch::Batch;
 use dataplane::batch::MemoryRecords;
 use crate::smart_stream::{RecordsCallBack, RecordsMemory, SmartStreamEngine, SmartStreamModule};
 use crate::smart_stream::file_batch::FileBatchIterator;
 use dataplane::smartstream::{
-    SmartStreamRuntimeError, SmartStreamAggregateInput, SmartStreamInput, SmartStreamOutput,
-    SmartStreamInternalError,
+    SmartStreamExtraParams, SmartStreamRuntimeError, SmartStreamAggregateInput, SmartStreamInput,
+    SmartStreamOutput, SmartStreamInternalError,
 };
 
 const AGGREGATE_FN_NAME: &str = "aggregate";
@@ -26,6 +27,7 @@ pub struct SmartStreamAggregate {
     instance: Instance,
     aggregate_fn: AggregateFn,
     records_cb: Arc<RecordsCallBack>,
+    params: SmartStreamExtraParams,
     accumulator: Vec<u8>,
 }
 
@@ -33,6 +35,7 @@ impl SmartStreamAggregate {
     pub fn new(
         engine: &SmartStreamEngine,
         module: &SmartStreamModule,
+        params: SmartStreamExtraParams,
         accumulator: Vec<u8>,
     ) -> Result<Self> {
         let mut store = Store::new(&engine.0, ());
@@ -61,6 +64,7 @@ impl SmartStreamAggregate {
             aggregate_fn,
             instance,
             records_cb,
+            params,
             accumulator,
         })
     }
@@ -98,46 +102,7 @@ impl SmartStreamAggregate {
                 "starting aggregate processing"
             );
 
-            let now = Instant::now();
-            self.records_cb.clear();
-
-            let smartstream_input = SmartStreamAggregateInput {
-                base: SmartStreamInput {
-                    base_offset: file_batch.batch.base_offset,
-                    record_data: file_batch.records.clone(),
-                },
-                accumulator: self.accumulator.clone(),
-            };
-
-            let mut input_data = vec![];
-            fluvio_protocol::Encoder::encode(&smartstream_input, &mut input_data, 0)?;
-
-            let aggregate_ptr = super::memory::copy_memory_to_instance(
-                &mut self.store,
-                &self.instance,
-                &input_data,
-            )?;
-            let aggregate_args = (aggregate_ptr as i32, input_data.len() as i32);
-
-            let aggregate_output = self.aggregate_fn.call(&mut self.store, aggregate_args)?;
-            debug!(aggregate_output, filter_execution_time = %now.elapsed().as_millis());
-
-            if aggregate_output < 0 {
-                let internal_error = SmartStreamInternalError::try_from(aggregate_output)
-                    .unwrap_or(SmartStreamInternalError::UnknownError);
-                return Err(internal_error.into());
-            }
-
-            let output_bytes = self
-                .records_cb
-                .get()
-                .and_then(|m| m.copy_memory_from(&mut self.store).ok())
-                .unwrap_or_default();
-            debug!(out_filter_bytes = output_bytes.len());
-
-            // this is inefficient for now
-            let mut output = SmartStreamOutput::default();
-            output.decode(&mut Cursor::new(output_bytes), 0)?;
+            let output = self.process(file_batch.base_offset(), file_batch.records.clone())?;
 
             let maybe_error = output.error;
             let mut records = output.successes;
@@ -146,14 +111,6 @@ impl SmartStreamAggregate {
             if records.is_empty() {
                 debug!("Aggregate records empty");
             } else {
-                // If any records came back, take the last one and set it as
-                // the new accumulator state.
-                let latest_accumulator = records.iter().last();
-                if let Some(latest) = latest_accumulator {
-                    debug!(?latest, "Got most recent accumulator:");
-                    self.accumulator = Vec::from(latest.value.as_ref());
-                }
-
                 // set base offset if this is first time
                 if aggregate_batch.base_offset == -1 {
                     aggregate_batch.base_offset = file_batch.base_offset();
@@ -161,7 +118,7 @@ impl SmartStreamAggregate {
 
                 // difference between filter batch and and current batch
                 // since base are different we need update delta offset for each records
-                let relative_base_offset = aggregate_batch.base_offset - file_batch.base_offset();
+                let relative_base_offset = file_batch.base_offset() - aggregate_batch.base_offset;
 
                 for record in &mut records {
                     record.add_base_offset(relative_base_offset);
@@ -179,6 +136,7 @@ impl SmartStreamAggregate {
                 }
 
                 total_bytes += record_bytes;
+                file_batch.rebase_timestamps(&mut aggregate_batch, &mut records);
 
                 debug!(
                     filter_records = records.len(),
@@ -202,4 +160,60 @@ impl SmartStreamAggregate {
             }
         }
     }
+
+    /// process records of single batch
+    pub fn process(
+        &mut self,
+        base_offset: Offset,
+        record_data: Vec<u8>,
+    ) -> Result<SmartStreamOutput> {
+        let now = Instant::now();
+        self.records_cb.clear();
+
+        let smartstream_input = SmartStreamAggregateInput {
+            base: SmartStreamInput {
+                base_offset,
+                record_data,
+                params: self.params.clone(),
+            },
+            accumulator: self.accumulator.clone(),
+        };
+
+        let mut input_data = vec![];
+        fluvio_protocol::Encoder::encode(&smartstream_input, &mut input_data, 0)?;
+
+        let aggregate_ptr =
+            super::memory::copy_memory_to_instance(&mut self.store, &self.instance, &input_data)?;
+        let aggregate_args = (aggregate_ptr as i32, input_data.len() as i32);
+
+        let aggregate_output = self.aggregate_fn.call(&mut self.store, aggregate_args)?;
+        debug!(aggregate_output, filter_execution_time = %now.elapsed().as_millis());
+
+        if aggregate_output < 0 {
+            let internal_error = SmartStreamInternalError::try_from(aggregate_output)
+                .unwrap_or(SmartStreamInternalError::UnknownError);
+            return Err(internal_error.into());
+        }
+
+        let output_bytes = self
+            .records_cb
+            .get()
+            .and_then(|m| m.copy_memory_from(&mut self.store).ok())
+            .unwrap_or_default();
+        debug!(out_filter_bytes = output_bytes.len());
+
+        // this is inefficient for now
+        let mut output = SmartStreamOutput::default();
+        output.decode(&mut Cursor::new(output_bytes), 0)?;
+
+        // If any records came back, take the last one and set it as
+        // the new accumulator state.
+        let latest_accumulator = output.successes.iter().last();
+        if let Some(latest) = latest_accumulator {
+            debug!(?latest, "Got most recent accumulator:");
+            self.accumulator = Vec::from(latest.value.as_ref());
+        }
+
+        Ok(output)
+    }
 }
@@ -0,0 +1,153 @@
+use anyhow::{Result, Error};
+
+use tracing::debug;
+
+use dataplane::Offset;
+use dataplane::core::Encoder;
+use dataplane::batch::Batch;
+use dataplane::batch::MemoryRecords;
+use dataplane::smartstream::{SmartStreamOutput, SmartStreamRuntimeError};
+use crate::smart_stream::SmartStream;
+use crate::smart_stream::file_batch::FileBatchIterator;
+
+/// SmartStreams executed in sequence, output records of each stage are input of next stage
+pub struct SmartStreamChain {
+    stages: Vec<SmartStream>,
+}
+
+impl SmartStreamChain {
+    pub fn new(stages: Vec<SmartStream>) -> Self {
+        Self { stages }
+    }
+
+    /// process batches through all stages with maximum bytes to be send back consumer
+    pub fn chain(
+        &mut self,
+        iter: &mut FileBatchIterator,
+        max_bytes: usize,
+    ) -> Result<(Batch, Option<SmartStreamRuntimeError>), Error> {
+        let mut memory_chain_batch = Batch::<MemoryRecords>::default();
+        memory_chain_batch.base_offset = -1; // indicate this is unitialized
+        memory_chain_batch.set_offset_delta(-1); // make add_to_offset_delta correctly
+
+        let mut total_bytes = 0;
+
+        loop {
+            let file_batch = match iter.next() {
+                // we process entire batches.  entire batches are process as group
+                // if we can't fit current batch into max bytes then it is discarded
+                Some(batch_result) => batch_result?,
+                None => {
+                    debug!(
+                        total_records = memory_chain_batch.records().len(),
+                        "no more batches chain end"
+                    );
+                    return Ok((memory_chain_batch, None));
+                }
+            };
+
+            debug!(
+                current_batch_offset = file_batch.batch.base_offset,
+                current_batch_offset_delta = file_batch.offset_delta(),
+                chain_offset_delta = memory_chain_batch.get_header().last_offset_delta,
+                chain_base_offset = memory_chain_batch.base_offset,
+                chain_records = memory_chain_batch.records().len(),
+                stages = self.stages.len(),
+                "starting chain processing"
+            );
+
+            let output = self.process(file_batch.base_offset(), file_batch.records.clone())?;
+
+            let maybe_error = output.error;
+            let mut records = output.successes;
+
+            if records.is_empty() {
+                debug!("chain records empty");
+            } else {
+                // set base offset if this is first time
+                if memory_chain_batch.base_offset == -1 {
+                    memory_chain_batch.base_offset = file_batch.base_offset();
+                }
+
+                // difference between chain batch and and current batch
+                // since base are different we need update delta offset for each records
+                let relative_base_offset =
+                    file_batch.base_offset() - memory_chain_batch.base_offset;
+
+                for record in &mut records {
+                    record.add_base_offset(relative_base_offset);
+                }
+
+                let record_bytes = records.write_size(0);
+
+                // if chain bytes exceed max bytes then we skip this batch
+                if total_bytes + record_bytes > max_bytes {
+                    debug!(
+                        total_bytes = total_bytes + record_bytes,
+                        max_bytes, "total chain bytes reached"
+                    );
+                    return Ok((memory_chain_batch, maybe_error));
+                }
+
+                total_bytes += record_bytes;
+                file_batch.rebase_timestamps(&mut memory_chain_batch, &mut records);
+
+                debug!(chain_records = records.len(), total_bytes, "finished chain");
+                memory_chain_batch.mut_records().append(&mut records);
+            }
+
+            // only increment chain offset delta if chain_batch has been initialized
+            if memory_chain_batch.base_offset != -1 {
+                debug!(
+                    offset_delta = file_batch.offset_delta(),
+                    "adding to offset delta"
+                );
+                memory_chain_batch.add_to_offset_delta(file_batch.offset_delta() + 1);
+            }
+
+            // If any stage had an error, return current batch and error
+            if maybe_error.is_some() {
+                return Ok((memory_chain_batch, maybe_error));
+            }
+        }
+    }
+
+    /// process records of single batch through all stages.
+    ///
+    /// Records before a failed record are still passed to next stages.
+    /// Error of later stage replaces error of earlier stage,
+    /// since it stops at lower offset.
+    pub fn process(
+        &mut self,
+        base_offset: Offset,
+        record_data: Vec<u8>,
+    ) -> Result<SmartStreamOutput> {
+        let mut record_data = record_data;
+        let mut output = SmartStreamOutput::default();
+
+        for (stage, smartstream) in self.stages.iter_mut().enumerate() {
+            let stage_output = smartstream.process(base_offset, record_data)?;
+            debug!(
+                stage,
+                records = stage_output.successes.len(),
+                has_error = stage_output.error.is_some(),
+                "finished chain stage"
+            );
+
+            output.successes = stage_output.successes;
+            if let Some(mut error) = stage_output.error {
+                error.stage = stage as u32;
+                output.error = Some(error);
+            }
+
+            if output.successes.is_empty() {
+                break;
+            }
+
+            record_data = Vec::new();
+            output.successes.encode(&mut record_data, 0)?;
+        }
+
+        Ok(output)
+    }
+}
@@ -1,5 +1,8 @@
 use dataplane::batch::{Batch, BATCH_FILE_HEADER_SIZE, BATCH_HEADER_SIZE};
+use dataplane::compression::Compression;
+use dataplane::record::Record;
 use dataplane::Offset;
+use std::cmp::max;
 use std::io::{Error as IoError, ErrorKind, Cursor};
 use tracing::{warn, debug};
 use std::os::unix::io::RawFd;
@@ -20,6 +23,26 @@ impl FileBatch {
     pub(crate) fn offset_delta(&self) -> i32 {
         self.batch.header.last_offset_delta
     }
+
+    /// make timestamps of records relative to first timestamp of target batch,
+    /// since records from multiple batches are combined into target
+    pub(crate) fn rebase_timestamps(&self, target: &mut Batch, records: &mut [Record]) {
+        let header = self.batch.get_header();
+        let target_header = target.get_mut_header();
+        for record in records.iter_mut() {
+            let timestamp = header.record_timestamp(record.preamble.timestamp_delta());
+            if timestamp < 0 {
+                continue;
+            }
+            if target_header.first_timestamp <= 0 {
+                target_header.first_timestamp = timestamp;
+            }
+            record
+                .preamble
+                .set_timestamp_delta(timestamp - target_header.first_timestamp);
+            target_header.max_time_stamp = max(target_header.max_time_stamp, timestamp);
+        }
+    }
 }
 
 /// Iterator that returns batch from file
@@ -119,6 +142,16 @@ impl Iterator for FileBatchIterator {
 
         self.offset += bytes_read as i64;
 
+        // smartstream operates on uncompressed records
+        let records = match batch.compression() {
+            Ok(Compression::None) => records,
+            Ok(compression) => match compression.uncompress(&records) {
+                Ok(uncompressed) => uncompressed,
+                Err(err) => return Some(Err(err.into())),
+            },
+            Err(err) => return Some(Err(err.into())),
+        };
+
         debug!(file_offset = self.offset, "fbatch end");
 
         Some(Ok(FileBatch { batch, records }))
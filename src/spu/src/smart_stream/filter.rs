@@ -8,10 +8,12 @@ use anyhow::{Result, Error};
 use tracing::debug;
 use wasmtime::{Caller, Extern, Func, Instance, Trap, TypedFunc, Store};
 
+use dataplane::Offset;
 use dataplane::batch::Batch;
 use dataplane::batch::MemoryRecords;
 use dataplane::smartstream::{
-    SmartStreamInput, SmartStreamOutput, SmartStreamRuntimeError, SmartStreamInternalError,
+    SmartStreamExtraParams, SmartStreamInput, SmartStreamOutput, SmartStreamRuntimeError,
+    SmartStreamInternalError,
 };
 use fluvio_protocol::{Encoder, Decoder};
 use crate::smart_stream::{RecordsCallBack, RecordsMemory, SmartStreamModule, SmartStreamEngine};
@@ -25,10 +27,15 @@ pub struct SmartStreamFilter {
     instance: Instance,
     filter_fn: FilterFn,
     records_cb: Arc<RecordsCallBack>,
+    params: SmartStreamExtraParams,
 }
 
 impl SmartStreamFilter {
-    pub fn new(engine: &SmartStreamEngine, module: &SmartStreamModule) -> Result<Self> {
+    pub fn new(
+        engine: &SmartStreamEngine,
+        module: &SmartStreamModule,
+        params: SmartStreamExtraParams,
+    ) -> Result<Self> {
         let mut store = Store::new(&engine.0, ());
         let cb = Arc::new(RecordsCallBack::new());
         let callback = cb.clone();
@@ -56,6 +63,7 @@ impl SmartStreamFilter {
             instance,
             filter_fn,
             records_cb: callback,
+            params,
         })
     }
 
@@ -94,44 +102,7 @@ impl SmartStreamFilter {
                 "starting filter processing"
             );
 
-            let now = Instant::now();
-
-            let mut input_data = Vec::new();
-            let smartstream_input = SmartStreamInput {
-                base_offset: file_batch.batch.base_offset,
-                record_data: file_batch.records.clone(),
-            };
-            fluvio_protocol::Encoder::encode(&smartstream_input, &mut input_data, 0)?;
-
-            self.records_cb.clear();
-            let array_ptr = super::memory::copy_memory_to_instance(
-                &mut self.store,
-                &self.instance,
-                &input_data,
-            )?;
-
-            let filter_output = self
-                .filter_fn
-                .call(&mut self.store, (array_ptr as i32, input_data.len() as i32))?;
-
-            debug!(filter_output,filter_execution_time = %now.elapsed().as_millis());
-
-            if filter_output < 0 {
-                let internal_error = SmartStreamInternalError::try_from(filter_output)
-                    .unwrap_or(SmartStreamInternalError::UnknownError);
-                return Err(internal_error.into());
-            }
-
-            let bytes = self
-                .records_cb
-                .get()
-                .and_then(|m| m.copy_memory_from(&mut self.store).ok())
-                .unwrap_or_default();
-            debug!(out_filter_bytes = bytes.len());
-
-            // this is inefficient for now
-            let mut output = SmartStreamOutput::default();
-            output.decode(&mut Cursor::new(bytes), 0)?;
+            let output = self.process(file_batch.base_offset(), file_batch.records.clone())?;
 
             let maybe_error = output.error;
             let mut records = output.successes;
@@ -148,7 +119,7 @@ impl SmartStreamFilter {
                 // difference between filter batch and and current batch
                 // since base are different we need update delta offset for each records
                 let relative_base_offset =
-                    memory_filter_batch.base_offset - file_batch.base_offset();
+                    file_batch.base_offset() - memory_filter_batch.base_offset;
 
                 for record in &mut records {
                     record.add_base_offset(relative_base_offset);
@@ -166,6 +137,7 @@ impl SmartStreamFilter {
                 }
 
                 total_bytes += record_bytes;
+                file_batch.rebase_timestamps(&mut memory_filter_batch, &mut records);
 
                 debug!(
                     filter_records = records.len(),
@@ -189,4 +161,50 @@ impl SmartStreamFilter {
             }
         }
     }
+
+    /// process records of single batch
+    pub fn process(
+        &mut self,
+        base_offset: Offset,
+        record_data: Vec<u8>,
+    ) -> Result<SmartStreamOutput> {
+        let now = Instant::now();
+
+        let mut input_data = Vec::new();
+        let smartstream_input = SmartStreamInput {
+            base_offset,
+            record_data,
+            params: self.params.clone(),
+        };
+        fluvio_protocol::Encoder::encode(&smartstream_input, &mut input_data, 0)?;
+
+        self.records_cb.clear();
+        let array_ptr =
+            super::memory::copy_memory_to_instance(&mut self.store, &self.instance, &input_data)?;
+
+        let filter_output = self
+            .filter_fn
+            .call(&mut self.store, (array_ptr as i32, input_data.len() as i32))?;
+
+        debug!(filter_output,filter_execution_time = %now.elapsed().as_millis());
+
+        if filter_output < 0 {
+            let internal_error = SmartStreamInternalError::try_from(filter_output)
+                .unwrap_or(SmartStreamInternalError::UnknownError);
+            return Err(internal_error.into());
+        }
+
+        let bytes = self
+            .records_cb
+            .get()
+            .and_then(|m| m.copy_memory_from(&mut self.store).ok())
+            .unwrap_or_default();
+        debug!(out_filter_bytes = bytes.len());
+
+        // this is inefficient for now
+        let mut output = SmartStreamOutput::default();
+        output.decode(&mut Cursor::new(bytes), 0)?;
+
+        Ok(output)
+    }
 }
@@ -9,33 +9,65 @@ use tracing::debug;
 use wasmtime::{Caller, Extern, Func, Instance, Trap, TypedFunc, Store};
 
 use dataplane::core::{Decoder, Encoder};
+use dataplane::Offset;
 use dataplane::batch::Batch;
 use dataplane::batch::MemoryRecords;
 use dataplane::smartstream::{
-    SmartStreamInput, SmartStreamOutput, SmartStreamRuntimeError, SmartStreamInternalError,
+    SmartStreamExtraParams, SmartStreamInput, SmartStreamOutput, SmartStreamRuntimeError,
+    SmartStreamInternalError,
 };
 use crate::smart_stream::{RecordsCallBack, RecordsMemory, SmartStreamEngine, SmartStreamModule};
 use crate::smart_stream::file_batch::FileBatchIterator;
 
-const MAP_FN_NAME: &str = "map";
 type MapFn = TypedFunc<(i32, i32), i32>;
 
+/// SmartStreams which transform records.
+/// They are processed the same way, only function exported by module differs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MapKind {
+    /// each record is transformed into one record
+    Map,
+    /// each record is transformed into zero or more records.
+    /// Output records keep offset of their input record, so records of same input share offset
+    ArrayMap,
+    /// each record is either transformed or dropped
+    FilterMap,
+}
+
+impl MapKind {
+    /// name of function exported by module
+    pub fn export_name(&self) -> &'static str {
+        match self {
+            Self::Map => "map",
+            Self::ArrayMap => "array_map",
+            Self::FilterMap => "filter_map",
+        }
+    }
+}
+
 pub struct SmartStreamMap {
+    kind: MapKind,
     store: Store<()>,
     instance: Instance,
     map_fn: MapFn,
     records_cb: Arc<RecordsCallBack>,
+    params: SmartStreamExtraParams,
 }
 
 impl SmartStreamMap {
-    pub fn new(engine: &SmartStreamEngine, module: &SmartStreamModule) -> Result<Self> {
+    pub fn new(
+        engine: &SmartStreamEngine,
+        module: &SmartStreamModule,
+        params: SmartStreamExtraParams,
+        kind: MapKind,
+    ) -> Result<Self> {
         let mut store = Store::new(&engine.0, ());
         let cb = Arc::new(RecordsCallBack::new());
         let records_cb = cb.clone();
         let copy_records = Func::wrap(
             &mut store,
             move |mut caller: Caller<'_, ()>, ptr: i32, len: i32| {
-                debug!(len, "callback from wasm map");
+                debug!(len, export = kind.export_name(), "callback from wasm map");
                 let memory = match caller.get_export("memory") {
                     Some(Extern::Memory(mem)) => mem,
                     _ => return Err(Trap::new("failed to find host memory")),
@@ -50,16 +82,22 @@ impl SmartStreamMap {
         );
 
         let instance = Instance::new(&mut store, &module.0, &[copy_records.into()])?;
-        let map_fn: MapFn = instance.get_typed_func(&mut store, MAP_FN_NAME)?;
+        let map_fn: MapFn = instance.get_typed_func(&mut store, kind.export_name())?;
 
         Ok(Self {
+            kind,
             store,
             instance,
             map_fn,
             records_cb,
+            params,
         })
     }
 
+    pub fn kind(&self) -> MapKind {
+        self.kind
+    }
+
     /// map batches with maximum bytes to be send back consumer
     pub fn map(
         &mut self,
@@ -95,44 +133,7 @@ impl SmartStreamMap {
                 "starting map processing"
             );
 
-            let now = Instant::now();
-
-            let mut input_data = Vec::new();
-            let smartstream_input = SmartStreamInput {
-                base_offset: file_batch.batch.base_offset,
-                record_data: file_batch.records.clone(),
-            };
-            smartstream_input.encode(&mut input_data, 0)?;
-
-            self.records_cb.clear();
-            let array_ptr = super::memory::copy_memory_to_instance(
-                &mut self.store,
-                &self.instance,
-                &input_data,
-            )?;
-
-            let map_output = self
-                .map_fn
-                .call(&mut self.store, (array_ptr as i32, input_data.len() as i32))?;
-
-            debug!(map_output, map_execution_time = %now.elapsed().as_millis());
-
-            if map_output < 0 {
-                let internal_error = SmartStreamInternalError::try_from(map_output)
-                    .unwrap_or(SmartStreamInternalError::UnknownError);
-                return Err(internal_error.into());
-            }
-
-            let bytes = self
-                .records_cb
-                .get()
-                .and_then(|m| m.copy_memory_from(&mut self.store).ok())
-                .unwrap_or_default();
-            debug!(out_map_bytes = bytes.len());
-
-            // this is inefficient for now
-            let mut output = SmartStreamOutput::default();
-            output.decode(&mut Cursor::new(bytes), 0)?;
+            let output = self.process(file_batch.base_offset(), file_batch.records.clone())?;
 
             let maybe_error = output.error;
             let mut records = output.successes;
@@ -148,7 +149,7 @@ impl SmartStreamMap {
 
                 // difference between map batch and and current batch
                 // since base are different we need update delta offset for each records
-                let relative_base_offset = memory_map_batch.base_offset - file_batch.base_offset();
+                let relative_base_offset = file_batch.base_offset() - memory_map_batch.base_offset;
 
                 for record in &mut records {
                     record.add_base_offset(relative_base_offset);
@@ -166,6 +167,7 @@ impl SmartStreamMap {
                 }
 
                 total_bytes += record_bytes;
+                file_batch.rebase_timestamps(&mut memory_map_batch, &mut records);
 
                 debug!(map_records = records.len(), total_bytes, "finished mapping");
                 memory_map_batch.mut_records().append(&mut records);
@@ -186,4 +188,50 @@ impl SmartStreamMap {
             }
         }
     }
+
+    /// process records of single batch
+    pub fn process(
+        &mut self,
+        base_offset: Offset,
+        record_data: Vec<u8>,
+    ) -> Result<SmartStreamOutput> {
+        let now = Instant::now();
+
+        let mut input_data = Vec::new();
+        let smartstream_input = SmartStreamInput {
+            base_offset,
+            record_data,
+            params: self.params.clone(),
+        };
+        smartstream_input.encode(&mut input_data, 0)?;
+
+        self.records_cb.clear();
+        let array_ptr =
+            super::memory::copy_memory_to_instance(&mut self.store, &self.instance, &input_data)?;
+
+        let map_output = self
+            .map_fn
+            .call(&mut self.store, (array_ptr as i32, input_data.len() as i32))?;
+
+        debug!(map_output, map_execution_time = %now.elapsed().as_millis());
+
+        if map_output < 0 {
+            let internal_error = SmartStreamInternalError::try_from(map_output)
+                .unwrap_or(SmartStreamInternalError::UnknownError);
+            return Err(internal_error.into());
+        }
+
+        let bytes = self
+            .records_cb
+            .get()
+            .and_then(|m| m.copy_memory_from(&mut self.store).ok())
+            .unwrap_or_default();
+        debug!(out_map_bytes = bytes.len());
+
+        // this is inefficient for now
+        let mut output = SmartStreamOutput::default();
+        output.decode(&mut Cursor::new(bytes), 0)?;
+
+        Ok(output)
+    }
 }
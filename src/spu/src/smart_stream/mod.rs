@@ -1,14 +1,18 @@
 use std::sync::Mutex;
 use anyhow::Result;
 use wasmtime::{Memory, Store, Engine, Module};
+use dataplane::Offset;
+use dataplane::smartstream::{SmartStreamExtraParams, SmartStreamOutput};
 use crate::smart_stream::filter::SmartStreamFilter;
-use crate::smart_stream::map::SmartStreamMap;
+use crate::smart_stream::map::{SmartStreamMap, MapKind};
 use crate::smart_stream::aggregate::SmartStreamAggregate;
+use crate::smart_stream::chain::SmartStreamChain;
 
 mod memory;
 pub mod filter;
 pub mod map;
 pub mod aggregate;
+pub mod chain;
 pub mod file_batch;
 
 #[derive(Default)]
@@ -24,30 +28,59 @@ impl SmartStreamEngine {
 pub struct SmartStreamModule(pub(crate) Module);
 
 impl SmartStreamModule {
-    pub fn create_filter(&self, engine: &SmartStreamEngine) -> Result<SmartStreamFilter> {
-        let filter = SmartStreamFilter::new(engine, self)?;
+    pub fn create_filter(
+        &self,
+        engine: &SmartStreamEngine,
+        params: SmartStreamExtraParams,
+    ) -> Result<SmartStreamFilter> {
+        let filter = SmartStreamFilter::new(engine, self, params)?;
         Ok(filter)
     }
 
-    pub fn create_map(&self, engine: &SmartStreamEngine) -> Result<SmartStreamMap> {
-        let map = SmartStreamMap::new(engine, self)?;
+    /// create map, array map or filter map
+    pub fn create_map(
+        &self,
+        engine: &SmartStreamEngine,
+        params: SmartStreamExtraParams,
+        kind: MapKind,
+    ) -> Result<SmartStreamMap> {
+        let map = SmartStreamMap::new(engine, self, params, kind)?;
         Ok(map)
     }
 
     pub fn create_aggregate(
         &self,
         engine: &SmartStreamEngine,
+        params: SmartStreamExtraParams,
         accumulator: Vec<u8>,
     ) -> Result<SmartStreamAggregate> {
-        let aggregate = SmartStreamAggregate::new(engine, self, accumulator)?;
+        let aggregate = SmartStreamAggregate::new(engine, self, params, accumulator)?;
         Ok(aggregate)
     }
 }
 
 pub enum SmartStream {
     Filter(SmartStreamFilter),
+    /// map, array map or filter map
     Map(SmartStreamMap),
     Aggregate(SmartStreamAggregate),
+    Chain(SmartStreamChain),
+}
+
+impl SmartStream {
+    /// process records of single batch
+    pub fn process(
+        &mut self,
+        base_offset: Offset,
+        record_data: Vec<u8>,
+    ) -> Result<SmartStreamOutput> {
+        match self {
+            Self::Filter(filter) => filter.process(base_offset, record_data),
+            Self::Map(map) => map.process(base_offset, record_data),
+            Self::Aggregate(aggregate) => aggregate.process(base_offset, record_data),
+            Self::Chain(chain) => chain.process(base_offset, record_data),
+        }
+    }
 }
 
 #[derive(Clone)]
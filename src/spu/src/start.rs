@@ -1,5 +1,7 @@
 use fluvio_storage::FileReplica;
 
+use fluvio_auth::RootAuthorization;
+
 use crate::config::{SpuConfig, SpuOpt};
 use crate::services::create_internal_server;
 use crate::services::create_public_server;
@@ -24,11 +26,10 @@ pub fn main_loop(opt: SpuOpt) {
     println!("starting spu server (id:{})", spu_config.id);
 
     run_block_on(async move {
-        let (_ctx, internal_server, public_server) =
-            create_services(spu_config.clone(), true, true);
+        let (ctx, internal_server, _) = create_services(spu_config.clone(), true, false);
 
-        let _public_shutdown = internal_server.unwrap().run();
-        let _private_shutdown = public_server.unwrap().run();
+        let _private_shutdown = internal_server.unwrap().run();
+        let _public_shutdown = pub_server::start(ctx);
 
         if let Some(tls_config) = tls_acceptor_option {
             proxy::start_proxy(spu_config, tls_config).await;
@@ -51,7 +52,7 @@ pub fn create_services(
 ) -> (
     DefaultSharedGlobalContext,
     Option<InternalApiServer>,
-    Option<PublicApiServer>,
+    Option<PublicApiServer<RootAuthorization>>,
 ) {
     let ctx = FileReplicaContext::new_shared_context(local_spu);
 
@@ -76,6 +77,45 @@ pub fn create_services(
     (ctx, internal_server, public_server)
 }
 
+mod pub_server {
+
+    use std::sync::Arc;
+
+    use tracing::info;
+
+    use fluvio_types::event::SimpleEvent;
+    use fluvio_auth::RootAuthorization;
+    use fluvio_auth::basic::BasicAuthorization;
+    use fluvio_auth::token::TokenAuthorization;
+
+    use crate::core::DefaultSharedGlobalContext;
+    use crate::services::create_public_server_with_auth;
+
+    /// run public server with authorization from configured policy
+    pub fn start(ctx: DefaultSharedGlobalContext) -> Arc<SimpleEvent> {
+        let addr = ctx.config().public_socket_addr().to_owned();
+        if let Some(credentials) = ctx.config().credentials.clone() {
+            let policy = ctx.config().auth_policy.clone().unwrap_or_default();
+            let mut authorization = TokenAuthorization::new(credentials, policy);
+            // clients with certificate authenticated by proxy don't need credentials
+            if ctx.config().x509_auth_scopes.is_some() {
+                info!("using x509 and token authorization");
+                authorization = authorization.with_x509_identity();
+            } else {
+                info!("using token authorization");
+            }
+            create_public_server_with_auth(addr, ctx, Arc::new(authorization)).run()
+        } else if let Some(policy) = ctx.config().auth_policy.clone() {
+            info!("using basic authorization");
+            create_public_server_with_auth(addr, ctx, Arc::new(BasicAuthorization::new(policy)))
+                .run()
+        } else {
+            info!("using root authorization");
+            create_public_server_with_auth(addr, ctx, Arc::new(RootAuthorization::new())).run()
+        }
+    }
+}
+
 mod proxy {
 
     use std::process;
@@ -84,15 +124,25 @@ mod proxy {
 
     use flv_util::print_cli_err;
     use fluvio_future::openssl::TlsAcceptor;
+    use fluvio_auth::x509::X509Authenticator;
     use crate::config::SpuConfig;
-    use flv_tls_proxy::start as proxy_start;
+    use flv_tls_proxy::{
+        start as proxy_start, start_with_authenticator as proxy_start_with_authenticator,
+    };
 
     pub async fn start_proxy(config: SpuConfig, acceptor: (TlsAcceptor, String)) {
         let (tls_acceptor, proxy_addr) = acceptor;
         let target = config.public_endpoint;
         info!("starting TLS proxy: {}", proxy_addr);
 
-        if let Err(err) = proxy_start(&proxy_addr, tls_acceptor, target).await {
+        let result = if let Some(x509_auth_scopes) = config.x509_auth_scopes {
+            let authenticator = Box::new(X509Authenticator::new(&x509_auth_scopes));
+            proxy_start_with_authenticator(&proxy_addr, tls_acceptor, target, authenticator).await
+        } else {
+            proxy_start(&proxy_addr, tls_acceptor, target).await
+        };
+
+        if let Err(err) = result {
             print_cli_err!(err);
             process::exit(-1);
         } else {
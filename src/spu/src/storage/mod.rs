@@ -1,16 +1,23 @@
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
 use std::fmt::Debug;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use tracing::{debug, instrument};
 use async_rwlock::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use async_trait::async_trait;
 
 use fluvio_controlplane_metadata::partition::{ReplicaKey};
+use fluvio_controlplane_metadata::topic::{CleanupPolicy, TopicConfig};
 use dataplane::{Isolation, record::RecordSet};
 use dataplane::core::Encoder;
-use dataplane::{Offset};
-use fluvio_storage::{ReplicaStorage, SlicePartitionResponse, StorageError, OffsetInfo};
+use dataplane::{Offset, Timestamp};
+use fluvio_storage::{
+    ReplicaStorage, ReplicaStorageConfig, SlicePartitionResponse, StorageError, OffsetInfo,
+};
+use fluvio_storage::config::{CleanupPolicy as StorageCleanupPolicy, ReplicaOverrides};
+use fluvio_storage::cleaner::{Cleaner, CleanableReplica};
 use fluvio_types::{event::offsets::OffsetChangeListener};
+use fluvio_types::defaults::SPU_LOG_CLEANUP_INTERVAL_SEC;
 use fluvio_types::event::offsets::OffsetPublisher;
 
 pub const REMOVAL_START: Offset = -1000; // indicate that storage about to be removed
@@ -41,17 +48,26 @@ where
     S: ReplicaStorage,
 {
     /// create new storage replica or restore from durable storage based on configuration
+    /// background cleaner is started to enforce retention
     pub async fn create(id: ReplicaKey, config: S::Config) -> Result<Self, StorageError> {
         let storage = S::create(&id, config).await?;
 
         let leo = Arc::new(OffsetPublisher::new(storage.get_leo()));
         let hw = Arc::new(OffsetPublisher::new(storage.get_hw()));
-        Ok(Self {
+        let replica_storage = Self {
             id,
             inner: Arc::new(RwLock::new(storage)),
             leo,
             hw,
-        })
+        };
+        Cleaner::start(
+            StorageCleanerHandle {
+                id: replica_storage.id.clone(),
+                inner: Arc::downgrade(&replica_storage.inner),
+            },
+            Duration::from_secs(SPU_LOG_CLEANUP_INTERVAL_SEC),
+        );
+        Ok(replica_storage)
     }
 
     pub fn id(&self) -> &ReplicaKey {
@@ -99,6 +115,14 @@ where
         (reader.get_log_start_offset(), reader.get_hw())
     }
 
+    /// offset of first record at or after timestamp
+    pub async fn find_offset_by_timestamp(
+        &self,
+        timestamp: Timestamp,
+    ) -> Result<Option<Offset>, StorageError> {
+        self.read().await.find_offset_by_timestamp(timestamp).await
+    }
+
     /// read records into partition response
     /// return leo and hw
     #[instrument(skip(self, offset, max_len, isolation, partition_response))]
@@ -129,12 +153,14 @@ where
         }
     }
 
+    /// write records, return base offset and last offset of records written.
+    /// Offsets are read under write lock, so they are not affected by concurrent writes
     #[instrument(skip(self, records, hw_update))]
     pub async fn write_record_set(
         &self,
         records: &mut RecordSet,
         hw_update: bool,
-    ) -> Result<(), StorageError> {
+    ) -> Result<(Offset, Offset), StorageError> {
         debug!(
             replica = %self.id,
             leo = self.leo(),
@@ -145,6 +171,7 @@ where
         );
 
         let mut writer = self.write().await;
+        let base_offset = writer.get_leo();
         let now = Instant::now();
         let _offset_updates = writer.write_recordset(records, hw_update).await?;
         debug!(write_time_ms = %now.elapsed().as_millis());
@@ -158,7 +185,7 @@ where
             self.hw.update(hw);
         }
 
-        Ok(())
+        Ok((base_offset, leo - 1))
     }
 
     /// perform permanent remove
@@ -170,3 +197,63 @@ where
         Ok(())
     }
 }
+
+/// apply storage settings of topic config to storage configuration of replica.
+/// settings not in topic config use storage default
+pub fn apply_topic_config<C>(config: &mut C, topic_config: &TopicConfig)
+where
+    C: ReplicaStorageConfig,
+{
+    match topic_config.cleanup_policy() {
+        Some(CleanupPolicy::Delete) => config.set_cleanup_policy(StorageCleanupPolicy::Delete, 0),
+        Some(CleanupPolicy::Compact(compaction)) => config.set_cleanup_policy(
+            StorageCleanupPolicy::Compact,
+            compaction.tombstone_retention_seconds,
+        ),
+        None => {}
+    }
+    config.apply_overrides(&ReplicaOverrides {
+        retention_seconds: topic_config.retention_seconds(),
+        segment_max_bytes: topic_config.segment_max_bytes(),
+        max_partition_size: topic_config.partition_max_bytes(),
+        max_batch_size: topic_config.max_message_bytes(),
+    });
+}
+
+/// Handle to storage used by background cleaner.
+/// It doesn't keep storage alive, so cleaner terminates once storage is dropped
+struct StorageCleanerHandle<S> {
+    id: ReplicaKey,
+    inner: Weak<RwLock<S>>,
+}
+
+#[async_trait]
+impl<S> CleanableReplica for StorageCleanerHandle<S>
+where
+    S: ReplicaStorage,
+{
+    fn name(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn is_removed(&self) -> bool {
+        self.inner.strong_count() == 0
+    }
+
+    async fn enforce_retention(&self) -> Result<bool, StorageError> {
+        if let Some(inner) = self.inner.upgrade() {
+            // segments are rewritten without holding lock, only swap blocks writers
+            let job = inner.read().await.compaction_job();
+            if let Some(job) = job {
+                let compacted = job.run().await?;
+                if inner.write().await.apply_compaction(compacted).await? {
+                    debug!(replica = %self.id, "segments compacted");
+                }
+            }
+            let mut writer = inner.write().await;
+            writer.enforce_retention().await
+        } else {
+            Ok(false)
+        }
+    }
+}
@@ -44,6 +44,10 @@ where
         &self.inner
     }
 
+    pub fn into_batch(self) -> Batch<R> {
+        self.inner
+    }
+
     pub fn get_pos(&self) -> Size {
         self.pos
     }
@@ -147,7 +151,7 @@ where
         }
 
         let mut cursor = Cursor::new(bytes);
-        self.inner.mut_records().decode(&mut cursor, 0)?;
+        self.inner.decode_records(&mut cursor, 0)?;
 
         Ok(())
     }
@@ -249,6 +253,7 @@ mod tests {
     use flv_util::fixture::ensure_new_dir;
     use dataplane::fixture::create_batch;
     use dataplane::fixture::create_batch_with_producer;
+    use dataplane::compression::Compression;
 
     use crate::config::ConfigOption;
     use crate::StorageError;
@@ -315,4 +320,34 @@ mod tests {
 
         Ok(())
     }
+
+    #[test_async]
+    async fn test_batch_stream_compressed() -> Result<(), StorageError> {
+        let test_dir = temp_dir().join("batch-stream-compressed");
+        ensure_new_dir(&test_dir)?;
+
+        let option = default_option(test_dir.clone());
+
+        let mut active_segment = MutableSegment::create(300, &option).await?;
+
+        let mut batch = create_batch_with_producer(25, 10);
+        batch.compress(Compression::Gzip).expect("compress");
+        let compressed_len = batch.write_size(0);
+        active_segment.write_batch(&mut batch).await?;
+        assert_eq!(active_segment.occupied_memory(), compressed_len as u64);
+
+        let mut batch_stream = active_segment
+            .open_default_batch_stream()
+            .await
+            .expect("open file batch stream");
+
+        let batch1 = batch_stream.next().await.expect("batch");
+        assert_eq!(batch1.total_len() as usize, compressed_len);
+        let batch = batch1.get_batch();
+        assert_eq!(batch.compression().expect("codec"), Compression::Gzip);
+        assert_eq!(batch.records().len(), 10);
+        assert_eq!(batch1.get_last_offset(), 309);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use tracing::{debug, error, instrument};
+use async_trait::async_trait;
+
+use fluvio_future::task::spawn;
+use fluvio_future::timer::sleep;
+
+use crate::StorageError;
+
+/// Replica which retention can be enforced on.
+/// This is usually shared with writers, so implementation must take care of locking
+#[async_trait]
+pub trait CleanableReplica: Send + Sync + 'static {
+    /// replica name for logging
+    fn name(&self) -> String;
+
+    /// true if replica has been removed, cleaner will stop
+    fn is_removed(&self) -> bool;
+
+    /// remove records which are no longer retained
+    /// return true if log start offset has been moved
+    async fn enforce_retention(&self) -> Result<bool, StorageError>;
+}
+
+/// Periodically removes expired segments of replica
+pub struct Cleaner<C> {
+    replica: C,
+    interval: Duration,
+}
+
+impl<C> Cleaner<C>
+where
+    C: CleanableReplica,
+{
+    /// start cleaner in background
+    pub fn start(replica: C, interval: Duration) {
+        let cleaner = Self { replica, interval };
+        spawn(cleaner.dispatch_loop());
+    }
+
+    #[instrument(
+        skip(self),
+        name = "cleaner_loop",
+        fields(replica = %self.replica.name())
+    )]
+    async fn dispatch_loop(self) {
+        debug!(interval_ms = %self.interval.as_millis(), "starting cleaner");
+
+        loop {
+            sleep(self.interval).await;
+
+            if self.replica.is_removed() {
+                debug!("replica removed, terminating cleaner");
+                break;
+            }
+
+            match self.replica.enforce_retention().await {
+                Ok(true) => debug!("expired segments removed"),
+                Ok(false) => {}
+                Err(err) => error!("error enforcing retention: {}", err),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+
+    use fluvio_future::test_async;
+    use fluvio_future::timer::sleep;
+
+    use crate::StorageError;
+
+    use super::{Cleaner, CleanableReplica};
+
+    #[derive(Default, Clone)]
+    struct MockReplica {
+        runs: Arc<AtomicU32>,
+        removed: Arc<AtomicBool>,
+    }
+
+    #[async_trait]
+    impl CleanableReplica for MockReplica {
+        fn name(&self) -> String {
+            "mock-0".to_owned()
+        }
+
+        fn is_removed(&self) -> bool {
+            self.removed.load(Ordering::SeqCst)
+        }
+
+        async fn enforce_retention(&self) -> Result<bool, StorageError> {
+            self.runs.fetch_add(1, Ordering::SeqCst);
+            Ok(false)
+        }
+    }
+
+    #[test_async]
+    async fn test_cleaner_stop_on_remove() -> Result<(), StorageError> {
+        let replica = MockReplica::default();
+        Cleaner::start(replica.clone(), Duration::from_millis(10));
+
+        sleep(Duration::from_millis(100)).await;
+        assert!(replica.runs.load(Ordering::SeqCst) > 0);
+
+        replica.removed.store(true, Ordering::SeqCst);
+        sleep(Duration::from_millis(50)).await;
+        let runs = replica.runs.load(Ordering::SeqCst);
+        sleep(Duration::from_millis(50)).await;
+        assert_eq!(replica.runs.load(Ordering::SeqCst), runs);
+
+        Ok(())
+    }
+}
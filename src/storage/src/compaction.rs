@@ -0,0 +1,272 @@
+//!
+//! # Compaction of read only segments
+//!
+//! Compaction is split in two steps, so replica doesn't have to be locked while segments are rewritten.
+//! `CompactionJob` writes compacted copies of segments into compaction directory of replica.
+//! It only reads segments which are no longer written to.
+//! Then replica swaps compacted copies in place of original segments.
+//!
+//! Before swap starts, marker file is written into compaction directory.
+//! If swap is interrupted, replica completes it on startup, so log and index of segment always match.
+//!
+use std::collections::HashMap;
+use std::io::{Error as IoError, ErrorKind};
+use std::path::{Path, PathBuf};
+
+use tracing::{debug, trace};
+
+use futures_lite::io::AsyncWriteExt;
+
+use fluvio_future::fs::{create_dir_all, read, remove_dir_all, rename, File};
+use dataplane::{Offset, Timestamp, now_timestamp};
+
+use crate::config::ConfigOption;
+use crate::segment::{ReadSegment, SEGMENT_EXTENSIONS};
+use crate::util::generate_file_name;
+use crate::StorageError;
+
+/// directory in replica where segments are rewritten during compaction
+pub(crate) const COMPACTION_DIR: &str = "compaction";
+/// present while compacted segments are being swapped in
+const SWAP_MARKER: &str = "swap";
+
+/// Compaction of read only segments of replica.
+/// It doesn't borrow replica, so it can be run without holding lock on replica.
+#[derive(Debug)]
+pub struct CompactionJob {
+    option: ConfigOption,
+    base_offsets: Vec<Offset>,
+}
+
+impl CompactionJob {
+    /// `option` is option of replica, segments must be committed and no longer written to
+    pub(crate) fn new(option: ConfigOption, base_offsets: Vec<Offset>) -> Self {
+        Self {
+            option,
+            base_offsets,
+        }
+    }
+
+    /// rewrite segments so only latest record of each key is kept.
+    /// Tombstones (records with empty value) are removed once their segment
+    /// is older than tombstone retention period.
+    pub async fn run(self) -> Result<CompactedSegments, StorageError> {
+        let compact_dir = compaction_dir(&self.option.base_dir);
+        // clean up leftover from previous compaction
+        if compact_dir.exists() {
+            remove_dir_all(&compact_dir).await?;
+        }
+        create_dir_all(&compact_dir).await?;
+
+        // segments are opened separately from replica, their files are not modified until swap
+        let mut segments = vec![];
+        for base_offset in &self.base_offsets {
+            segments.push(ReadSegment::open_for_read(*base_offset, &self.option).await?);
+        }
+
+        // find latest offset of each key among segments
+        let mut latest_offsets: HashMap<Vec<u8>, Offset> = HashMap::new();
+        for segment in &segments {
+            segment
+                .collect_latest_key_offsets(&mut latest_offsets)
+                .await?;
+        }
+
+        let tombstone_retention_ms = self.option.tombstone_retention_seconds as Timestamp * 1000;
+        let now_ms = now_timestamp();
+        let mut base_offsets = vec![];
+        for segment in segments {
+            let base_offset = segment.get_base_offset();
+            let drop_tombstones =
+                now_ms - segment.last_timestamp().await? >= tombstone_retention_ms;
+            let rewritten = segment
+                .write_compacted(&compact_dir, |offset, record| match record.key() {
+                    Some(key) => {
+                        latest_offsets.get(key.as_ref()) == Some(&offset)
+                            && !(drop_tombstones && record.value().as_ref().is_empty())
+                    }
+                    None => true,
+                })
+                .await?;
+            if rewritten {
+                debug!(base_offset, drop_tombstones, "segment compacted");
+                base_offsets.push(base_offset);
+            }
+        }
+
+        Ok(CompactedSegments { base_offsets })
+    }
+}
+
+/// Segments rewritten by compaction job, ready to be swapped into replica
+#[derive(Debug, Default)]
+pub struct CompactedSegments {
+    base_offsets: Vec<Offset>,
+}
+
+impl CompactedSegments {
+    pub fn is_empty(&self) -> bool {
+        self.base_offsets.is_empty()
+    }
+
+    pub fn base_offsets(&self) -> &[Offset] {
+        &self.base_offsets
+    }
+}
+
+fn compaction_dir(replica_dir: &Path) -> PathBuf {
+    replica_dir.join(COMPACTION_DIR)
+}
+
+/// replace segments of replica with their compacted copies.
+/// Segments must be closed by replica before.
+pub(crate) async fn swap_segments(
+    replica_dir: &Path,
+    base_offsets: &[Offset],
+) -> Result<(), StorageError> {
+    let compact_dir = compaction_dir(replica_dir);
+    // marker lists segments being swapped, so recovery doesn't bring back other copies
+    let segments: Vec<String> = base_offsets
+        .iter()
+        .map(|offset| offset.to_string())
+        .collect();
+    // marker is written under temporary name, so it is never seen partially written
+    let marker_path = compact_dir.join(SWAP_MARKER);
+    let tmp_marker_path = marker_path.with_extension("tmp");
+    let mut marker = File::create(&tmp_marker_path).await?;
+    marker.write_all(segments.join("\n").as_bytes()).await?;
+    marker.sync_all().await?;
+    drop(marker);
+    rename(&tmp_marker_path, &marker_path).await?;
+
+    for base_offset in base_offsets {
+        move_segment(&compact_dir, replica_dir, *base_offset).await?;
+    }
+
+    // compacted copies of segments which were not swapped are removed with directory
+    remove_dir_all(&compact_dir).await?;
+    Ok(())
+}
+
+/// finish swap interrupted by crash, otherwise discard partially compacted segments.
+/// Must be done before segments of replica are loaded.
+pub(crate) async fn recover(replica_dir: &Path) -> Result<(), StorageError> {
+    let compact_dir = compaction_dir(replica_dir);
+    if !compact_dir.exists() {
+        return Ok(());
+    }
+
+    let marker = compact_dir.join(SWAP_MARKER);
+    if marker.exists() {
+        debug!(dir = %compact_dir.display(), "completing interrupted compaction");
+        // files are moved only after marker is written, so remaining files are complete copies
+        let segments = String::from_utf8_lossy(&read(&marker).await?).to_string();
+        for segment in segments.lines() {
+            let base_offset: Offset = segment.parse().map_err(|_| {
+                IoError::new(
+                    ErrorKind::InvalidData,
+                    format!("invalid segment in compaction marker: {}", segment),
+                )
+            })?;
+            move_segment(&compact_dir, replica_dir, base_offset).await?;
+        }
+    }
+
+    remove_dir_all(&compact_dir).await?;
+    Ok(())
+}
+
+/// move files of segment which are still in compaction directory
+async fn move_segment(
+    compact_dir: &Path,
+    replica_dir: &Path,
+    base_offset: Offset,
+) -> Result<(), StorageError> {
+    for extension in SEGMENT_EXTENSIONS {
+        let compacted = generate_file_name(compact_dir, base_offset, extension);
+        if compacted.exists() {
+            trace!(file = %compacted.display(), "swapping compacted file");
+            rename(
+                &compacted,
+                generate_file_name(replica_dir, base_offset, extension),
+            )
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::env::temp_dir;
+    use std::fs;
+
+    use fluvio_future::test_async;
+    use flv_util::fixture::ensure_clean_dir;
+
+    use crate::StorageError;
+    use crate::util::generate_file_name;
+    use crate::segment::SEGMENT_EXTENSIONS;
+
+    use super::{recover, COMPACTION_DIR, SWAP_MARKER};
+
+    #[test_async]
+    async fn test_recover_interrupted_swap() -> Result<(), StorageError> {
+        let replica_dir = temp_dir().join("test_compaction_recover");
+        ensure_clean_dir(&replica_dir);
+        let compact_dir = replica_dir.join(COMPACTION_DIR);
+        fs::create_dir_all(&compact_dir)?;
+
+        // log of segment 0 has been swapped, index hasn't
+        for extension in SEGMENT_EXTENSIONS {
+            fs::write(generate_file_name(&replica_dir, 0, extension), b"old")?;
+            fs::write(generate_file_name(&compact_dir, 0, extension), b"new")?;
+        }
+        fs::rename(
+            generate_file_name(&compact_dir, 0, SEGMENT_EXTENSIONS[0]),
+            generate_file_name(&replica_dir, 0, SEGMENT_EXTENSIONS[0]),
+        )?;
+        fs::write(compact_dir.join(SWAP_MARKER), b"0")?;
+
+        recover(&replica_dir).await?;
+
+        for extension in SEGMENT_EXTENSIONS {
+            assert_eq!(
+                fs::read(generate_file_name(&replica_dir, 0, extension))?,
+                b"new"
+            );
+        }
+        assert!(!compact_dir.exists());
+
+        Ok(())
+    }
+
+    #[test_async]
+    async fn test_recover_discards_unfinished_compaction() -> Result<(), StorageError> {
+        let replica_dir = temp_dir().join("test_compaction_discard");
+        ensure_clean_dir(&replica_dir);
+        let compact_dir = replica_dir.join(COMPACTION_DIR);
+        fs::create_dir_all(&compact_dir)?;
+
+        for extension in SEGMENT_EXTENSIONS {
+            fs::write(generate_file_name(&replica_dir, 0, extension), b"old")?;
+        }
+        fs::write(
+            generate_file_name(&compact_dir, 0, SEGMENT_EXTENSIONS[0]),
+            b"partial",
+        )?;
+
+        recover(&replica_dir).await?;
+
+        for extension in SEGMENT_EXTENSIONS {
+            assert_eq!(
+                fs::read(generate_file_name(&replica_dir, 0, extension))?,
+                b"old"
+            );
+        }
+        assert!(!compact_dir.exists());
+
+        Ok(())
+    }
+}
@@ -8,13 +8,14 @@ use serde::Deserialize;
 use fluvio_types::defaults::{SPU_LOG_INDEX_MAX_BYTES, SPU_LOG_BASE_DIR};
 use fluvio_types::defaults::SPU_LOG_INDEX_MAX_INTERVAL_BYTES;
 use fluvio_types::defaults::SPU_LOG_SEGMENT_MAX_BYTES;
+use fluvio_types::defaults::{SPU_LOG_TOMBSTONE_RETENTION_SECONDS, SPU_LOG_MAX_BATCH_SIZE};
 use dataplane::Size;
 
 use crate::ReplicaStorageConfig;
 
 pub const DEFAULT_FLUSH_WRITE_COUNT: u32 = 1;
 pub const DEFAULT_FLUSH_IDLE_MSEC: u32 = 0;
-pub const DEFAULT_MAX_BATCH_SIZE: u32 = 1048588;
+pub const DEFAULT_MAX_BATCH_SIZE: u32 = SPU_LOG_MAX_BATCH_SIZE;
 
 // common option
 #[derive(Builder, Debug, Clone, PartialEq, Deserialize)]
@@ -44,6 +45,34 @@ pub struct ConfigOption {
     #[builder(default = "default_update_hw()")]
     #[serde(default = "default_update_hw")]
     pub update_hw: bool, // if true, enable hw update
+    #[builder(default)]
+    #[serde(default)]
+    pub retention_seconds: Option<Size>, // segments older than this are removed, none keeps them forever
+    #[builder(default)]
+    #[serde(default)]
+    pub max_partition_size: Option<u64>, // oldest segments are removed when partition exceeds this, none for no limit
+    #[builder(default)]
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
+    #[builder(default = "default_tombstone_retention_seconds()")]
+    #[serde(default = "default_tombstone_retention_seconds")]
+    pub tombstone_retention_seconds: Size, // compacted tombstones older than this are removed
+}
+
+/// How records which are no longer needed are removed from replica
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CleanupPolicy {
+    /// remove oldest segments, based on retention time and partition size
+    Delete,
+    /// rewrite closed segments so only latest record of each key is kept
+    Compact,
+}
+
+impl Default for CleanupPolicy {
+    fn default() -> Self {
+        Self::Delete
+    }
 }
 
 impl fmt::Display for ConfigOption {
@@ -52,7 +81,36 @@ impl fmt::Display for ConfigOption {
     }
 }
 
-impl ReplicaStorageConfig for ConfigOption {}
+/// Storage settings overridden for specific replica, none means SPU setting is used
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReplicaOverrides {
+    pub retention_seconds: Option<Size>,
+    pub segment_max_bytes: Option<Size>,
+    pub max_partition_size: Option<u64>,
+    pub max_batch_size: Option<Size>,
+}
+
+impl ReplicaStorageConfig for ConfigOption {
+    fn set_cleanup_policy(&mut self, policy: CleanupPolicy, tombstone_retention_seconds: Size) {
+        self.cleanup_policy = policy;
+        self.tombstone_retention_seconds = tombstone_retention_seconds;
+    }
+
+    fn apply_overrides(&mut self, overrides: &ReplicaOverrides) {
+        if let Some(retention_seconds) = overrides.retention_seconds {
+            self.retention_seconds = Some(retention_seconds);
+        }
+        if let Some(segment_max_bytes) = overrides.segment_max_bytes {
+            self.segment_max_bytes = segment_max_bytes;
+        }
+        if let Some(max_partition_size) = overrides.max_partition_size {
+            self.max_partition_size = Some(max_partition_size);
+        }
+        if let Some(max_batch_size) = overrides.max_batch_size {
+            self.max_batch_size = max_batch_size;
+        }
+    }
+}
 
 fn default_base_dir() -> PathBuf {
     PathBuf::from(SPU_LOG_BASE_DIR)
@@ -86,6 +144,10 @@ const fn default_max_batch_size() -> Size {
     DEFAULT_MAX_BATCH_SIZE
 }
 
+const fn default_tombstone_retention_seconds() -> Size {
+    SPU_LOG_TOMBSTONE_RETENTION_SECONDS
+}
+
 impl ConfigOption {
     // Used to get a [`ConfigOptionBuilder`].
     pub fn builder() -> ConfigOptionBuilder {
@@ -104,6 +166,10 @@ impl Default for ConfigOption {
             flush_idle_msec: default_flush_idle_msec(),
             max_batch_size: default_max_batch_size(),
             update_hw: true,
+            retention_seconds: None,
+            max_partition_size: None,
+            cleanup_policy: CleanupPolicy::default(),
+            tombstone_retention_seconds: default_tombstone_retention_seconds(),
         }
     }
 }
@@ -1,17 +1,20 @@
 pub mod batch;
 pub mod batch_header;
 mod checkpoint;
+pub mod compaction;
 mod error;
 mod records;
 mod index;
 mod mut_records;
 mod mut_index;
+mod time_index;
 mod range_map;
 mod replica;
 pub mod segment;
 mod util;
 mod validator;
 pub mod config;
+pub mod cleaner;
 
 #[cfg(feature = "fixture")]
 pub mod fixture;
@@ -26,7 +29,7 @@ pub use inner::*;
 mod inner {
     use async_trait::async_trait;
 
-    use dataplane::{ErrorCode, Isolation, Offset, ReplicaKey};
+    use dataplane::{ErrorCode, Isolation, Offset, ReplicaKey, Size, Timestamp};
     use dataplane::fetch::FilePartitionResponse;
     use dataplane::record::RecordSet;
     use fluvio_future::file_slice::AsyncFileSlice;
@@ -102,6 +105,8 @@ mod inner {
     }
 
     use crate::StorageError;
+    use crate::compaction::{CompactionJob, CompactedSegments};
+    use crate::config::{CleanupPolicy, ReplicaOverrides};
 
     /// output from storage is represented as slice
     pub trait SlicePartitionResponse {
@@ -133,10 +138,16 @@ mod inner {
     }
 
     /// some storage configuration
-    pub trait ReplicaStorageConfig {}
+    pub trait ReplicaStorageConfig {
+        /// override cleanup policy for specific replica
+        fn set_cleanup_policy(&mut self, policy: CleanupPolicy, tombstone_retention_seconds: Size);
+
+        /// override storage settings for specific replica
+        fn apply_overrides(&mut self, overrides: &ReplicaOverrides);
+    }
 
     #[async_trait]
-    pub trait ReplicaStorage: Sized {
+    pub trait ReplicaStorage: Sized + Send + Sync + 'static {
         type Config: ReplicaStorageConfig;
 
         /// create new storage area,
@@ -172,6 +183,28 @@ mod inner {
 
         async fn update_high_watermark(&mut self, offset: Offset) -> Result<bool, StorageError>;
 
+        /// offset of first record whose timestamp is same or later than given timestamp.
+        /// none if all records are older
+        async fn find_offset_by_timestamp(
+            &self,
+            timestamp: Timestamp,
+        ) -> Result<Option<Offset>, StorageError>;
+
+        /// remove records which are no longer retained
+        /// return true if log start offset has been moved
+        async fn enforce_retention(&mut self) -> Result<bool, StorageError>;
+
+        /// prepare compaction of segments, none if there is nothing to compact.
+        /// Job doesn't borrow replica, so it can be run without holding lock on replica
+        fn compaction_job(&self) -> Option<CompactionJob>;
+
+        /// swap segments rewritten by compaction job into replica
+        /// return true if any segment has been replaced
+        async fn apply_compaction(
+            &mut self,
+            compacted: CompactedSegments,
+        ) -> Result<bool, StorageError>;
+
         /// permanently remove
         async fn remove(&self) -> Result<(), StorageError>;
     }
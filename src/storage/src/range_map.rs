@@ -3,6 +3,7 @@ use std::cmp::min;
 use std::collections::BTreeMap;
 use std::ops::Bound::Excluded;
 use std::ops::Bound::Included;
+use std::ops::Bound::Unbounded;
 use std::ffi::OsStr;
 
 use tracing::debug;
@@ -105,7 +106,6 @@ impl SegmentList {
         self.segments.insert(segment.get_base_offset(), segment);
     }
 
-    #[allow(dead_code)]
     pub fn get_segment(&self, offset: Offset) -> Option<&ReadSegment> {
         self.segments.get(&offset)
     }
@@ -115,6 +115,41 @@ impl SegmentList {
             .range((Excluded(offset - self.max_base_offset), Included(offset)))
             .next_back()
     }
+
+    /// segment with lowest base offset
+    pub fn oldest_segment(&self) -> Option<&ReadSegment> {
+        self.segments.values().next()
+    }
+
+    /// segments ordered by base offset
+    pub fn segments(&self) -> impl Iterator<Item = &ReadSegment> {
+        self.segments.values()
+    }
+
+    /// base offset of segment which follows segment at offset
+    pub fn next_base_offset(&self, offset: Offset) -> Option<Offset> {
+        self.segments
+            .range((Excluded(offset), Unbounded))
+            .next()
+            .map(|(base_offset, _)| *base_offset)
+    }
+
+    /// remove segment from list, min and max offsets are recomputed
+    pub fn remove_segment(&mut self, offset: Offset) -> Option<ReadSegment> {
+        let segment = self.segments.remove(&offset)?;
+        debug!("removed segment base: {}", offset);
+        self.min_base_offset = self.segments.keys().next().copied().unwrap_or(-1);
+        self.max_base_offset = self.segments.keys().next_back().copied().unwrap_or(0);
+        Some(segment)
+    }
+
+    /// total bytes of all segments
+    pub fn occupied_memory(&self) -> u64 {
+        self.segments
+            .values()
+            .map(|segment| segment.occupied_memory())
+            .sum()
+    }
 }
 
 #[cfg(test)]
@@ -224,4 +259,38 @@ mod tests {
         assert!(last_segment.is_none());
         Ok(())
     }
+
+    const TEST_REMOVE_DIR: &str = "segmentlist-remove";
+
+    #[test_async]
+    async fn test_segment_remove() -> Result<(), StorageError> {
+        let rep_dir = temp_dir().join(TEST_REMOVE_DIR);
+        ensure_new_dir(&rep_dir)?;
+        let mut list = SegmentList::new();
+
+        let option = default_option(rep_dir);
+
+        list.add_segment(create_segment(&option, 0, 500).await?);
+        list.add_segment(create_segment(&option, 500, 2000).await?);
+        list.add_segment(create_segment(&option, 2000, 1000).await?);
+
+        assert_eq!(list.next_base_offset(0), Some(500));
+        assert_eq!(list.next_base_offset(2000), None);
+        assert_eq!(list.oldest_segment().expect("oldest").get_base_offset(), 0);
+
+        let removed = list.remove_segment(0).expect("removed");
+        assert_eq!(removed.get_base_offset(), 0);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.min_offset(), 500);
+        assert_eq!(list.max_offset(), 2000);
+        assert!(list.find_segment(100).is_none());
+        assert!(list.remove_segment(0).is_none());
+
+        list.remove_segment(500);
+        list.remove_segment(2000);
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.min_offset(), -1);
+
+        Ok(())
+    }
 }
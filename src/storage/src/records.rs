@@ -2,6 +2,7 @@ use std::io::Error as IoError;
 use std::io::ErrorKind;
 use std::path::PathBuf;
 use std::path::Path;
+use std::time::SystemTime;
 
 use tracing::debug;
 
@@ -39,6 +40,7 @@ pub struct FileRecordsSlice {
     len: u64,
 }
 
+#[allow(clippy::len_without_is_empty)]
 impl FileRecordsSlice {
     pub async fn open(
         base_offset: Offset,
@@ -63,6 +65,16 @@ impl FileRecordsSlice {
         self.base_offset
     }
 
+    /// length of the log file in bytes
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// time when log file was last written
+    pub async fn last_modified(&self) -> Result<SystemTime, IoError> {
+        self.file.metadata().await?.modified()
+    }
+
     #[allow(dead_code)]
     pub async fn validate(&mut self) -> Result<Offset, LogValidationError> {
         validate(&self.path).await
@@ -5,14 +5,15 @@ use tracing::{debug, trace, error, warn, instrument};
 use async_trait::async_trait;
 
 use fluvio_future::fs::{create_dir_all, remove_dir_all};
-use dataplane::{ErrorCode, Isolation, Offset, ReplicaKey, Size};
+use dataplane::{ErrorCode, Isolation, Offset, ReplicaKey, Size, Timestamp, now_timestamp};
 use dataplane::batch::Batch;
 use dataplane::record::RecordSet;
 
 use crate::{OffsetInfo, checkpoint::CheckPoint};
+use crate::compaction::{self, CompactionJob, CompactedSegments};
 use crate::range_map::SegmentList;
-use crate::segment::MutableSegment;
-use crate::config::ConfigOption;
+use crate::segment::{MutableSegment, ReadSegment};
+use crate::config::{ConfigOption, CleanupPolicy};
 use crate::{SegmentSlice};
 use crate::{StorageError, SlicePartitionResponse, ReplicaStorage};
 
@@ -134,6 +135,96 @@ impl ReplicaStorage for FileReplica {
         }
     }
 
+    #[instrument(skip(self))]
+    async fn find_offset_by_timestamp(
+        &self,
+        timestamp: Timestamp,
+    ) -> Result<Option<Offset>, StorageError> {
+        for segment in self.prev_segments.segments() {
+            // segment whose records are all older is skipped without reading its log
+            if matches!(segment.max_timestamp(), Some(max_timestamp) if max_timestamp < timestamp) {
+                trace!(
+                    base_offset = segment.get_base_offset(),
+                    "segment is older than timestamp"
+                );
+                continue;
+            }
+            if let Some(offset) = segment.find_offset_by_timestamp(timestamp).await? {
+                return Ok(Some(offset));
+            }
+        }
+        self.active_segment
+            .find_offset_by_timestamp(timestamp)
+            .await
+    }
+
+    /// remove read only segments which are no longer retained by delete policy.
+    /// Active segment and segments with uncommitted records are never touched.
+    #[instrument(skip(self))]
+    async fn enforce_retention(&mut self) -> Result<bool, StorageError> {
+        match self.option.cleanup_policy {
+            CleanupPolicy::Delete => self.remove_expired_segments().await,
+            CleanupPolicy::Compact => Ok(false),
+        }
+    }
+
+    /// compact read only segments which are fully committed
+    fn compaction_job(&self) -> Option<CompactionJob> {
+        if self.option.cleanup_policy != CleanupPolicy::Compact {
+            return None;
+        }
+
+        let mut base_offsets = vec![];
+        let mut next = self
+            .prev_segments
+            .oldest_segment()
+            .map(|segment| segment.get_base_offset());
+        while let Some(base_offset) = next {
+            if !self.is_segment_committed(base_offset) {
+                trace!(base_offset, "segment is not fully committed, stopping");
+                break;
+            }
+            base_offsets.push(base_offset);
+            next = self.prev_segments.next_base_offset(base_offset);
+        }
+
+        if base_offsets.is_empty() {
+            None
+        } else {
+            Some(CompactionJob::new(self.option.clone(), base_offsets))
+        }
+    }
+
+    #[instrument(skip(self, compacted))]
+    async fn apply_compaction(
+        &mut self,
+        compacted: CompactedSegments,
+    ) -> Result<bool, StorageError> {
+        // segments removed while job was running are not swapped in
+        let base_offsets: Vec<Offset> = compacted
+            .base_offsets()
+            .iter()
+            .copied()
+            .filter(|base_offset| self.prev_segments.get_segment(*base_offset).is_some())
+            .collect();
+
+        // segments must be closed before their files are replaced
+        for base_offset in &base_offsets {
+            self.prev_segments.remove_segment(*base_offset);
+        }
+        let result = compaction::swap_segments(&self.option.base_dir, &base_offsets).await;
+        if let Err(err) = &result {
+            error!("swapping compacted segments failed: {}", err);
+        }
+        for base_offset in &base_offsets {
+            let segment = ReadSegment::open_for_read(*base_offset, &self.option).await?;
+            self.prev_segments.add_segment(segment);
+        }
+        result?;
+
+        Ok(!base_offsets.is_empty())
+    }
+
     #[instrument(skip(self))]
     async fn remove(&self) -> Result<(), StorageError> {
         remove_dir_all(&self.option.base_dir)
@@ -175,6 +266,9 @@ impl FileReplica {
 
         let mut rep_option = option.clone();
         rep_option.base_dir = replica_dir;
+
+        // segment files may have been left mismatched by crash during compaction
+        compaction::recover(&rep_option.base_dir).await?;
         // create active segment
 
         let (segments, last_offset_res) = SegmentList::from_dir(&rep_option).await?;
@@ -221,6 +315,79 @@ impl FileReplica {
         }
     }
 
+    /// remove read only segments which fall outside of retention policy.
+    /// Segment is removed if it is older than retention period or partition exceeds max size.
+    /// return true if log start offset has been moved
+    async fn remove_expired_segments(&mut self) -> Result<bool, StorageError> {
+        if self.option.retention_seconds.is_none() && self.option.max_partition_size.is_none() {
+            trace!("no retention limit");
+            return Ok(false);
+        }
+
+        let retention_ms = self
+            .option
+            .retention_seconds
+            .map(|seconds| seconds as Timestamp * 1000);
+        let now_ms = now_timestamp();
+        let mut total_size =
+            self.prev_segments.occupied_memory() + self.active_segment.occupied_memory();
+        let mut removed = false;
+
+        while let Some(segment) = self.prev_segments.oldest_segment() {
+            let base_offset = segment.get_base_offset();
+            if !self.is_segment_committed(base_offset) {
+                trace!(base_offset, "segment is not fully committed, stopping");
+                break;
+            }
+
+            match self.option.max_partition_size {
+                Some(max_partition_size) if total_size > max_partition_size => {
+                    debug!(
+                        base_offset,
+                        total_size,
+                        max = max_partition_size,
+                        "partition exceeds max size"
+                    );
+                }
+                _ => {
+                    let retention_ms = match retention_ms {
+                        Some(retention_ms) => retention_ms,
+                        None => break,
+                    };
+                    let last_timestamp = segment.last_timestamp().await?;
+                    if now_ms - last_timestamp < retention_ms {
+                        trace!(base_offset, last_timestamp, "segment is still retained");
+                        break;
+                    }
+                    debug!(base_offset, last_timestamp, "segment expired");
+                }
+            }
+
+            if let Some(segment) = self.prev_segments.remove_segment(base_offset) {
+                total_size -= segment.occupied_memory();
+                segment.remove().await?;
+                removed = true;
+            }
+        }
+
+        if removed {
+            debug!(
+                log_start_offset = self.get_log_start_offset(),
+                "log start offset moved"
+            );
+        }
+        Ok(removed)
+    }
+
+    /// check if all records of read only segment has been committed
+    fn is_segment_committed(&self, base_offset: Offset) -> bool {
+        let next_base_offset = self
+            .prev_segments
+            .next_base_offset(base_offset)
+            .unwrap_or_else(|| self.active_segment.get_base_offset());
+        next_base_offset <= self.get_hw()
+    }
+
     /// update high watermark to end
     #[instrument(skip(self))]
     pub async fn update_high_watermark_to_end(&mut self) -> Result<bool, StorageError> {
@@ -394,16 +561,16 @@ mod tests {
 
     use fluvio_future::test_async;
     use dataplane::{Isolation, batch::Batch};
-    use dataplane::{Offset, ErrorCode};
+    use dataplane::{Offset, ErrorCode, Timestamp};
     use dataplane::core::{Decoder, Encoder};
     use dataplane::fetch::FilePartitionResponse;
-    use dataplane::record::RecordSet;
+    use dataplane::record::{Record, RecordSet};
     use dataplane::batch::MemoryRecords;
     use dataplane::fixture::{BatchProducer, create_batch};
     use dataplane::fixture::read_bytes_from_file;
     use flv_util::fixture::ensure_clean_dir;
 
-    use crate::config::ConfigOption;
+    use crate::config::{ConfigOption, CleanupPolicy};
     use crate::StorageError;
     use crate::ReplicaStorage;
 
@@ -750,6 +917,252 @@ mod tests {
         Ok(())
     }
 
+    const TEST_RETENTION_SIZE_DIR: &str = "test_retention_size";
+
+    #[test_async]
+    async fn test_replica_retention_size() -> Result<(), StorageError> {
+        let mut option = rollover_option(TEST_RETENTION_SIZE_DIR);
+        option.max_partition_size = Some(200);
+
+        let mut replica = FileReplica::create("test", 0, 0, option.clone())
+            .await
+            .expect("test replica");
+
+        // each batch is 79 bytes, segment max is 100 bytes, so each batch rolls over
+        for _ in 0..4 {
+            replica.write_batch(&mut create_batch()).await?;
+        }
+        assert_eq!(replica.get_log_start_offset(), 0);
+
+        // nothing can be removed until records are committed
+        assert!(!replica.enforce_retention().await?);
+        assert_eq!(replica.get_log_start_offset(), 0);
+
+        replica.update_high_watermark_to_end().await?;
+        assert!(replica.enforce_retention().await?);
+        assert_eq!(replica.get_log_start_offset(), 4);
+        assert!(!option
+            .base_dir
+            .join("test-0")
+            .join("00000000000000000000.log")
+            .exists());
+
+        // purged offsets should be out of range
+        let mut partition_response = FilePartitionResponse::default();
+        replica
+            .read_partition_slice(
+                0,
+                FileReplica::PREFER_MAX_LEN,
+                Isolation::ReadCommitted,
+                &mut partition_response,
+            )
+            .await;
+        assert_eq!(partition_response.error_code, ErrorCode::OffsetOutOfRange);
+        assert_eq!(partition_response.log_start_offset, 4);
+
+        // retention is idempotent
+        assert!(!replica.enforce_retention().await?);
+
+        Ok(())
+    }
+
+    const TEST_RETENTION_TIME_DIR: &str = "test_retention_time";
+
+    #[test_async]
+    async fn test_replica_retention_time() -> Result<(), StorageError> {
+        let mut option = rollover_option(TEST_RETENTION_TIME_DIR);
+        option.retention_seconds = Some(3600);
+
+        let mut replica = FileReplica::create("test", 0, 0, option)
+            .await
+            .expect("test replica");
+
+        // two hours old batch
+        let mut old_batch = create_batch();
+        let old_timestamp = dataplane::now_timestamp() - 2 * 3600 * 1000;
+        old_batch.get_mut_header().first_timestamp = old_timestamp;
+        old_batch.get_mut_header().max_time_stamp = old_timestamp;
+        replica.write_batch(&mut old_batch).await?;
+
+        // batches without timestamp are aged by modification time
+        replica.write_batch(&mut create_batch()).await?;
+        replica.write_batch(&mut create_batch()).await?;
+        replica.update_high_watermark_to_end().await?;
+
+        assert!(replica.enforce_retention().await?);
+        assert_eq!(replica.get_log_start_offset(), 2);
+        assert!(!replica.enforce_retention().await?);
+        assert_eq!(replica.get_log_start_offset(), 2);
+
+        Ok(())
+    }
+
+    #[test_async]
+    async fn test_replica_find_offset_by_timestamp() -> Result<(), StorageError> {
+        let option = rollover_option("test_find_offset_by_timestamp");
+        let mut replica = FileReplica::create("test", 0, 0, option)
+            .await
+            .expect("test replica");
+
+        // each batch has 2 records, last batch stays in active segment
+        for timestamp in &[1000, 2000, 3000] {
+            let mut batch = create_batch();
+            batch.get_mut_header().first_timestamp = *timestamp;
+            batch.get_mut_header().max_time_stamp = *timestamp;
+            replica.write_batch(&mut batch).await?;
+        }
+        assert_eq!(replica.prev_segments.len(), 2);
+        let max_timestamps: Vec<Option<Timestamp>> = replica
+            .prev_segments
+            .segments()
+            .map(|segment| segment.max_timestamp())
+            .collect();
+        assert_eq!(max_timestamps, vec![Some(1000), Some(2000)]);
+
+        assert_eq!(replica.find_offset_by_timestamp(500).await?, Some(0));
+        assert_eq!(replica.find_offset_by_timestamp(1000).await?, Some(0));
+        assert_eq!(replica.find_offset_by_timestamp(1500).await?, Some(2));
+        assert_eq!(replica.find_offset_by_timestamp(3000).await?, Some(4));
+        assert_eq!(replica.find_offset_by_timestamp(4000).await?, None);
+
+        Ok(())
+    }
+
+    #[test_async]
+    async fn test_replica_recover_max_timestamp() -> Result<(), StorageError> {
+        let base_dir = temp_dir().join("test_recover_max_timestamp");
+        ensure_clean_dir(&base_dir);
+        // only first batch of segment is in time index
+        let option = ConfigOption {
+            segment_max_bytes: 1000,
+            base_dir,
+            index_max_bytes: 1000,
+            index_max_interval_bytes: 10000,
+            ..Default::default()
+        };
+
+        let mut replica = FileReplica::create("test", 0, 0, option.clone())
+            .await
+            .expect("test replica");
+        for timestamp in &[1000, 2000] {
+            let mut batch = create_batch();
+            batch.get_mut_header().first_timestamp = *timestamp;
+            batch.get_mut_header().max_time_stamp = *timestamp;
+            replica.write_batch(&mut batch).await?;
+        }
+        drop(replica);
+
+        // segment closed without roll over is sealed with timestamp of batches which were not indexed
+        let mut replica = FileReplica::create("test", 0, 0, option)
+            .await
+            .expect("test replica");
+        while replica.prev_segments.len() == 0 {
+            let mut batch = create_batch();
+            batch.get_mut_header().first_timestamp = 500;
+            batch.get_mut_header().max_time_stamp = 500;
+            replica.write_batch(&mut batch).await?;
+        }
+        let segment = replica.prev_segments.segments().next().expect("segment");
+        assert_eq!(segment.max_timestamp(), Some(2000));
+        assert_eq!(replica.find_offset_by_timestamp(1500).await?, Some(2));
+
+        Ok(())
+    }
+
+    const TEST_COMPACTION_DIR: &str = "test_compaction";
+
+    fn create_key_value_batch(key: &str, value: &str) -> Batch {
+        let mut batch = Batch::default();
+        batch.get_mut_header().magic = 2;
+        batch.add_record(Record::new_key_value(key, value.as_bytes().to_vec()));
+        batch
+    }
+
+    fn read_segment_batch(
+        replica_dir: &std::path::Path,
+        base_offset: Offset,
+    ) -> Batch<MemoryRecords> {
+        let bytes = read_bytes_from_file(replica_dir.join(format!("{:020}.log", base_offset)))
+            .expect("segment file");
+        Batch::<MemoryRecords>::decode_from(&mut Cursor::new(bytes), 0).expect("decode batch")
+    }
+
+    /// run compaction job and swap in its result, as cleaner does
+    async fn compact(replica: &mut FileReplica) -> Result<bool, StorageError> {
+        match replica.compaction_job() {
+            Some(job) => {
+                let compacted = job.run().await?;
+                replica.apply_compaction(compacted).await
+            }
+            None => Ok(false),
+        }
+    }
+
+    #[test_async]
+    async fn test_replica_compaction() -> Result<(), StorageError> {
+        let mut option = rollover_option(TEST_COMPACTION_DIR);
+        option.cleanup_policy = CleanupPolicy::Compact;
+        option.tombstone_retention_seconds = 3600;
+
+        let mut replica = FileReplica::create("test", 0, 0, option.clone())
+            .await
+            .expect("test replica");
+
+        // each batch rolls over, so every batch except last one is in read only segment
+        replica
+            .write_batch(&mut create_key_value_batch("a", "1"))
+            .await?;
+        replica
+            .write_batch(&mut create_key_value_batch("b", "1"))
+            .await?;
+        replica
+            .write_batch(&mut create_key_value_batch("a", "2"))
+            .await?;
+        replica
+            .write_batch(&mut create_key_value_batch("b", ""))
+            .await?;
+        replica
+            .write_batch(&mut create_key_value_batch("c", "1"))
+            .await?;
+        assert_eq!(replica.prev_segments.len(), 4);
+
+        // uncommitted segments are not compacted
+        compact(&mut replica).await?;
+        let replica_dir = option.base_dir.join("test-0");
+        assert_eq!(read_segment_batch(&replica_dir, 0).records().len(), 1);
+
+        replica.update_high_watermark_to_end().await?;
+        compact(&mut replica).await?;
+
+        // superseded records are removed, but offsets are preserved
+        let batch = read_segment_batch(&replica_dir, 0);
+        assert_eq!(batch.get_base_offset(), 0);
+        assert!(batch.records().is_empty());
+        assert!(read_segment_batch(&replica_dir, 1).records().is_empty());
+        let batch = read_segment_batch(&replica_dir, 2);
+        assert_eq!(batch.get_base_offset(), 2);
+        assert_eq!(batch.records().len(), 1);
+        assert_eq!(batch.records()[0].value().as_ref(), b"2");
+
+        // tombstone is kept until retention has passed
+        let batch = read_segment_batch(&replica_dir, 3);
+        assert_eq!(batch.records().len(), 1);
+        assert!(batch.records()[0].value().as_ref().is_empty());
+
+        replica.option.tombstone_retention_seconds = 0;
+        compact(&mut replica).await?;
+        assert!(read_segment_batch(&replica_dir, 3).records().is_empty());
+
+        assert_eq!(replica.get_log_start_offset(), 0);
+        assert_eq!(replica.get_leo(), 5);
+        assert!(!replica_dir
+            .join("compaction")
+            .join("00000000000000000000.log")
+            .exists());
+
+        Ok(())
+    }
+
     #[test_async]
     async fn test_replica_limit_batch() -> Result<(), StorageError> {
         let mut option = base_option("test_batch_limit");
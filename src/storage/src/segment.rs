@@ -1,14 +1,21 @@
+use std::cmp::max;
+use std::collections::HashMap;
 use std::fmt;
 use std::io::Error as IoError;
+use std::io::ErrorKind;
 use std::ops::Deref;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
 
 use tracing::debug;
 use tracing::trace;
 
-use dataplane::batch::Batch;
-use dataplane::{Offset, Size};
+use dataplane::batch::{Batch, MemoryRecords};
+use dataplane::record::Record;
+use dataplane::{Offset, Size, Timestamp};
 use fluvio_future::file_slice::AsyncFileSlice;
 use fluvio_future::fs::util as file_util;
+use fluvio_future::fs::{remove_file, File};
 
 use crate::batch_header::{BatchHeaderStream, BatchHeaderPos};
 use crate::mut_index::MutLogIndex;
@@ -21,8 +28,16 @@ use crate::config::ConfigOption;
 use crate::StorageError;
 use crate::batch::FileBatchStream;
 use crate::index::OffsetPosition;
+use crate::index::EXTENSION as INDEX_EXTENSION;
+use crate::time_index::TimeIndex;
+use crate::time_index::EXTENSION as TIME_INDEX_EXTENSION;
+use crate::records::MESSAGE_LOG_EXTENSION;
 use crate::validator::LogValidationError;
-use crate::util::OffsetError;
+use crate::util::{OffsetError, generate_file_name};
+
+/// extensions of files which make up segment
+pub(crate) const SEGMENT_EXTENSIONS: &[&str] =
+    &[MESSAGE_LOG_EXTENSION, INDEX_EXTENSION, TIME_INDEX_EXTENSION];
 
 pub type MutableSegment = Segment<MutLogIndex, MutFileRecords>;
 pub type ReadSegment = Segment<LogIndex, FileRecordsSlice>;
@@ -57,6 +72,7 @@ pub struct Segment<I, L> {
     option: ConfigOption,
     msg_log: L,
     index: I,
+    time_index: TimeIndex,
     base_offset: Offset,
     end_offset: Offset,
 }
@@ -206,6 +222,55 @@ where
         }
         Ok(None)
     }
+
+    /// offset of first record whose timestamp is same or later than given timestamp.
+    /// none if there is no such record in this segment
+    pub async fn find_offset_by_timestamp(
+        &self,
+        timestamp: Timestamp,
+    ) -> Result<Option<Offset>, StorageError> {
+        // all records before start offset are older
+        let start_delta = self.time_index.find_offset(timestamp).unwrap_or(0);
+        let position = match self.index.find_offset(start_delta) {
+            None => 0,
+            Some(entry) => entry.position(),
+        };
+        trace!(
+            timestamp,
+            start_delta,
+            position,
+            "finding offset by timestamp"
+        );
+
+        let mut header_stream = self.open_batch_header_stream(position).await?;
+        while let Some(batch_pos) = header_stream.next().await {
+            let header = batch_pos.get_batch().get_header();
+            if max(header.first_timestamp, header.max_time_stamp) < timestamp {
+                continue;
+            }
+            if header.is_log_append_time() {
+                return Ok(Some(batch_pos.get_base_offset()));
+            }
+
+            // records in batch may have different timestamps
+            let file = file_util::open(self.msg_log.get_path()).await?;
+            let mut batch_stream =
+                FileBatchStream::<MemoryRecords>::new_with_pos(file, batch_pos.get_pos()).await?;
+            if let Some(batch_pos) = batch_stream.next().await {
+                let batch = batch_pos.get_batch();
+                for record in batch.records() {
+                    let record_timestamp = batch
+                        .get_header()
+                        .record_timestamp(record.preamble.timestamp_delta());
+                    if record_timestamp >= timestamp {
+                        return Ok(Some(batch.get_base_offset() + record.get_offset_delta()));
+                    }
+                }
+            }
+            return Ok(Some(batch_pos.get_base_offset()));
+        }
+        Ok(None)
+    }
 }
 
 impl Segment<LogIndex, FileRecordsSlice> {
@@ -216,11 +281,13 @@ impl Segment<LogIndex, FileRecordsSlice> {
         let msg_log = FileRecordsSlice::open(base_offset, option).await?;
         let base_offset = msg_log.get_base_offset();
         let index = LogIndex::open_from_offset(base_offset, option).await?;
+        let time_index = TimeIndex::open(base_offset, option).await?;
 
         let base_offset = msg_log.get_base_offset();
         Ok(Segment {
             msg_log,
             index,
+            time_index,
             option: option.to_owned(),
             base_offset,
             end_offset: base_offset,
@@ -230,6 +297,170 @@ impl Segment<LogIndex, FileRecordsSlice> {
     pub fn to_segment_slice(&self) -> SegmentSlice {
         SegmentSlice::new_segment(self)
     }
+
+    /// size of message log in bytes
+    pub fn occupied_memory(&self) -> u64 {
+        self.msg_log.len()
+    }
+
+    /// largest timestamp of batches, recorded in time index when segment was sealed.
+    /// none if segment has no time index or its batches don't carry timestamp
+    pub fn max_timestamp(&self) -> Option<Timestamp> {
+        self.time_index.max_timestamp()
+    }
+
+    /// largest timestamp of batches stored in this segment, recorded in time index when segment was sealed.
+    /// if batches don't carry timestamp, time of last write to log is used
+    pub async fn last_timestamp(&self) -> Result<Timestamp, StorageError> {
+        if let Some(timestamp) = self.max_timestamp() {
+            return Ok(timestamp);
+        }
+
+        trace!(
+            base_offset = self.base_offset,
+            "no batch timestamp, using log modified time"
+        );
+        let modified = self.msg_log.last_modified().await?;
+        let since_epoch = modified.duration_since(UNIX_EPOCH).unwrap_or_default();
+        Ok(since_epoch.as_millis() as Timestamp)
+    }
+
+    /// permanently remove log and index files of this segment
+    pub async fn remove(self) -> Result<(), StorageError> {
+        let base_dir = &self.option.base_dir;
+        let log_path = generate_file_name(base_dir, self.base_offset, MESSAGE_LOG_EXTENSION);
+        let index_path = generate_file_name(base_dir, self.base_offset, INDEX_EXTENSION);
+        let time_index_path = generate_file_name(base_dir, self.base_offset, TIME_INDEX_EXTENSION);
+        debug!(
+            base_offset = self.base_offset,
+            log = %log_path.display(),
+            "removing segment"
+        );
+        drop(self);
+        remove_file(&log_path).await?;
+        remove_file(&index_path).await?;
+        // segment written before time index was introduced doesn't have it
+        if time_index_path.exists() {
+            remove_file(&time_index_path).await?;
+        }
+        Ok(())
+    }
+
+    /// write copy of segment into `compact_dir`, keeping only records accepted by `retain`.
+    /// offsets of retained records are preserved. batches which become empty are dropped
+    /// except the last one, so copy still ends at same offset.
+    /// Files are synced to disk before returning, so they can be swapped in safely.
+    /// if every record is retained, nothing is written and false is returned.
+    pub async fn write_compacted<F>(
+        &self,
+        compact_dir: &Path,
+        retain: F,
+    ) -> Result<bool, StorageError>
+    where
+        F: Fn(Offset, &Record) -> bool,
+    {
+        if !self.has_unretained_records(&retain).await? {
+            trace!(base_offset = self.base_offset, "nothing to compact");
+            return Ok(false);
+        }
+
+        let base_offset = self.base_offset;
+        let compact_option = ConfigOption {
+            base_dir: compact_dir.to_owned(),
+            segment_max_bytes: max(
+                self.option.segment_max_bytes,
+                self.occupied_memory() as Size,
+            ),
+            ..self.option.clone()
+        };
+        debug!(
+            base_offset,
+            dir = %compact_dir.display(),
+            "compacting segment"
+        );
+
+        let mut compacted = MutableSegment::create(base_offset, &compact_option).await?;
+        let mut batch_stream = self.open_default_batch_stream().await?;
+        let mut pending: Option<Batch> = None;
+        while let Some(batch_pos) = batch_stream.next().await {
+            let mut batch = batch_pos.into_batch();
+            let batch_base_offset = batch.get_base_offset();
+            batch
+                .mut_records()
+                .retain(|record| retain(batch_base_offset + record.get_offset_delta(), record));
+
+            if let Some(mut previous) = pending.replace(batch) {
+                if !previous.records().is_empty() {
+                    compacted.write_compacted_batch(&mut previous).await?;
+                }
+            }
+        }
+        if let Some(err) = batch_stream.invalid() {
+            return Err(err.into());
+        }
+        if let Some(mut last) = pending {
+            compacted.write_compacted_batch(&mut last).await?;
+        }
+        compacted.flush().await?;
+        compacted.roll_over().await?;
+        drop(compacted);
+
+        for extension in SEGMENT_EXTENSIONS {
+            File::open(generate_file_name(compact_dir, base_offset, extension))
+                .await?
+                .sync_all()
+                .await?;
+        }
+
+        Ok(true)
+    }
+
+    /// record offset of latest record for each key in this segment
+    pub async fn collect_latest_key_offsets(
+        &self,
+        latest_offsets: &mut HashMap<Vec<u8>, Offset>,
+    ) -> Result<(), StorageError> {
+        let mut batch_stream = self.open_default_batch_stream().await?;
+        while let Some(batch_pos) = batch_stream.next().await {
+            let batch = batch_pos.get_batch();
+            let batch_base_offset = batch.get_base_offset();
+            for record in batch.records() {
+                if let Some(key) = record.key() {
+                    latest_offsets.insert(
+                        key.as_ref().to_vec(),
+                        batch_base_offset + record.get_offset_delta(),
+                    );
+                }
+            }
+        }
+        if let Some(err) = batch_stream.invalid() {
+            return Err(err.into());
+        }
+        Ok(())
+    }
+
+    /// check if there is any record which is not accepted by `retain`
+    async fn has_unretained_records<F>(&self, retain: &F) -> Result<bool, StorageError>
+    where
+        F: Fn(Offset, &Record) -> bool,
+    {
+        let mut batch_stream = self.open_default_batch_stream().await?;
+        while let Some(batch_pos) = batch_stream.next().await {
+            let batch = batch_pos.get_batch();
+            let batch_base_offset = batch.get_base_offset();
+            if batch
+                .records()
+                .iter()
+                .any(|record| !retain(batch_base_offset + record.get_offset_delta(), record))
+            {
+                return Ok(true);
+            }
+        }
+        if let Some(err) = batch_stream.invalid() {
+            return Err(err.into());
+        }
+        Ok(false)
+    }
 }
 
 impl Unpin for Segment<MutLogIndex, MutFileRecords> {}
@@ -245,11 +476,13 @@ impl Segment<MutLogIndex, MutFileRecords> {
         let msg_log = MutFileRecords::create(base_offset, option).await?;
 
         let index = MutLogIndex::create(base_offset, option).await?;
+        let time_index = TimeIndex::open_for_write(base_offset, option).await?;
 
         Ok(MutableSegment {
             option: option.to_owned(),
             msg_log,
             index,
+            time_index,
             base_offset,
             end_offset: base_offset,
         })
@@ -267,12 +500,14 @@ impl Segment<MutLogIndex, MutFileRecords> {
         let msg_log = MutFileRecords::open(base_offset, option).await?;
         let base_offset = msg_log.get_base_offset();
         let index = MutLogIndex::open(base_offset, option).await?;
+        let time_index = TimeIndex::open_for_write(base_offset, option).await?;
 
         let base_offset = msg_log.get_base_offset();
         Ok(MutableSegment {
             option: option.to_owned(),
             msg_log,
             index,
+            time_index,
             base_offset,
             end_offset: base_offset,
         })
@@ -282,20 +517,45 @@ impl Segment<MutLogIndex, MutFileRecords> {
         self.msg_log.get_pos()
     }
 
+    /// size of message log in bytes
+    pub fn occupied_memory(&self) -> u64 {
+        self.get_log_pos() as u64
+    }
+
     /// validate the segment and load last offset
     pub async fn validate(&mut self) -> Result<(), StorageError> {
         self.end_offset = self.msg_log.validate().await?;
-        Ok(())
+        self.recover_max_timestamp().await
     }
 
-    // shrink index
-    async fn shrink_index(&mut self) -> Result<(), IoError> {
-        self.index.shrink().await
+    /// batches after last time index entry are not indexed until segment is sealed,
+    /// so their largest timestamp is lost if segment was closed without roll over.
+    /// It is read from batch headers, so sealed time index covers whole segment
+    async fn recover_max_timestamp(&mut self) -> Result<(), StorageError> {
+        let position = self
+            .time_index
+            .entries()
+            .last()
+            .and_then(|(_, relative_offset)| self.index.find_offset(*relative_offset))
+            .map(|entry| entry.position())
+            .unwrap_or(0);
+
+        let mut header_stream = self.open_batch_header_stream(position).await?;
+        while let Some(batch_pos) = header_stream.next().await {
+            let header = batch_pos.get_batch().get_header();
+            let relative_offset = (batch_pos.get_base_offset() - self.base_offset) as Size;
+            self.time_index.recover(
+                max(header.first_timestamp, header.max_time_stamp),
+                relative_offset,
+            );
+        }
+        Ok(())
     }
 
     // perform any action during roll over
     pub async fn roll_over(&mut self) -> Result<(), IoError> {
-        self.index.shrink().await
+        self.index.shrink().await?;
+        self.time_index.seal().await
     }
 
     /// convert to immutable segment
@@ -307,7 +567,7 @@ impl Segment<MutLogIndex, MutFileRecords> {
     /// shrink and convert as immutable
     #[allow(dead_code)]
     pub async fn convert_to_segment(mut self) -> Result<ReadSegment, StorageError> {
-        self.shrink_index().await?;
+        self.roll_over().await?;
         Segment::open_for_read(self.get_base_offset(), &self.option).await
     }
 
@@ -330,7 +590,8 @@ impl Segment<MutLogIndex, MutFileRecords> {
             ));
         }
 
-        let batch_offset_delta = (current_offset - base_offset) as i32;
+        // batch may start after current offset if offsets have been removed by compaction
+        let batch_offset_delta = (item.get_base_offset() - base_offset) as i32;
         debug!(
             base_offset,
             file_offset = pos,
@@ -344,10 +605,18 @@ impl Segment<MutLogIndex, MutFileRecords> {
             self.index
                 .send((batch_offset_delta as u32, pos, batch_len))
                 .await?;
+            let header = item.get_header();
+            self.time_index
+                .send(
+                    max(header.first_timestamp, header.max_time_stamp),
+                    batch_offset_delta as u32,
+                    batch_len - pos,
+                )
+                .await?;
 
             let last_offset_delta = self.msg_log.get_item_last_offset_delta();
             trace!("flushing: last offset delta: {}", last_offset_delta);
-            self.end_offset = self.end_offset + last_offset_delta as Offset + 1;
+            self.end_offset = item.get_base_offset() + last_offset_delta as Offset + 1;
             debug!(end_offset = self.end_offset, "updated leo");
             Ok(true)
         } else {
@@ -355,6 +624,22 @@ impl Segment<MutLogIndex, MutFileRecords> {
         }
     }
 
+    /// write batch rewritten by compaction, it must fit into segment
+    async fn write_compacted_batch(&mut self, item: &mut Batch) -> Result<(), StorageError> {
+        if self.write_batch(item).await? {
+            Ok(())
+        } else {
+            Err(StorageError::Io(IoError::new(
+                ErrorKind::Other,
+                format!(
+                    "compacted batch: {} doesn't fit into segment: {}",
+                    item.get_base_offset(),
+                    self.base_offset
+                ),
+            )))
+        }
+    }
+
     #[allow(unused)]
     pub async fn flush(&mut self) -> Result<(), StorageError> {
         self.msg_log.flush().await.map_err(|err| err.into())
@@ -0,0 +1,249 @@
+use std::convert::TryInto;
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+use std::mem::size_of;
+use std::path::Path;
+
+use tracing::debug;
+use tracing::trace;
+use futures_lite::io::AsyncWriteExt;
+
+use fluvio_future::fs::File;
+use fluvio_future::fs::OpenOptions;
+use dataplane::{Offset, Size, Timestamp};
+
+use crate::util::generate_file_name;
+use crate::config::ConfigOption;
+
+/// size of each entry, timestamp followed by relative offset
+const TIME_INDEX_ENTRY_SIZE: usize = size_of::<Timestamp>() + size_of::<Size>();
+
+pub const EXTENSION: &str = "timeindex";
+
+/// Segment time index
+///
+/// Maps timestamp into relative offset of batch.
+/// Each entry contains largest timestamp seen up to and including batch at the offset,
+/// so entries are ordered by both timestamp and offset.
+///
+/// Like offset index, it is sparse. Entry is added only after index interval bytes
+/// have been written since last entry.
+///
+/// Entries are kept in memory and appended to file for active segment.
+/// When segment is sealed, largest timestamp is always written, so last entry
+/// gives largest timestamp of segment without reading its batches.
+pub struct TimeIndex {
+    entries: Vec<(Timestamp, Size)>,
+    file: Option<File>,
+    max_timestamp: Timestamp,
+    max_timestamp_offset: Size,
+    bytes_delta: Size,
+    interval_bytes: Size,
+}
+
+impl TimeIndex {
+    /// open index for read, segment written before time index was introduced has no entries
+    pub async fn open(base_offset: Offset, option: &ConfigOption) -> Result<Self, IoError> {
+        let index_file_path = generate_file_name(&option.base_dir, base_offset, EXTENSION);
+        let entries = read_entries(&index_file_path).await?;
+        trace!(
+            path = %index_file_path.display(),
+            entries = entries.len(),
+            "opened time index"
+        );
+        let (max_timestamp, max_timestamp_offset) = entries.last().copied().unwrap_or((0, 0));
+        Ok(Self {
+            entries,
+            file: None,
+            max_timestamp,
+            max_timestamp_offset,
+            bytes_delta: 0,
+            interval_bytes: option.index_max_interval_bytes,
+        })
+    }
+
+    /// open index which can be appended
+    pub async fn open_for_write(
+        base_offset: Offset,
+        option: &ConfigOption,
+    ) -> Result<Self, IoError> {
+        let mut index = Self::open(base_offset, option).await?;
+        let index_file_path = generate_file_name(&option.base_dir, base_offset, EXTENSION);
+        debug!(path = %index_file_path.display(), "opening time index for write");
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&index_file_path)
+            .await?;
+        index.file = Some(file);
+        Ok(index)
+    }
+
+    pub fn entries(&self) -> &[(Timestamp, Size)] {
+        &self.entries
+    }
+
+    /// record timestamp of batch written at relative offset
+    pub async fn send(
+        &mut self,
+        timestamp: Timestamp,
+        relative_offset: Size,
+        batch_size: Size,
+    ) -> Result<(), IoError> {
+        let bytes_delta = self.bytes_delta;
+        self.bytes_delta = bytes_delta + batch_size;
+
+        if timestamp <= self.max_timestamp {
+            trace!(
+                timestamp,
+                max = self.max_timestamp,
+                "timestamp is not newer"
+            );
+            return Ok(());
+        }
+        self.max_timestamp = timestamp;
+        self.max_timestamp_offset = relative_offset;
+
+        if !self.entries.is_empty() && bytes_delta < self.interval_bytes {
+            trace!(
+                bytes_delta,
+                interval = self.interval_bytes,
+                "time index writing skipped"
+            );
+            return Ok(());
+        }
+
+        self.append(timestamp, relative_offset).await
+    }
+
+    /// write largest timestamp if it was skipped, called when segment is rolled over
+    pub async fn seal(&mut self) -> Result<(), IoError> {
+        let last_timestamp = self.entries.last().map(|(timestamp, _)| *timestamp);
+        if self.max_timestamp > last_timestamp.unwrap_or(0) {
+            debug!(max_timestamp = self.max_timestamp, "sealing time index");
+            self.append(self.max_timestamp, self.max_timestamp_offset)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// record timestamp of batch which was written without being indexed,
+    /// so it is included when index is sealed
+    pub fn recover(&mut self, timestamp: Timestamp, relative_offset: Size) {
+        if timestamp > self.max_timestamp {
+            self.max_timestamp = timestamp;
+            self.max_timestamp_offset = relative_offset;
+        }
+    }
+
+    /// largest timestamp in index, none if segment has no batch with timestamp
+    pub fn max_timestamp(&self) -> Option<Timestamp> {
+        self.entries
+            .last()
+            .map(|(timestamp, _)| *timestamp)
+            .filter(|timestamp| *timestamp > 0)
+    }
+
+    async fn append(&mut self, timestamp: Timestamp, relative_offset: Size) -> Result<(), IoError> {
+        let file = self
+            .file
+            .as_mut()
+            .ok_or_else(|| IoError::new(ErrorKind::PermissionDenied, "time index is read only"))?;
+        let mut entry = Vec::with_capacity(TIME_INDEX_ENTRY_SIZE);
+        entry.extend_from_slice(&timestamp.to_be_bytes());
+        entry.extend_from_slice(&relative_offset.to_be_bytes());
+        file.write_all(&entry).await?;
+        file.flush().await?;
+
+        self.entries.push((timestamp, relative_offset));
+        self.bytes_delta = 0;
+        trace!(timestamp, relative_offset, "time index written");
+        Ok(())
+    }
+
+    /// relative offset where search for timestamp should start.
+    /// all records before this offset have smaller timestamp
+    pub fn find_offset(&self, timestamp: Timestamp) -> Option<Size> {
+        let idx = self
+            .entries
+            .partition_point(|(entry_timestamp, _)| *entry_timestamp < timestamp);
+        if idx == 0 {
+            None
+        } else {
+            Some(self.entries[idx - 1].1)
+        }
+    }
+}
+
+async fn read_entries(path: &Path) -> Result<Vec<(Timestamp, Size)>, IoError> {
+    let bytes = match fluvio_future::fs::read(path).await {
+        Ok(bytes) => bytes,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(err),
+    };
+
+    // ignore partially written entry
+    Ok(bytes
+        .chunks_exact(TIME_INDEX_ENTRY_SIZE)
+        .map(|entry| {
+            let (timestamp, offset) = entry.split_at(size_of::<Timestamp>());
+            (
+                Timestamp::from_be_bytes(timestamp.try_into().unwrap()),
+                Size::from_be_bytes(offset.try_into().unwrap()),
+            )
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::env::temp_dir;
+
+    use fluvio_future::test_async;
+    use flv_util::fixture::ensure_new_dir;
+
+    use crate::config::ConfigOption;
+    use super::TimeIndex;
+
+    #[test_async]
+    async fn test_time_index() -> Result<(), std::io::Error> {
+        let test_dir = temp_dir().join("time-index");
+        ensure_new_dir(&test_dir)?;
+
+        let option = ConfigOption {
+            base_dir: test_dir,
+            index_max_interval_bytes: 100,
+            ..Default::default()
+        };
+
+        let mut index = TimeIndex::open_for_write(10, &option).await?;
+        index.send(1000, 0, 60).await?; // first entry is always written
+        index.send(1100, 2, 60).await?; // skipped, interval not reached
+        index.send(1050, 4, 60).await?; // older timestamp
+        index.send(1200, 6, 60).await?;
+        assert_eq!(index.entries(), &[(1000, 0), (1200, 6)]);
+
+        assert_eq!(index.find_offset(900), None);
+        assert_eq!(index.find_offset(1000), None);
+        assert_eq!(index.find_offset(1150), Some(0));
+        assert_eq!(index.find_offset(1300), Some(6));
+
+        // largest timestamp skipped by interval is written when sealed
+        index.send(1250, 8, 60).await?;
+        assert_eq!(index.max_timestamp(), Some(1200));
+        index.seal().await?;
+        assert_eq!(index.max_timestamp(), Some(1250));
+        index.seal().await?;
+
+        drop(index);
+        let index = TimeIndex::open(10, &option).await?;
+        assert_eq!(index.entries(), &[(1000, 0), (1200, 6), (1250, 8)]);
+        assert_eq!(index.max_timestamp(), Some(1250));
+
+        let empty = TimeIndex::open(20, &option).await?;
+        assert!(empty.entries().is_empty());
+        assert_eq!(empty.max_timestamp(), None);
+        Ok(())
+    }
+}
@@ -1,7 +1,6 @@
 use std::path::Path;
 use std::path::PathBuf;
 use std::num::ParseIntError;
-
 use dataplane::Offset;
 
 /// given parent directory, base offset, extension, generate path
@@ -0,0 +1,515 @@
+//!
+//! # Local Metadata Dispatcher
+//!
+//! Persists metadata objects as json files in a local directory instead of Kubernetes.
+//! Objects are stored in Kubernetes form so conversion, ownership and finalizers behave
+//! the same as with K8ClusterStateDispatcher.
+//!
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::{Debug, Display};
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use tracing::{debug, error, trace, instrument};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use async_rwlock::RwLock;
+use futures_lite::stream::StreamExt;
+
+use fluvio_future::fs;
+use fluvio_future::task::spawn;
+use fluvio_future::timer::sleep;
+
+use crate::k8_types::{K8Obj, ObjectMeta, Spec as K8Spec};
+use crate::core::{Spec, MetadataItem};
+use crate::store::k8::{K8ExtendedSpec, K8MetaItem};
+use crate::store::actions::LSUpdate;
+use crate::store::{StoreContext, MetadataStoreObject};
+use crate::actions::WSAction;
+
+/// finalizer which holds owner until all of its dependents are deleted
+const FOREGROUND_DELETION: &str = "foregroundDeletion";
+
+/// interval to finish pending deletes and remove objects whose owner is gone
+const GC_INTERVAL_SEC: u64 = 2;
+
+/// Directory shared by dispatchers of all specs.
+/// Tracks revision and ownership across specs so dependents can be garbage collected.
+#[derive(Debug, Clone)]
+pub struct LocalMetadataStorage {
+    base_dir: PathBuf,
+    revision: Arc<AtomicU64>,
+    // uid of each stored object to uids of its owners
+    owners: Arc<RwLock<HashMap<String, Vec<String>>>>,
+}
+
+impl LocalMetadataStorage {
+    pub fn new(base_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            base_dir: base_dir.into(),
+            revision: Arc::new(AtomicU64::new(0)),
+            owners: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    pub fn base_dir(&self) -> &Path {
+        &self.base_dir
+    }
+
+    fn next_revision(&self) -> u64 {
+        self.revision.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    fn observe_revision(&self, revision: u64) {
+        self.revision.fetch_max(revision, Ordering::SeqCst);
+    }
+
+    fn new_uid(&self) -> String {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_nanos())
+            .unwrap_or(0);
+        format!("{:x}-{:x}", nanos, self.next_revision())
+    }
+
+    async fn register(&self, meta: &ObjectMeta) {
+        let owners = meta
+            .owner_references
+            .iter()
+            .map(|owner| owner.uid.clone())
+            .collect();
+        self.owners.write().await.insert(meta.uid.clone(), owners);
+    }
+
+    async fn unregister(&self, uid: &str) {
+        self.owners.write().await.remove(uid);
+    }
+
+    async fn is_live(&self, uid: &str) -> bool {
+        self.owners.read().await.contains_key(uid)
+    }
+
+    async fn has_dependents(&self, uid: &str) -> bool {
+        self.owners
+            .read()
+            .await
+            .values()
+            .any(|owners| owners.iter().any(|owner| owner == uid))
+    }
+}
+
+/// For each spec, persist updates into local directory and apply them to the store
+pub struct LocalClusterStateDispatcher<S>
+where
+    S: K8ExtendedSpec,
+{
+    storage: LocalMetadataStorage,
+    dir: PathBuf,
+    namespace: String,
+    ctx: StoreContext<S>,
+    objects: HashMap<String, K8Obj<S::K8Spec>>,
+}
+
+impl<S> Debug for LocalClusterStateDispatcher<S>
+where
+    S: K8ExtendedSpec,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} LocalStateDispatcher", S::LABEL)
+    }
+}
+
+impl<S> LocalClusterStateDispatcher<S>
+where
+    S: K8ExtendedSpec + Into<<S as K8ExtendedSpec>::K8Spec> + Sync + Send + 'static,
+    <S as Spec>::Owner: K8ExtendedSpec,
+    S::Status: Into<<<S as K8ExtendedSpec>::K8Spec as K8Spec>::Status>,
+    S::Status: Display + Sync + Send + 'static,
+    S::IndexKey: Display + Sync + Send + 'static,
+    S::K8Spec: Sync + Send + 'static,
+    K8Obj<S::K8Spec>: Serialize + DeserializeOwned + Clone + Default + Send + Sync,
+{
+    /// load objects persisted in storage and start dispatcher
+    pub async fn start(
+        namespace: impl Into<String>,
+        storage: LocalMetadataStorage,
+        ctx: StoreContext<S>,
+    ) -> Result<(), IoError> {
+        let dir = storage.base_dir().join(S::LABEL.to_lowercase());
+        fs::create_dir_all(&dir).await?;
+
+        let mut dispatcher = Self {
+            storage,
+            dir,
+            namespace: namespace.into(),
+            ctx,
+            objects: HashMap::new(),
+        };
+        // load before spawning so ownership of all specs is known before first collection
+        dispatcher.load().await?;
+
+        spawn(dispatcher.dispatch_loop());
+        Ok(())
+    }
+
+    async fn load(&mut self) -> Result<(), IoError> {
+        let mut entries = fs::read_dir(&self.dir).await?;
+        while let Some(entry) = entries.next().await {
+            let path: PathBuf = entry?.path().into();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            let k8_obj: K8Obj<S::K8Spec> = match serde_json::from_slice(&fs::read(&path).await?) {
+                Ok(obj) => obj,
+                Err(err) => {
+                    error!("{}: skipping invalid object {:?}: {}", S::LABEL, path, err);
+                    continue;
+                }
+            };
+            if let Ok(revision) = k8_obj.metadata.resource_version.parse() {
+                self.storage.observe_revision(revision);
+            }
+            self.storage.register(&k8_obj.metadata).await;
+            self.objects.insert(k8_obj.metadata.name.clone(), k8_obj);
+        }
+        debug!("{}: loaded {} objects", S::LABEL, self.objects.len());
+        Ok(())
+    }
+
+    #[instrument(
+        name = "LocalStateDispatcher",
+        skip(self),
+        fields(spec = S::LABEL)
+    )]
+    async fn dispatch_loop(mut self) {
+        use tokio::select;
+
+        let mut items = vec![];
+        for k8_obj in self.objects.values() {
+            match convert(k8_obj.clone()) {
+                Ok(item) => items.push(item),
+                Err(err) => error!("{}: {}", S::LABEL, err),
+            }
+        }
+        self.ctx.store().sync_all(items).await;
+
+        let mut gc_timer = sleep(Duration::from_secs(GC_INTERVAL_SEC));
+
+        loop {
+            trace!("dispatcher waiting");
+            let ws_receiver = self.ctx.receiver();
+
+            select! {
+                _ = &mut gc_timer => {
+                    let changes = self.collect_garbage().await;
+                    self.apply_changes(changes).await;
+                    gc_timer = sleep(Duration::from_secs(GC_INTERVAL_SEC));
+                },
+
+                msg = ws_receiver.recv() => {
+                    match msg {
+                        Ok(action) => {
+                            debug!("store: received ws action: {}", action);
+                            let changes = self.process_ws_action(action).await;
+                            self.apply_changes(changes).await;
+                        },
+                        Err(err) => {
+                            error!("WS channel error: {}", err);
+                            panic!("WS channel error: {}", err);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    async fn apply_changes(&self, changes: Vec<LSUpdate<S, K8MetaItem>>) {
+        if !changes.is_empty() {
+            let _ = self.ctx.store().apply_changes(changes).await;
+        }
+    }
+
+    async fn process_ws_action(&mut self, action: WSAction<S>) -> Vec<LSUpdate<S, K8MetaItem>> {
+        let label = action.to_string();
+        let result = match action {
+            WSAction::Apply(obj) => self.apply(obj).await.map(|change| vec![change]),
+            WSAction::UpdateSpec((key, spec)) => {
+                let name = key.to_string();
+                let mut k8_obj = self
+                    .objects
+                    .get(&name)
+                    .cloned()
+                    .unwrap_or_else(|| self.new_object(&name, None));
+                k8_obj.spec = spec.into();
+                self.write(k8_obj).await.map(|change| vec![change])
+            }
+            WSAction::UpdateStatus((key, status)) => match self.get(&key) {
+                Ok(mut k8_obj) => {
+                    k8_obj.status = status.into();
+                    self.write(k8_obj).await.map(|change| vec![change])
+                }
+                Err(err) => Err(err),
+            },
+            WSAction::Delete(key) => self.delete(&key.to_string()).await,
+            WSAction::DeleteFinal(key) => match self.get(&key) {
+                Ok(mut k8_obj) => {
+                    k8_obj.metadata.finalizers.clear();
+                    if k8_obj.metadata.deletion_grace_period_seconds.is_some() {
+                        self.remove(&k8_obj.metadata.name).await
+                    } else {
+                        self.write(k8_obj).await.map(|change| vec![change])
+                    }
+                }
+                Err(err) => Err(err),
+            },
+        };
+
+        match result {
+            Ok(changes) => changes,
+            Err(err) => {
+                error!("error: {}, {}", label, err);
+                vec![]
+            }
+        }
+    }
+
+    fn get(&self, key: &S::IndexKey) -> Result<K8Obj<S::K8Spec>, IoError> {
+        self.objects.get(&key.to_string()).cloned().ok_or_else(|| {
+            IoError::new(
+                ErrorKind::NotFound,
+                format!("{} not found: {}", S::LABEL, key),
+            )
+        })
+    }
+
+    /// add or update object, status of existing object is kept
+    async fn apply(
+        &mut self,
+        value: MetadataStoreObject<S, K8MetaItem>,
+    ) -> Result<LSUpdate<S, K8MetaItem>, IoError> {
+        let (key, spec, _status, ctx) = value.parts();
+        let name = key.to_string();
+
+        let mut k8_obj = self
+            .objects
+            .get(&name)
+            .cloned()
+            .unwrap_or_else(|| self.new_object(&name, ctx.owner()));
+        k8_obj.spec = spec.into();
+        k8_obj.metadata.labels = ctx.item().get_labels();
+        k8_obj.metadata.annotations = ctx.item().annotations.clone();
+
+        self.write(k8_obj).await
+    }
+
+    fn new_object(&self, name: &str, owner: Option<&K8MetaItem>) -> K8Obj<S::K8Spec> {
+        let mut metadata = ObjectMeta::new(name.to_owned(), self.namespace.clone());
+        metadata.uid = self.storage.new_uid();
+
+        if let Some(owner) = owner {
+            metadata.owner_references = owner
+                .make_child_input_metadata::<<<S as Spec>::Owner as K8ExtendedSpec>::K8Spec>(
+                    name.to_owned(),
+                )
+                .owner_references;
+            if let Some(finalizer) = S::FINALIZER {
+                metadata.finalizers = vec![finalizer.to_owned()];
+            }
+        }
+
+        K8Obj {
+            api_version: S::K8Spec::api_version(),
+            kind: S::K8Spec::kind(),
+            metadata,
+            ..Default::default()
+        }
+    }
+
+    /// delete object now or mark it as being deleted if it has finalizers
+    async fn delete(&mut self, name: &str) -> Result<Vec<LSUpdate<S, K8MetaItem>>, IoError> {
+        let mut k8_obj = self.objects.get(name).cloned().ok_or_else(|| {
+            IoError::new(
+                ErrorKind::NotFound,
+                format!("{} not found: {}", S::LABEL, name),
+            )
+        })?;
+
+        if k8_obj.metadata.deletion_grace_period_seconds.is_some() {
+            debug!("{}: {} is already being deleted", S::LABEL, name);
+            return Ok(vec![]);
+        }
+
+        if S::DELETE_WAIT_DEPENDENTS && self.storage.has_dependents(&k8_obj.metadata.uid).await {
+            k8_obj
+                .metadata
+                .finalizers
+                .push(FOREGROUND_DELETION.to_owned());
+        }
+
+        if k8_obj.metadata.finalizers.is_empty() {
+            self.remove(name).await
+        } else {
+            debug!("{}: {} waiting for finalizers", S::LABEL, name);
+            k8_obj.metadata.deletion_grace_period_seconds = Some(0);
+            self.write(k8_obj).await.map(|change| vec![change])
+        }
+    }
+
+    /// finish deletes which were only waiting for dependents and delete objects whose owner is gone
+    async fn collect_garbage(&mut self) -> Vec<LSUpdate<S, K8MetaItem>> {
+        let mut completed = vec![];
+        let mut orphans = vec![];
+        for (name, k8_obj) in self.objects.iter() {
+            let meta = &k8_obj.metadata;
+            if meta.deletion_grace_period_seconds.is_some() {
+                if meta
+                    .finalizers
+                    .iter()
+                    .all(|finalizer| finalizer == FOREGROUND_DELETION)
+                    && !self.storage.has_dependents(&meta.uid).await
+                {
+                    completed.push(name.clone());
+                }
+            } else if !meta.owner_references.is_empty() {
+                let mut has_owner = false;
+                for owner in &meta.owner_references {
+                    if self.storage.is_live(&owner.uid).await {
+                        has_owner = true;
+                        break;
+                    }
+                }
+                if !has_owner {
+                    orphans.push(name.clone());
+                }
+            }
+        }
+
+        let mut changes = vec![];
+        for name in completed {
+            debug!("{}: {} has no more dependents", S::LABEL, name);
+            match self.remove(&name).await {
+                Ok(mut removed) => changes.append(&mut removed),
+                Err(err) => error!("{}: error removing {}: {}", S::LABEL, name, err),
+            }
+        }
+        for name in orphans {
+            debug!("{}: owner of {} is deleted", S::LABEL, name);
+            match self.delete(&name).await {
+                Ok(mut deleted) => changes.append(&mut deleted),
+                Err(err) => error!("{}: error deleting {}: {}", S::LABEL, name, err),
+            }
+        }
+        changes
+    }
+
+    /// persist object with next revision
+    async fn write(
+        &mut self,
+        mut k8_obj: K8Obj<S::K8Spec>,
+    ) -> Result<LSUpdate<S, K8MetaItem>, IoError> {
+        k8_obj.metadata.resource_version = self.storage.next_revision().to_string();
+        let name = k8_obj.metadata.name.clone();
+
+        let bytes = serde_json::to_vec_pretty(&k8_obj)
+            .map_err(|err| IoError::new(ErrorKind::InvalidData, err))?;
+        let path = self.object_path(&name);
+        let tmp_path = path.with_extension("json.tmp");
+        fs::write(&tmp_path, bytes).await?;
+        fs::rename(&tmp_path, &path).await?;
+
+        trace!("{}: persisted {} at {:?}", S::LABEL, name, path);
+        self.storage.register(&k8_obj.metadata).await;
+        self.objects.insert(name, k8_obj.clone());
+
+        convert(k8_obj).map(LSUpdate::Mod)
+    }
+
+    async fn remove(&mut self, name: &str) -> Result<Vec<LSUpdate<S, K8MetaItem>>, IoError> {
+        let k8_obj = match self.objects.remove(name) {
+            Some(obj) => obj,
+            None => return Ok(vec![]),
+        };
+
+        if let Err(err) = fs::remove_file(self.object_path(name)).await {
+            if err.kind() != ErrorKind::NotFound {
+                return Err(err);
+            }
+        }
+        self.storage.unregister(&k8_obj.metadata.uid).await;
+        debug!("{}: removed {}", S::LABEL, name);
+
+        let item = convert(k8_obj)?;
+        Ok(vec![LSUpdate::Delete(item.key_owned())])
+    }
+
+    fn object_path(&self, name: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", name))
+    }
+}
+
+fn convert<S>(k8_obj: K8Obj<S::K8Spec>) -> Result<MetadataStoreObject<S, K8MetaItem>, IoError>
+where
+    S: K8ExtendedSpec,
+    <S as Spec>::Owner: K8ExtendedSpec,
+{
+    S::convert_from_k8(k8_obj).map_err(|err| {
+        IoError::new(
+            ErrorKind::InvalidData,
+            format!("error converting {}: {:?}", S::LABEL, err),
+        )
+    })
+}
+
+#[cfg(test)]
+mod test {
+
+    use fluvio_future::test_async;
+
+    use crate::k8_types::{ObjectMeta, OwnerReferences};
+
+    use super::LocalMetadataStorage;
+
+    fn meta(uid: &str, owner: Option<&str>) -> ObjectMeta {
+        ObjectMeta {
+            uid: uid.to_owned(),
+            owner_references: owner
+                .map(|owner| {
+                    vec![OwnerReferences {
+                        uid: owner.to_owned(),
+                        ..Default::default()
+                    }]
+                })
+                .unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+
+    #[test_async]
+    async fn test_storage_dependents() -> Result<(), ()> {
+        let storage = LocalMetadataStorage::new("/tmp");
+        storage.register(&meta("topic1", None)).await;
+        storage.register(&meta("partition1", Some("topic1"))).await;
+
+        assert!(storage.is_live("topic1").await);
+        assert!(storage.has_dependents("topic1").await);
+        assert!(!storage.has_dependents("partition1").await);
+
+        storage.unregister("partition1").await;
+        assert!(!storage.has_dependents("topic1").await);
+        assert!(!storage.is_live("partition1").await);
+        Ok(())
+    }
+
+    #[test]
+    fn test_storage_revision() {
+        let storage = LocalMetadataStorage::new("/tmp");
+        storage.observe_revision(10);
+        assert_eq!(storage.next_revision(), 11);
+        assert_ne!(storage.new_uid(), storage.new_uid());
+    }
+}
@@ -1,8 +1,10 @@
 mod k8_dispatcher;
 mod k8_ws_service;
+mod local_dispatcher;
 
 pub use k8_dispatcher::*;
 pub use k8_ws_service::*;
+pub use local_dispatcher::*;
 
 /*
 mod delta{
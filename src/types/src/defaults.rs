@@ -24,11 +24,20 @@ pub const SPU_PRIVATE_HOSTNAME: &str = "0.0.0.0";
 pub const SPU_CREDENTIALS_FILE: &str = "/etc/fluvio/.credentials/token_secret";
 pub const SPU_RETRY_SC_TIMEOUT_MS: u16 = 3000;
 pub const SPU_MIN_IN_SYNC_REPLICAS: u16 = 1;
+pub const SPU_REPLICA_LAG_TIME_MAX_MS: u64 = 10000;
 pub const SPU_LOG_BASE_DIR: &str = "/var/lib/fluvio/data";
 pub const SPU_LOG_SIZE: &str = "1Gi";
 pub const SPU_LOG_INDEX_MAX_BYTES: u32 = 10485760;
 pub const SPU_LOG_INDEX_MAX_INTERVAL_BYTES: u32 = 4096;
 pub const SPU_LOG_SEGMENT_MAX_BYTES: u32 = 1073741824;
+pub const SPU_LOG_MAX_BATCH_SIZE: u32 = 1048588;
+pub const SPU_LOG_TOMBSTONE_RETENTION_SECONDS: u32 = 24 * 3600; // 1 day
+pub const SPU_LOG_CLEANUP_INTERVAL_SEC: u64 = 60;
+
+// Consumer groups
+pub const CONSUMER_OFFSETS_TOPIC: &str = "consumer-offsets";
+pub const CONSUMER_OFFSETS_PARTITIONS: i32 = 8;
+pub const CONSUMER_OFFSETS_REPLICATION: i32 = 3;
 
 // CLI config
 pub const CLI_PROFILES_DIR: &str = "profiles";